@@ -0,0 +1,213 @@
+/// Address lookup table module.
+/// Fetches and caches the address lookup tables referenced by versioned transactions.
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use solana_commitment_config::CommitmentConfig;
+use solana_network_sdk::Solana;
+use solana_sdk::{message::VersionedMessage, pubkey::Pubkey};
+
+use crate::types::JupiterError;
+
+/// Size of the lookup table metadata preceding the addresses
+const LOOKUP_TABLE_META_SIZE: usize = 56;
+/// Discriminator of an initialized lookup table account
+const LOOKUP_TABLE_DISCRIMINATOR: u32 = 1;
+
+/// Decoded address lookup table
+#[derive(Debug, Clone, PartialEq)]
+pub struct LookupTable {
+    pub key: Pubkey,
+    pub addresses: Vec<Pubkey>,
+    /// Slot at which the table was deactivated, `u64::MAX` while active
+    pub deactivation_slot: u64,
+    /// Slot of the last extension
+    pub last_extended_slot: u64,
+}
+
+impl LookupTable {
+    /// Decodes a lookup table from its account data
+    pub fn deserialize(key: Pubkey, data: &[u8]) -> Result<Self, JupiterError> {
+        let invalid = || JupiterError::ParseError(format!("invalid lookup table account {}", key));
+        if data.len() < LOOKUP_TABLE_META_SIZE {
+            return Err(invalid());
+        }
+        let u64_at = |offset: usize| {
+            data[offset..offset + 8]
+                .try_into()
+                .map(u64::from_le_bytes)
+                .map_err(|_| invalid())
+        };
+        let discriminator = u32::from_le_bytes([data[0], data[1], data[2], data[3]]);
+        if discriminator != LOOKUP_TABLE_DISCRIMINATOR {
+            return Err(invalid());
+        }
+        let addresses = data[LOOKUP_TABLE_META_SIZE..]
+            .chunks_exact(32)
+            .map(|chunk| Pubkey::try_from(chunk).map_err(|_| invalid()))
+            .collect::<Result<Vec<Pubkey>, JupiterError>>()?;
+        Ok(Self {
+            key,
+            addresses,
+            deactivation_slot: u64_at(4)?,
+            last_extended_slot: u64_at(12)?,
+        })
+    }
+
+    /// Whether the table has been deactivated
+    pub fn is_deactivated(&self) -> bool {
+        self.deactivation_slot != u64::MAX
+    }
+}
+
+/// Cache of address lookup tables shared by a client and its clones
+///
+/// Tables are only ever extended, so cached entries stay valid until a message
+/// references an index past the cached addresses, which triggers a refetch.
+#[derive(Debug, Clone, Default)]
+pub struct LookupTableCache {
+    tables: Arc<Mutex<HashMap<Pubkey, LookupTable>>>,
+}
+
+impl LookupTableCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cached table, if any
+    pub fn get(&self, key: &Pubkey) -> Option<LookupTable> {
+        self.tables.lock().unwrap().get(key).cloned()
+    }
+
+    /// Drops a cached table, e.g. after extending it
+    pub fn invalidate(&self, key: &Pubkey) {
+        self.tables.lock().unwrap().remove(key);
+    }
+
+    pub fn clear(&self) {
+        self.tables.lock().unwrap().clear();
+    }
+
+    /// Loads tables, fetching those not cached in a single RPC call
+    ///
+    /// # Params
+    /// solana - Solana client used to fetch missing tables
+    /// commitment - Commitment of the fetch
+    /// keys - Lookup table addresses
+    pub async fn load(
+        &self,
+        solana: &Solana,
+        commitment: CommitmentConfig,
+        keys: &[Pubkey],
+    ) -> Result<Vec<LookupTable>, JupiterError> {
+        let missing: Vec<Pubkey> = {
+            let tables = self.tables.lock().unwrap();
+            keys.iter()
+                .filter(|key| !tables.contains_key(key))
+                .copied()
+                .collect()
+        };
+        if !missing.is_empty() {
+            self.fetch(solana, commitment, &missing).await?;
+        }
+        let tables = self.tables.lock().unwrap();
+        Ok(keys
+            .iter()
+            .filter_map(|key| tables.get(key).cloned())
+            .collect())
+    }
+
+    /// Resolves every account key of a message: static keys followed by the writable
+    /// and then readonly addresses loaded from lookup tables
+    ///
+    /// Tables whose cached copy is too short for the referenced indexes are refetched.
+    pub async fn resolve(
+        &self,
+        solana: &Solana,
+        commitment: CommitmentConfig,
+        message: &VersionedMessage,
+    ) -> Result<Vec<Pubkey>, JupiterError> {
+        let mut keys = message.static_account_keys().to_vec();
+        let Some(lookups) = message.address_table_lookups() else {
+            return Ok(keys);
+        };
+        let table_keys: Vec<Pubkey> = lookups.iter().map(|lookup| lookup.account_key).collect();
+        self.load(solana, commitment, &table_keys).await?;
+        let stale: Vec<Pubkey> = {
+            let tables = self.tables.lock().unwrap();
+            lookups
+                .iter()
+                .filter(|lookup| {
+                    let len = tables
+                        .get(&lookup.account_key)
+                        .map_or(0, |table| table.addresses.len());
+                    lookup
+                        .writable_indexes
+                        .iter()
+                        .chain(&lookup.readonly_indexes)
+                        .any(|index| *index as usize >= len)
+                })
+                .map(|lookup| lookup.account_key)
+                .collect()
+        };
+        if !stale.is_empty() {
+            self.fetch(solana, commitment, &stale).await?;
+        }
+        let tables = self.tables.lock().unwrap();
+        for writable in [true, false] {
+            for lookup in lookups {
+                let table = tables.get(&lookup.account_key).ok_or_else(|| {
+                    JupiterError::ParseError(format!(
+                        "lookup table {} not found",
+                        lookup.account_key
+                    ))
+                })?;
+                let indexes = if writable {
+                    &lookup.writable_indexes
+                } else {
+                    &lookup.readonly_indexes
+                };
+                for index in indexes {
+                    let address = table.addresses.get(*index as usize).ok_or_else(|| {
+                        JupiterError::ParseError(format!(
+                            "index {} out of range for lookup table {}",
+                            index, lookup.account_key
+                        ))
+                    })?;
+                    keys.push(*address);
+                }
+            }
+        }
+        Ok(keys)
+    }
+
+    async fn fetch(
+        &self,
+        solana: &Solana,
+        commitment: CommitmentConfig,
+        keys: &[Pubkey],
+    ) -> Result<(), JupiterError> {
+        let accounts = solana
+            .client
+            .clone()
+            .ok_or(JupiterError::Error("solana client error".to_string()))?
+            .get_multiple_accounts_with_commitment(keys, commitment)
+            .await
+            .map_err(|e| JupiterError::NetworkError(e.to_string()))?
+            .value;
+        let mut tables = self.tables.lock().unwrap();
+        for (key, account) in keys.iter().zip(accounts) {
+            match account {
+                Some(account) => {
+                    tables.insert(*key, LookupTable::deserialize(*key, &account.data)?);
+                }
+                None => {
+                    tables.remove(key);
+                }
+            }
+        }
+        Ok(())
+    }
+}
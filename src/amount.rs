@@ -0,0 +1,209 @@
+/// Token amount module.
+/// Decimals-aware token amounts with checked conversion to and from display values.
+use rust_decimal::{
+    Decimal,
+    prelude::{FromPrimitive, ToPrimitive},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{tool::parse_amount, types::JupiterError};
+
+/// Largest decimals value representable by `Decimal`
+const MAX_DECIMALS: u8 = 28;
+
+/// Raw token amount paired with the decimals of its mint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TokenAmount {
+    /// Amount in the mint's smallest unit
+    pub raw: u64,
+    pub decimals: u8,
+}
+
+impl TokenAmount {
+    /// Creates an amount from raw units
+    pub fn new(raw: u64, decimals: u8) -> Self {
+        Self { raw, decimals }
+    }
+
+    /// Zero amount
+    pub fn zero(decimals: u8) -> Self {
+        Self { raw: 0, decimals }
+    }
+
+    /// Parses a display string such as `"1.5"`
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::amount::TokenAmount;
+    ///
+    /// let amount = TokenAmount::from_ui_str("1.5", 9).unwrap();
+    /// assert_eq!(amount.raw, 1_500_000_000);
+    /// assert_eq!(amount.to_string(), "1.5");
+    /// assert!(TokenAmount::from_ui_str("0.0000001", 6).is_err());
+    /// ```
+    pub fn from_ui_str(value: &str, decimals: u8) -> Result<Self, JupiterError> {
+        let raw = parse_amount(value, decimals).map_err(JupiterError::InvalidInput)?;
+        Ok(Self { raw, decimals })
+    }
+
+    /// Converts a decimal display value, rejecting negative values, values with more
+    /// fractional digits than `decimals` and values above `u64::MAX` raw units
+    pub fn from_decimal(value: Decimal, decimals: u8) -> Result<Self, JupiterError> {
+        if value.is_sign_negative() && !value.is_zero() {
+            return Err(JupiterError::InvalidInput(format!(
+                "negative amount {}",
+                value
+            )));
+        }
+        if decimals > MAX_DECIMALS || value.normalize().scale() > decimals as u32 {
+            return Err(JupiterError::InvalidInput(format!(
+                "{} exceeds {} decimals",
+                value, decimals
+            )));
+        }
+        let mut scaled = value;
+        scaled.rescale(decimals as u32);
+        let raw = (scaled.scale() == decimals as u32)
+            .then(|| u64::try_from(scaled.mantissa()).ok())
+            .flatten()
+            .ok_or_else(|| {
+                JupiterError::InvalidInput(format!("{} overflows raw token units", value))
+            })?;
+        Ok(Self { raw, decimals })
+    }
+
+    /// Converts an `f64` display value, rounding down to the nearest raw unit
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::amount::TokenAmount;
+    ///
+    /// assert_eq!(TokenAmount::from_f64(0.1, 6).unwrap().raw, 100_000);
+    /// assert!(TokenAmount::from_f64(-1.0, 6).is_err());
+    /// ```
+    pub fn from_f64(value: f64, decimals: u8) -> Result<Self, JupiterError> {
+        let value = Decimal::from_f64(value)
+            .ok_or_else(|| JupiterError::InvalidInput(format!("invalid amount {}", value)))?;
+        Self::from_decimal(value.trunc_with_scale(decimals as u32), decimals)
+    }
+
+    /// Display value as an exact decimal
+    pub fn to_decimal(&self) -> Decimal {
+        Decimal::from_i128_with_scale(self.raw as i128, self.decimals.min(MAX_DECIMALS) as u32)
+    }
+
+    /// Display value as `f64`, which may lose precision for large amounts
+    pub fn to_f64(&self) -> f64 {
+        self.to_decimal().to_f64().unwrap_or(0.0)
+    }
+
+    /// Display string without trailing fractional zeros
+    pub fn to_ui_string(&self) -> String {
+        self.to_decimal().normalize().to_string()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.raw == 0
+    }
+
+    /// Adds two amounts of the same decimals
+    pub fn checked_add(&self, other: TokenAmount) -> Option<TokenAmount> {
+        self.same_decimals(&other)?;
+        Some(Self::new(self.raw.checked_add(other.raw)?, self.decimals))
+    }
+
+    /// Subtracts two amounts of the same decimals
+    pub fn checked_sub(&self, other: TokenAmount) -> Option<TokenAmount> {
+        self.same_decimals(&other)?;
+        Some(Self::new(self.raw.checked_sub(other.raw)?, self.decimals))
+    }
+
+    /// Subtracts two amounts of the same decimals, clamping at zero
+    pub fn saturating_sub(&self, other: TokenAmount) -> Option<TokenAmount> {
+        self.same_decimals(&other)?;
+        Some(Self::new(self.raw.saturating_sub(other.raw), self.decimals))
+    }
+
+    fn same_decimals(&self, other: &TokenAmount) -> Option<()> {
+        (self.decimals == other.decimals).then_some(())
+    }
+}
+
+impl std::fmt::Display for TokenAmount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_ui_string())
+    }
+}
+
+/// Serde adapter for amount fields sent as JSON strings by some API versions and as
+/// numbers by others; always serialized as a string
+///
+/// Pair it with `#[schemars(with = "String")]` under the `schema` feature.
+///
+/// # Example
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Fill {
+///     #[serde(with = "jup_sdk::amount::string_or_number")]
+///     amount: String,
+/// }
+///
+/// let text: Fill = serde_json::from_str(r#"{"amount":"1500"}"#).unwrap();
+/// let number: Fill = serde_json::from_str(r#"{"amount":1500}"#).unwrap();
+/// assert_eq!(text.amount, number.amount);
+/// assert_eq!(serde_json::to_string(&number).unwrap(), r#"{"amount":"1500"}"#);
+/// ```
+pub mod string_or_number {
+    use serde::{
+        Deserializer, Serializer,
+        de::{Error, Visitor},
+    };
+    use std::fmt;
+
+    pub fn serialize<S: Serializer>(value: &str, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(value)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+        deserializer.deserialize_any(StringOrNumber)
+    }
+
+    struct StringOrNumber;
+
+    impl Visitor<'_> for StringOrNumber {
+        type Value = String;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a numeric string or a number")
+        }
+
+        fn visit_str<E: Error>(self, value: &str) -> Result<String, E> {
+            Ok(value.to_string())
+        }
+
+        fn visit_string<E: Error>(self, value: String) -> Result<String, E> {
+            Ok(value)
+        }
+
+        fn visit_u64<E: Error>(self, value: u64) -> Result<String, E> {
+            Ok(value.to_string())
+        }
+
+        fn visit_i64<E: Error>(self, value: i64) -> Result<String, E> {
+            Ok(value.to_string())
+        }
+
+        fn visit_u128<E: Error>(self, value: u128) -> Result<String, E> {
+            Ok(value.to_string())
+        }
+
+        fn visit_f64<E: Error>(self, value: f64) -> Result<String, E> {
+            if !value.is_finite() {
+                return Err(E::custom(format!("invalid amount {}", value)));
+            }
+            Ok(value.to_string())
+        }
+    }
+}
@@ -0,0 +1,77 @@
+/// Associated token account module.
+/// Derivation, existence checks and idempotent creation of associated token accounts.
+use std::str::FromStr;
+
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+
+use crate::{
+    dex::{ASSOCIATED_TOKEN_PROGRAM_ID, SYSTEM_PROGRAM_ID},
+    tool::associated_token_address,
+};
+
+/// `CreateIdempotent` instruction discriminant of the associated token account program
+const CREATE_IDEMPOTENT: u8 = 1;
+
+/// State of an owner's associated token account for a mint
+#[derive(Debug, Clone, PartialEq)]
+pub struct AtaStatus {
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+    /// Associated token account address
+    pub address: Pubkey,
+    /// Token program owning the mint (SPL Token or Token-2022)
+    pub token_program: Pubkey,
+    pub exists: bool,
+    /// Idempotent creation instruction, present when the account is missing
+    pub create_instruction: Option<Instruction>,
+}
+
+/// Builds an idempotent instruction creating `owner`'s associated token account
+///
+/// The instruction succeeds without changes when the account already exists, so it
+/// can be prepended to any transaction.
+///
+/// # Params
+/// payer - Account paying the rent, must sign
+/// owner - Wallet owning the token account
+/// mint - Token mint
+/// token_program - Token program of the mint
+///
+/// # Example
+/// ```rust
+/// use jup_sdk::ata::create_associated_token_account_idempotent;
+/// use solana_sdk::pubkey::Pubkey;
+///
+/// let owner = Pubkey::new_unique();
+/// let mint = Pubkey::new_unique();
+/// let token_program = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA".parse().unwrap();
+/// let instruction = create_associated_token_account_idempotent(&owner, &owner, &mint, &token_program);
+/// assert_eq!(instruction.data, vec![1]);
+/// ```
+pub fn create_associated_token_account_idempotent(
+    payer: &Pubkey,
+    owner: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    let address = associated_token_address(owner, mint, token_program);
+    Instruction {
+        program_id: program_id(ASSOCIATED_TOKEN_PROGRAM_ID),
+        accounts: vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new(address, false),
+            AccountMeta::new_readonly(*owner, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new_readonly(program_id(SYSTEM_PROGRAM_ID), false),
+            AccountMeta::new_readonly(*token_program, false),
+        ],
+        data: vec![CREATE_IDEMPOTENT],
+    }
+}
+
+fn program_id(address: &str) -> Pubkey {
+    Pubkey::from_str(address).expect("built-in program ID is a valid pubkey")
+}
@@ -0,0 +1,278 @@
+/// Response cache module.
+/// Conditional-request caching for large endpoints and pluggable TTL cache backends.
+use async_trait::async_trait;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{Hash, Hasher};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Last cached response with the validators used to revalidate it
+struct ConditionalEntry<T> {
+    etag: Option<String>,
+    content_hash: u64,
+    value: T,
+}
+
+/// Single-slot cache for conditional (ETag / If-None-Match) requests
+///
+/// When the server answers 304 the cached value is reused; when it does not send
+/// an ETag, a hash of the body still avoids re-parsing unchanged content.
+pub struct ConditionalCache<T> {
+    entry: Mutex<Option<ConditionalEntry<T>>>,
+}
+
+impl<T> Default for ConditionalCache<T> {
+    fn default() -> Self {
+        Self {
+            entry: Mutex::new(None),
+        }
+    }
+}
+
+impl<T: Clone> ConditionalCache<T> {
+    /// Creates an empty cache
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// ETag of the cached response, to send as If-None-Match
+    pub fn etag(&self) -> Option<String> {
+        self.entry
+            .lock()
+            .unwrap()
+            .as_ref()
+            .and_then(|entry| entry.etag.clone())
+    }
+
+    /// Cached value, if any
+    pub fn cached(&self) -> Option<T> {
+        self.entry
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|entry| entry.value.clone())
+    }
+
+    /// Cached value if the new body hashes to the same content, refreshing its ETag
+    pub fn cached_if_unchanged(&self, etag: Option<String>, content_hash: u64) -> Option<T> {
+        let mut entry = self.entry.lock().unwrap();
+        let entry = entry.as_mut()?;
+        if entry.content_hash != content_hash {
+            return None;
+        }
+        entry.etag = etag;
+        Some(entry.value.clone())
+    }
+
+    /// Stores a freshly downloaded response
+    pub fn store(&self, etag: Option<String>, content_hash: u64, value: T) {
+        *self.entry.lock().unwrap() = Some(ConditionalEntry {
+            etag,
+            content_hash,
+            value,
+        });
+    }
+
+    /// Drops the cached response
+    pub fn clear(&self) {
+        *self.entry.lock().unwrap() = None;
+    }
+}
+
+/// Hashes a response body for change detection
+pub fn content_hash(body: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    body.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Key-value cache backend for API responses
+///
+/// Values are stored serialized so that in-process and remote backends behave the same.
+#[async_trait]
+pub trait Cache: Send + Sync {
+    /// Gets a value if present and not expired
+    async fn get(&self, key: &str) -> Option<Vec<u8>>;
+
+    /// Stores a value for `ttl`
+    async fn set(&self, key: &str, value: Vec<u8>, ttl: Duration);
+
+    /// Removes a value
+    async fn remove(&self, key: &str);
+}
+
+/// Per-endpoint cache TTLs; `None` disables caching for that endpoint
+#[derive(Debug, Clone)]
+pub struct CacheTtlConfig {
+    pub prices: Option<Duration>,
+    pub tokens: Option<Duration>,
+    pub route_map: Option<Duration>,
+    pub program_ids: Option<Duration>,
+    /// Memoizes quotes per (pair, amount, params); keep below a slot (~400ms)
+    pub quotes: Option<Duration>,
+}
+
+impl Default for CacheTtlConfig {
+    fn default() -> Self {
+        Self {
+            // Prices go stale within a slot, so they are only cached on request
+            prices: None,
+            tokens: Some(Duration::from_secs(300)),
+            route_map: Some(Duration::from_secs(300)),
+            program_ids: Some(Duration::from_secs(3600)),
+            // Quotes are only memoized on request, e.g. for UIs re-rendering within a slot
+            quotes: None,
+        }
+    }
+}
+
+/// In-memory cache evicting the least recently used entry when full
+#[derive(Debug)]
+pub struct LruCache {
+    capacity: usize,
+    state: Mutex<LruState>,
+}
+
+#[derive(Debug, Default)]
+struct LruState {
+    entries: HashMap<String, LruEntry>,
+    // Access tick -> key, oldest first
+    order: BTreeMap<u64, String>,
+    tick: u64,
+}
+
+#[derive(Debug)]
+struct LruEntry {
+    value: Vec<u8>,
+    expires_at: Instant,
+    tick: u64,
+}
+
+impl LruCache {
+    /// Creates a cache holding at most `capacity` entries
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            state: Mutex::new(LruState::default()),
+        }
+    }
+
+    /// Number of cached entries, including expired ones not yet evicted
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().entries.len()
+    }
+
+    /// Whether the cache holds no entries
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl LruState {
+    fn remove(&mut self, key: &str) -> Option<LruEntry> {
+        let entry = self.entries.remove(key)?;
+        self.order.remove(&entry.tick);
+        Some(entry)
+    }
+
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+}
+
+#[async_trait]
+impl Cache for LruCache {
+    async fn get(&self, key: &str) -> Option<Vec<u8>> {
+        let mut state = self.state.lock().unwrap();
+        let mut entry = state.remove(key)?;
+        if entry.expires_at <= Instant::now() {
+            return None;
+        }
+        let value = entry.value.clone();
+        entry.tick = state.next_tick();
+        state.order.insert(entry.tick, key.to_string());
+        state.entries.insert(key.to_string(), entry);
+        Some(value)
+    }
+
+    async fn set(&self, key: &str, value: Vec<u8>, ttl: Duration) {
+        let mut state = self.state.lock().unwrap();
+        state.remove(key);
+        let tick = state.next_tick();
+        state.order.insert(tick, key.to_string());
+        state.entries.insert(
+            key.to_string(),
+            LruEntry {
+                value,
+                expires_at: Instant::now() + ttl,
+                tick,
+            },
+        );
+        while state.entries.len() > self.capacity {
+            let Some((_, oldest)) = state.order.pop_first() else {
+                break;
+            };
+            state.entries.remove(&oldest);
+        }
+    }
+
+    async fn remove(&self, key: &str) {
+        self.state.lock().unwrap().remove(key);
+    }
+}
+
+/// Redis-backed cache, shareable between processes
+#[cfg(feature = "redis")]
+pub struct RedisCache {
+    client: redis::Client,
+    prefix: String,
+}
+
+#[cfg(feature = "redis")]
+impl RedisCache {
+    /// Creates a cache from a Redis URL, namespacing keys with `prefix`
+    pub fn new(url: &str, prefix: &str) -> Result<Self, crate::types::JupiterError> {
+        let client = redis::Client::open(url)
+            .map_err(|e| crate::types::JupiterError::Error(format!("redis error: {}", e)))?;
+        Ok(Self {
+            client,
+            prefix: prefix.to_string(),
+        })
+    }
+
+    fn key(&self, key: &str) -> String {
+        format!("{}{}", self.prefix, key)
+    }
+}
+
+#[cfg(feature = "redis")]
+#[async_trait]
+impl Cache for RedisCache {
+    async fn get(&self, key: &str) -> Option<Vec<u8>> {
+        use redis::AsyncCommands;
+        let mut connection = self.client.get_multiplexed_async_connection().await.ok()?;
+        connection
+            .get::<_, Option<Vec<u8>>>(self.key(key))
+            .await
+            .ok()
+            .flatten()
+    }
+
+    async fn set(&self, key: &str, value: Vec<u8>, ttl: Duration) {
+        use redis::AsyncCommands;
+        if let Ok(mut connection) = self.client.get_multiplexed_async_connection().await {
+            let _ = connection
+                .pset_ex::<_, _, ()>(self.key(key), value, (ttl.as_millis() as u64).max(1))
+                .await;
+        }
+    }
+
+    async fn remove(&self, key: &str) {
+        use redis::AsyncCommands;
+        if let Ok(mut connection) = self.client.get_multiplexed_async_connection().await {
+            let _ = connection.del::<_, ()>(self.key(key)).await;
+        }
+    }
+}
@@ -0,0 +1,69 @@
+/// Request coalescing module.
+/// Shares one upstream call between concurrent callers asking for the same thing.
+use crate::types::JupiterError;
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex};
+use tokio::sync::OnceCell;
+
+type Flight<V> = Arc<OnceCell<Result<V, JupiterError>>>;
+
+/// Single-flight group: concurrent calls with the same key run the operation once
+/// and all receive a clone of its result.
+pub struct SingleFlight<K, V> {
+    inflight: Mutex<HashMap<K, Flight<V>>>,
+}
+
+impl<K, V> Default for SingleFlight<K, V> {
+    fn default() -> Self {
+        Self {
+            inflight: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl<K, V> SingleFlight<K, V>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+{
+    /// Creates an empty single-flight group
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Runs `operation` unless a call with the same key is already in flight,
+    /// in which case its result is awaited and shared instead
+    ///
+    /// # Params
+    /// key - Identity of the call
+    /// operation - Closure producing the upstream call
+    pub async fn run<F, Fut>(&self, key: K, operation: F) -> Result<V, JupiterError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<V, JupiterError>>,
+    {
+        let flight = self
+            .inflight
+            .lock()
+            .unwrap()
+            .entry(key.clone())
+            .or_default()
+            .clone();
+        let result = flight.get_or_init(operation).await.clone();
+        let mut inflight = self.inflight.lock().unwrap();
+        if inflight
+            .get(&key)
+            .is_some_and(|current| Arc::ptr_eq(current, &flight))
+        {
+            inflight.remove(&key);
+        }
+        result
+    }
+
+    /// Number of distinct calls currently in flight
+    pub fn in_flight(&self) -> usize {
+        self.inflight.lock().unwrap().len()
+    }
+}
@@ -0,0 +1,274 @@
+/// Transaction composition module.
+/// Assembles versioned transactions from user instructions around Jupiter swap instructions.
+use std::str::FromStr;
+
+use solana_sdk::{
+    hash::Hash,
+    instruction::Instruction,
+    message::{AddressLookupTableAccount, VersionedMessage, v0},
+    pubkey::Pubkey,
+    signature::Signature,
+    transaction::VersionedTransaction,
+};
+
+use crate::{
+    alt::LookupTable,
+    dex::COMPUTE_BUDGET_PROGRAM_ID,
+    tx::validate_transaction_size,
+    types::{JupiterError, SwapInstructionsResponse},
+};
+
+/// Composes `[compute budget] + [user setup] + [Jupiter swap] + [user cleanup]`
+/// into a single ALT-aware versioned transaction
+///
+/// Compute budget instructions are placed first and deduplicated: Jupiter's unit
+/// limit and price are kept unless overridden, and the limit is raised by
+/// `extra_compute_units` to cover the user instructions.
+///
+/// # Example
+/// ```rust
+/// use jup_sdk::{compose::TransactionComposer, types::SwapInstructionsResponse};
+/// use solana_sdk::{hash::Hash, instruction::Instruction, pubkey::Pubkey};
+///
+/// fn example(
+///     user: Pubkey,
+///     swap: &SwapInstructionsResponse,
+///     deposit: Instruction,
+///     blockhash: Hash,
+/// ) -> Result<(), Box<dyn std::error::Error>> {
+///     let transaction = TransactionComposer::new(user)
+///         .with_cleanup_instructions(vec![deposit])
+///         .with_extra_compute_units(50_000)
+///         .compose(swap, blockhash)?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TransactionComposer {
+    payer: Pubkey,
+    setup_instructions: Vec<Instruction>,
+    cleanup_instructions: Vec<Instruction>,
+    compute_unit_limit: Option<u32>,
+    compute_unit_price: Option<u64>,
+    extra_compute_units: u32,
+    lookup_tables: Vec<LookupTable>,
+}
+
+impl TransactionComposer {
+    /// Creates a composer for transactions paid and signed by `payer`
+    pub fn new(payer: Pubkey) -> Self {
+        Self {
+            payer,
+            setup_instructions: Vec::new(),
+            cleanup_instructions: Vec::new(),
+            compute_unit_limit: None,
+            compute_unit_price: None,
+            extra_compute_units: 0,
+            lookup_tables: Vec::new(),
+        }
+    }
+
+    /// Instructions executed before the Jupiter setup and swap instructions
+    pub fn with_setup_instructions(mut self, instructions: Vec<Instruction>) -> Self {
+        self.setup_instructions = instructions;
+        self
+    }
+
+    /// Instructions executed after the Jupiter swap and cleanup instructions
+    pub fn with_cleanup_instructions(mut self, instructions: Vec<Instruction>) -> Self {
+        self.cleanup_instructions = instructions;
+        self
+    }
+
+    /// Overrides the compute unit limit
+    pub fn with_compute_unit_limit(mut self, units: u32) -> Self {
+        self.compute_unit_limit = Some(units);
+        self
+    }
+
+    /// Overrides the compute unit price in micro-lamports
+    pub fn with_compute_unit_price(mut self, micro_lamports: u64) -> Self {
+        self.compute_unit_price = Some(micro_lamports);
+        self
+    }
+
+    /// Compute units added to Jupiter's limit for the user instructions
+    pub fn with_extra_compute_units(mut self, units: u32) -> Self {
+        self.extra_compute_units = units;
+        self
+    }
+
+    /// Additional lookup tables, e.g. the user's own, used alongside Jupiter's
+    pub fn with_lookup_tables(mut self, tables: Vec<LookupTable>) -> Self {
+        self.lookup_tables = tables;
+        self
+    }
+
+    /// Adds lookup tables not already provided
+    pub fn with_additional_lookup_tables(mut self, tables: Vec<LookupTable>) -> Self {
+        for table in tables {
+            if !self.lookup_tables.iter().any(|t| t.key == table.key) {
+                self.lookup_tables.push(table);
+            }
+        }
+        self
+    }
+
+    /// Builds the ordered instruction list
+    pub fn instructions(
+        &self,
+        swap: &SwapInstructionsResponse,
+    ) -> Result<Vec<Instruction>, JupiterError> {
+        self.instructions_many(std::slice::from_ref(swap))
+    }
+
+    /// Builds the ordered instruction list running several swaps in order
+    ///
+    /// Jupiter's compute unit limits are summed and the highest unit price is kept.
+    pub fn instructions_many(
+        &self,
+        swaps: &[SwapInstructionsResponse],
+    ) -> Result<Vec<Instruction>, JupiterError> {
+        let compute_budget_program = compute_budget_program();
+        let mut limit: Option<u32> = None;
+        let mut price: Option<u64> = None;
+        for instruction in swaps
+            .iter()
+            .flat_map(|swap| &swap.compute_budget_instructions)
+        {
+            let instruction = instruction.to_instruction()?;
+            match instruction.data.split_first() {
+                Some((&2, rest)) => {
+                    if let Some(b) = rest.get(..4) {
+                        let units = u32::from_le_bytes([b[0], b[1], b[2], b[3]]);
+                        limit = Some(limit.unwrap_or(0).saturating_add(units));
+                    }
+                }
+                Some((&3, rest)) => {
+                    if let Some(micro_lamports) = rest
+                        .get(..8)
+                        .and_then(|b| b.try_into().ok())
+                        .map(u64::from_le_bytes)
+                    {
+                        price = price.max(Some(micro_lamports));
+                    }
+                }
+                _ => {}
+            }
+        }
+        let limit = self
+            .compute_unit_limit
+            .or_else(|| limit.map(|limit: u32| limit.saturating_add(self.extra_compute_units)));
+        let price = self.compute_unit_price.or(price);
+
+        let mut instructions = Vec::new();
+        if let Some(limit) = limit {
+            instructions.push(compute_budget_instruction(2, &limit.to_le_bytes()));
+        }
+        if let Some(price) = price {
+            instructions.push(compute_budget_instruction(3, &price.to_le_bytes()));
+        }
+        // User compute budget instructions would conflict with the ones above
+        let user = |instructions: &[Instruction]| -> Vec<Instruction> {
+            instructions
+                .iter()
+                .filter(|i| i.program_id != compute_budget_program)
+                .cloned()
+                .collect()
+        };
+        instructions.extend(user(&self.setup_instructions));
+        for swap in swaps {
+            for instruction in swap
+                .token_ledger_instruction
+                .iter()
+                .chain(&swap.setup_instructions)
+                .chain(std::iter::once(&swap.swap_instruction))
+                .chain(&swap.cleanup_instruction)
+            {
+                instructions.push(instruction.to_instruction()?);
+            }
+        }
+        instructions.extend(user(&self.cleanup_instructions));
+        for instruction in swaps.iter().flat_map(|swap| &swap.other_instructions) {
+            instructions.push(instruction.to_instruction()?);
+        }
+        Ok(instructions)
+    }
+
+    /// Compiles an unsigned versioned transaction
+    ///
+    /// # Params
+    /// swap - Swap instructions returned by the API
+    /// recent_blockhash - Blockhash the transaction is valid for
+    ///
+    /// Fails with `JupiterError::ValidationError` when the transaction exceeds the
+    /// packet size limit, or when a lookup table referenced by `swap` is missing.
+    pub fn compose(
+        &self,
+        swap: &SwapInstructionsResponse,
+        recent_blockhash: Hash,
+    ) -> Result<VersionedTransaction, JupiterError> {
+        self.compose_many(std::slice::from_ref(swap), recent_blockhash)
+    }
+
+    /// Compiles an unsigned versioned transaction running several swaps atomically,
+    /// e.g. the legs of a split order
+    ///
+    /// Fails like `compose`; several routes rarely fit one transaction without
+    /// lookup tables.
+    pub fn compose_many(
+        &self,
+        swaps: &[SwapInstructionsResponse],
+        recent_blockhash: Hash,
+    ) -> Result<VersionedTransaction, JupiterError> {
+        for address in swaps
+            .iter()
+            .flat_map(|swap| &swap.address_lookup_table_addresses)
+        {
+            let key = parse_pubkey(address)?;
+            if !self.lookup_tables.iter().any(|table| table.key == key) {
+                return Err(JupiterError::ValidationError(format!(
+                    "lookup table {} not provided",
+                    key
+                )));
+            }
+        }
+        let instructions = self.instructions_many(swaps)?;
+        let lookup_tables: Vec<AddressLookupTableAccount> = self
+            .lookup_tables
+            .iter()
+            .map(|table| AddressLookupTableAccount {
+                key: table.key,
+                addresses: table.addresses.clone(),
+            })
+            .collect();
+        let message =
+            v0::Message::try_compile(&self.payer, &instructions, &lookup_tables, recent_blockhash)
+                .map_err(|e| JupiterError::ValidationError(format!("compile message: {}", e)))?;
+        let num_signatures = message.header.num_required_signatures as usize;
+        let transaction = VersionedTransaction {
+            signatures: vec![Signature::default(); num_signatures],
+            message: VersionedMessage::V0(message),
+        };
+        validate_transaction_size(&transaction)?;
+        Ok(transaction)
+    }
+}
+
+fn parse_pubkey(address: &str) -> Result<Pubkey, JupiterError> {
+    Pubkey::from_str(address).map_err(|e| JupiterError::ParseError(format!("{}: {}", address, e)))
+}
+
+fn compute_budget_program() -> Pubkey {
+    Pubkey::from_str(COMPUTE_BUDGET_PROGRAM_ID).expect("compute budget program ID is valid")
+}
+
+fn compute_budget_instruction(discriminant: u8, value: &[u8]) -> Instruction {
+    let mut data = vec![discriminant];
+    data.extend_from_slice(value);
+    Instruction {
+        program_id: compute_budget_program(),
+        accounts: Vec::new(),
+        data,
+    }
+}
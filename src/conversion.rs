@@ -0,0 +1,236 @@
+/// Conversion module.
+/// Ranks conversions of one input token into candidate outputs by USD value, combining
+/// quotes with the price API and token registry.
+use rust_decimal::{Decimal, RoundingStrategy, prelude::FromPrimitive};
+use std::{
+    cmp::Ordering,
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    amount::TokenAmount,
+    types::{JupiterError, QuoteResponse},
+};
+
+/// USD value of a raw token amount
+///
+/// # Params
+/// amount - Raw token amount
+/// decimals - Decimals of the mint
+/// price - USD price of one whole token
+pub fn usd_value(amount: u64, decimals: u8, price: f64) -> f64 {
+    amount as f64 / 10f64.powi(decimals as i32) * price
+}
+
+/// Rounding of a converted amount to whole raw units
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Rounding {
+    /// Towards zero, never overstating the converted amount
+    #[default]
+    Down,
+    /// Away from zero, never understating the converted amount
+    Up,
+    /// To the nearest raw unit, halves to even
+    Nearest,
+}
+
+impl Rounding {
+    fn strategy(self) -> RoundingStrategy {
+        match self {
+            Rounding::Down => RoundingStrategy::ToZero,
+            Rounding::Up => RoundingStrategy::AwayFromZero,
+            Rounding::Nearest => RoundingStrategy::MidpointNearestEven,
+        }
+    }
+}
+
+/// Converts a raw amount between tokens at their USD prices
+///
+/// Returns `None` for non-positive or non-finite prices and results overflowing `u64`.
+///
+/// # Params
+/// amount - Raw amount of the source token
+/// from_decimals - Decimals of the source mint
+/// from_price - USD price of one whole source token
+/// to_decimals - Decimals of the target mint
+/// to_price - USD price of one whole target token
+/// rounding - Rounding to whole raw units of the target
+///
+/// # Example
+/// ```rust
+/// use jup_sdk::conversion::{Rounding, convert_raw};
+///
+/// // 1.5 SOL at $150 into USDC at $1
+/// assert_eq!(convert_raw(1_500_000_000, 9, 150.0, 6, 1.0, Rounding::Down), Some(225_000_000));
+/// assert_eq!(convert_raw(1, 6, 1.0, 6, 3.0, Rounding::Down), Some(0));
+/// assert_eq!(convert_raw(1, 6, 1.0, 6, 3.0, Rounding::Up), Some(1));
+/// ```
+pub fn convert_raw(
+    amount: u64,
+    from_decimals: u8,
+    from_price: f64,
+    to_decimals: u8,
+    to_price: f64,
+    rounding: Rounding,
+) -> Option<u64> {
+    let price = |price: f64| {
+        Decimal::from_f64(price).filter(|price| price.is_sign_positive() && !price.is_zero())
+    };
+    let (from_price, to_price) = (price(from_price)?, price(to_price)?);
+    let value = TokenAmount::new(amount, from_decimals)
+        .to_decimal()
+        .checked_mul(from_price)?
+        .checked_div(to_price)?;
+    let raw = value
+        .checked_mul(Decimal::from(10u64.checked_pow(to_decimals as u32)?))?
+        .round_dp_with_strategy(0, rounding.strategy());
+    u64::try_from(raw).ok()
+}
+
+/// Amount converted between tokens at price API rates
+#[derive(Debug, Clone, PartialEq)]
+pub struct AmountConversion {
+    pub from_mint: String,
+    pub to_mint: String,
+    pub from: TokenAmount,
+    pub to: TokenAmount,
+    /// USD value of `from`
+    pub usd_value: f64,
+    /// Whole target tokens per whole source token
+    pub rate: f64,
+    pub rounding: Rounding,
+    /// When the conversion was computed
+    pub priced_at: SystemTime,
+    /// Upper bound of the age of the prices used: the client's price cache TTL, or
+    /// `None` when prices aren't cached and were fetched for this conversion
+    pub max_staleness: Option<Duration>,
+}
+
+impl AmountConversion {
+    /// Whether the prices may be older than `max_age`
+    pub fn may_be_older_than(&self, max_age: Duration) -> bool {
+        let age = self.priced_at.elapsed().unwrap_or_default();
+        age + self.max_staleness.unwrap_or_default() > max_age
+    }
+}
+
+/// Quote of one candidate output
+#[derive(Debug, Clone)]
+pub struct OutputQuote {
+    pub output_mint: String,
+    /// Quote, or the error that prevented quoting this output
+    pub quote: Result<QuoteResponse, JupiterError>,
+    /// Output amount (raw units), 0 without a quote
+    pub out_amount: u64,
+    /// USD value of `out_amount`, `None` without a quote or a price
+    pub output_usd: Option<f64>,
+}
+
+impl OutputQuote {
+    pub fn price_impact_pct(&self) -> Option<f64> {
+        self.quote
+            .as_ref()
+            .ok()
+            .and_then(|quote| quote.price_impact_pct.parse().ok())
+    }
+}
+
+/// Quotes of one input against many outputs, ranked by output USD value
+#[derive(Debug, Clone)]
+pub struct OutputQuoteTable {
+    pub input_mint: String,
+    /// Input amount (raw units)
+    pub amount: u64,
+    /// USD value of the input, `None` without a price
+    pub input_usd: Option<f64>,
+    /// Best first; unpriced outputs follow priced ones, failed quotes come last
+    pub rows: Vec<OutputQuote>,
+}
+
+impl OutputQuoteTable {
+    /// Builds the table, ranking `rows` by output USD value
+    pub fn new(
+        input_mint: &str,
+        amount: u64,
+        input_usd: Option<f64>,
+        mut rows: Vec<OutputQuote>,
+    ) -> Self {
+        rows.sort_by(|a, b| {
+            let rank = |row: &OutputQuote| (row.quote.is_ok(), row.output_usd.is_some());
+            rank(b).cmp(&rank(a)).then_with(|| {
+                b.output_usd
+                    .partial_cmp(&a.output_usd)
+                    .unwrap_or(Ordering::Equal)
+            })
+        });
+        Self {
+            input_mint: input_mint.to_string(),
+            amount,
+            input_usd,
+            rows,
+        }
+    }
+
+    /// Output with the highest USD value
+    pub fn best(&self) -> Option<&OutputQuote> {
+        self.rows.first().filter(|row| row.output_usd.is_some())
+    }
+
+    /// Share of the input's USD value kept by `row`, e.g. 0.995 for 0.5% lost to
+    /// fees and price impact
+    pub fn value_retained(&self, row: &OutputQuote) -> Option<f64> {
+        match (self.input_usd, row.output_usd) {
+            (Some(input), Some(output)) if input > 0.0 => Some(output / input),
+            _ => None,
+        }
+    }
+}
+
+/// Cheapest conversion of an input into one of several candidates for a USD target
+#[derive(Debug, Clone)]
+pub struct ConversionPlan {
+    pub input_mint: String,
+    /// Input amount (raw units) sized to the USD target
+    pub input_amount: u64,
+    /// USD value of the input
+    pub input_usd: f64,
+    /// Candidate keeping the most USD value
+    pub best: OutputQuote,
+    /// USD value lost to fees, price impact and price disagreement
+    pub cost_usd: f64,
+    /// `cost_usd` relative to `input_usd`, in basis points
+    pub cost_bps: f64,
+    /// Remaining candidates, best first
+    pub alternatives: Vec<OutputQuote>,
+}
+
+impl ConversionPlan {
+    /// Picks the best row of a ranked table
+    ///
+    /// # Params
+    /// table - Quotes of the sized input against the candidates
+    /// usd_target - Target USD value, used when the table has no input price
+    pub fn from_table(table: OutputQuoteTable, usd_target: f64) -> Result<Self, JupiterError> {
+        let input_usd = table.input_usd.unwrap_or(usd_target);
+        let mut rows = table.rows.into_iter();
+        let Some(best) = rows.next().filter(|row| row.output_usd.is_some()) else {
+            return Err(JupiterError::Error(
+                "no candidate could be quoted and priced".to_string(),
+            ));
+        };
+        let cost_usd = input_usd - best.output_usd.unwrap_or_default();
+        Ok(Self {
+            input_mint: table.input_mint,
+            input_amount: table.amount,
+            input_usd,
+            cost_bps: if input_usd > 0.0 {
+                cost_usd / input_usd * 10_000.0
+            } else {
+                0.0
+            },
+            cost_usd,
+            best,
+            alternatives: rows.collect(),
+        })
+    }
+}
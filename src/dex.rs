@@ -0,0 +1,290 @@
+/// DEX registry module.
+/// Maps AMM program IDs and Jupiter route labels to structured venue identifiers.
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use solana_sdk::pubkey::Pubkey;
+use std::{collections::HashMap, str::FromStr};
+
+/// Jupiter aggregator program ID
+pub const JUPITER_PROGRAM_ID: &str = "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4";
+
+/// System program ID
+pub const SYSTEM_PROGRAM_ID: &str = "11111111111111111111111111111111";
+/// Compute budget program ID
+pub const COMPUTE_BUDGET_PROGRAM_ID: &str = "ComputeBudget111111111111111111111111111111";
+/// SPL Token program ID
+pub const TOKEN_PROGRAM_ID: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+/// SPL Token-2022 program ID
+pub const TOKEN_2022_PROGRAM_ID: &str = "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
+/// Associated token account program ID
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: &str = "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
+/// SPL Memo program ID
+pub const MEMO_PROGRAM_ID: &str = "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr";
+
+/// Runtime and SPL programs that appear in Jupiter swap transactions
+const CORE_PROGRAMS: [(&str, &str); 6] = [
+    (SYSTEM_PROGRAM_ID, "System Program"),
+    (COMPUTE_BUDGET_PROGRAM_ID, "Compute Budget"),
+    (TOKEN_PROGRAM_ID, "Token Program"),
+    (TOKEN_2022_PROGRAM_ID, "Token-2022 Program"),
+    (
+        ASSOCIATED_TOKEN_PROGRAM_ID,
+        "Associated Token Account Program",
+    ),
+    (MEMO_PROGRAM_ID, "Memo Program"),
+];
+
+/// Structured identifier of a DEX/venue used in Jupiter routes
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DexId {
+    Raydium,
+    RaydiumClmm,
+    RaydiumCp,
+    Whirlpool,
+    OrcaV2,
+    Meteora,
+    MeteoraDlmm,
+    Phoenix,
+    OpenBookV2,
+    LifinityV2,
+    PumpFun,
+    PumpAmm,
+    Saber,
+    /// Venue not present in the registry, holding the raw label or program ID
+    Unknown(String),
+}
+
+impl DexId {
+    /// All venues known to the registry
+    pub fn all() -> Vec<DexId> {
+        vec![
+            DexId::Raydium,
+            DexId::RaydiumClmm,
+            DexId::RaydiumCp,
+            DexId::Whirlpool,
+            DexId::OrcaV2,
+            DexId::Meteora,
+            DexId::MeteoraDlmm,
+            DexId::Phoenix,
+            DexId::OpenBookV2,
+            DexId::LifinityV2,
+            DexId::PumpFun,
+            DexId::PumpAmm,
+            DexId::Saber,
+        ]
+    }
+
+    /// Label used by the Jupiter API (route plan `label`, `dexes`/`excludeDexes` params)
+    pub fn label(&self) -> &str {
+        match self {
+            DexId::Raydium => "Raydium",
+            DexId::RaydiumClmm => "Raydium CLMM",
+            DexId::RaydiumCp => "Raydium CP",
+            DexId::Whirlpool => "Whirlpool",
+            DexId::OrcaV2 => "Orca V2",
+            DexId::Meteora => "Meteora",
+            DexId::MeteoraDlmm => "Meteora DLMM",
+            DexId::Phoenix => "Phoenix",
+            DexId::OpenBookV2 => "OpenBook V2",
+            DexId::LifinityV2 => "Lifinity V2",
+            DexId::PumpFun => "Pump.fun",
+            DexId::PumpAmm => "Pump.fun Amm",
+            DexId::Saber => "Saber",
+            DexId::Unknown(label) => label,
+        }
+    }
+
+    /// On-chain program ID of the venue, if known
+    pub fn program_id(&self) -> Option<&'static str> {
+        match self {
+            DexId::Raydium => Some("675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8"),
+            DexId::RaydiumClmm => Some("CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK"),
+            DexId::RaydiumCp => Some("CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C"),
+            DexId::Whirlpool => Some("whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc"),
+            DexId::OrcaV2 => Some("9W959DqEETiGZocYWCQPaJ6sBmUzgfxXfqGeTEdp3aQP"),
+            DexId::Meteora => Some("Eo7WjKq67rjJQSZxS6z3YkapzY3eMj6Xy8X5EQVn5UaB"),
+            DexId::MeteoraDlmm => Some("LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo"),
+            DexId::Phoenix => Some("PhoeNiXZ8ByJGLkxNfZRnkUfjvmuYqLR89jjFHGqdXY"),
+            DexId::OpenBookV2 => Some("opnb2LAfJYbRMAHHvqjCwQxanZn7ReEHp1k81EohpZb"),
+            DexId::LifinityV2 => Some("2wT8Yq49kHgDzXuPxZSaeLaH1qbmGXtEvPy64bL7aD3c"),
+            DexId::PumpFun => Some("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P"),
+            DexId::PumpAmm => Some("pAMMBay6oceH9fJKBRHGP5D4bD4sWpmSwMn52FMfXEA"),
+            DexId::Saber => Some("SSwpkEEcbUqx4vtoEByFjSkhKdCT862DNVb52nZg1UZ"),
+            DexId::Unknown(_) => None,
+        }
+    }
+
+    /// Resolves a Jupiter route label (case-insensitive), falling back to `Unknown`
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::dex::DexId;
+    ///
+    /// assert_eq!(DexId::from_label("meteora dlmm"), DexId::MeteoraDlmm);
+    /// assert_eq!(DexId::from_label("NewDex"), DexId::Unknown("NewDex".to_string()));
+    /// ```
+    pub fn from_label(label: &str) -> DexId {
+        DexId::all()
+            .into_iter()
+            .find(|dex| dex.label().eq_ignore_ascii_case(label.trim()))
+            .unwrap_or_else(|| DexId::Unknown(label.to_string()))
+    }
+
+    /// Resolves an AMM program ID, falling back to `Unknown`
+    pub fn from_program_id(program_id: &str) -> DexId {
+        DexId::all()
+            .into_iter()
+            .find(|dex| dex.program_id() == Some(program_id))
+            .unwrap_or_else(|| DexId::Unknown(program_id.to_string()))
+    }
+
+    /// Whether the venue is present in the registry
+    pub fn is_known(&self) -> bool {
+        !matches!(self, DexId::Unknown(_))
+    }
+}
+
+/// Empty `Unknown` label, used by `Default` fixtures of route types
+impl Default for DexId {
+    fn default() -> Self {
+        DexId::Unknown(String::new())
+    }
+}
+
+impl std::fmt::Display for DexId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.label())
+    }
+}
+
+impl Serialize for DexId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.label())
+    }
+}
+
+impl<'de> Deserialize<'de> for DexId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let label = String::deserialize(deserializer)?;
+        let dex = DexId::from_label(&label);
+        // Strict builds reject venues missing from the registry instead of keeping them
+        // as `Unknown`, so new upstream venues surface in CI
+        #[cfg(feature = "strict-serde")]
+        if !dex.is_known() {
+            return Err(serde::de::Error::custom(format!(
+                "unknown venue label {}",
+                label
+            )));
+        }
+        Ok(dex)
+    }
+}
+
+/// Schema of the route label `DexId` serializes to
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for DexId {
+    fn schema_name() -> String {
+        "DexId".to_string()
+    }
+
+    fn json_schema(generator: &mut schemars::r#gen::SchemaGenerator) -> schemars::schema::Schema {
+        String::json_schema(generator)
+    }
+}
+
+/// Category of a program involved in a Jupiter swap
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ProgramCategory {
+    /// Jupiter aggregator program
+    Aggregator,
+    /// AMM or order book venue
+    Amm,
+    /// Runtime or SPL program (system, token, compute budget, ...)
+    Core,
+}
+
+/// Program ID with its label and category
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProgramInfo {
+    pub program_id: Pubkey,
+    pub label: String,
+    /// Venue resolved from the label
+    pub dex: DexId,
+    pub category: ProgramCategory,
+}
+
+/// Typed list of the programs that Jupiter swap transactions may invoke
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProgramIdList {
+    pub programs: Vec<ProgramInfo>,
+}
+
+impl ProgramIdList {
+    /// Builds the list from the API's program ID to label map, adding the Jupiter
+    /// aggregator and core runtime/SPL programs
+    ///
+    /// Entries whose program ID is not a valid pubkey are skipped.
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::dex::{DexId, ProgramIdList, ProgramCategory};
+    /// use std::collections::HashMap;
+    ///
+    /// let mut labels = HashMap::new();
+    /// labels.insert(
+    ///     "whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc".to_string(),
+    ///     "Whirlpool".to_string(),
+    /// );
+    /// let list = ProgramIdList::from_labels(labels);
+    /// let whirlpool = list.get(&"whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc".parse().unwrap()).unwrap();
+    /// assert_eq!(whirlpool.dex, DexId::Whirlpool);
+    /// assert_eq!(whirlpool.category, ProgramCategory::Amm);
+    /// ```
+    pub fn from_labels(labels: HashMap<String, String>) -> Self {
+        let mut programs: Vec<ProgramInfo> = Vec::new();
+        let mut push = |program_id: &str, label: &str, category: ProgramCategory| {
+            if let Ok(program_id) = Pubkey::from_str(program_id)
+                && !programs.iter().any(|p| p.program_id == program_id)
+            {
+                programs.push(ProgramInfo {
+                    program_id,
+                    label: label.to_string(),
+                    dex: DexId::from_label(label),
+                    category,
+                });
+            }
+        };
+        push(JUPITER_PROGRAM_ID, "Jupiter", ProgramCategory::Aggregator);
+        for (program_id, label) in CORE_PROGRAMS {
+            push(program_id, label, ProgramCategory::Core);
+        }
+        let mut labels: Vec<(String, String)> = labels.into_iter().collect();
+        labels.sort();
+        for (program_id, label) in &labels {
+            push(program_id, label, ProgramCategory::Amm);
+        }
+        Self { programs }
+    }
+
+    /// Looks up a program
+    pub fn get(&self, program_id: &Pubkey) -> Option<&ProgramInfo> {
+        self.programs.iter().find(|p| &p.program_id == program_id)
+    }
+
+    /// Whether the program is the Jupiter aggregator, a known venue or a core program
+    pub fn is_known_program(&self, program_id: &Pubkey) -> bool {
+        self.get(program_id).is_some()
+    }
+
+    /// Label of a program, if known
+    pub fn label(&self, program_id: &Pubkey) -> Option<&str> {
+        self.get(program_id).map(|p| p.label.as_str())
+    }
+
+    /// Programs of a category
+    pub fn by_category(&self, category: ProgramCategory) -> Vec<&ProgramInfo> {
+        self.programs
+            .iter()
+            .filter(|p| p.category == category)
+            .collect()
+    }
+}
@@ -0,0 +1,356 @@
+/// Export module.
+/// Writes token lists, quote ladders and swap history to CSV, and to Parquet behind the
+/// `parquet` feature, for offline analysis.
+use serde::{Deserialize, Serialize};
+use std::{fs::File, io::Write, path::Path};
+
+use crate::{
+    liquidity::DepthPoint,
+    types::{JupiterError, SwapExecutionResult, TokenInfo},
+};
+
+/// Type of an exported column
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnKind {
+    Text,
+    UInt,
+    Float,
+    Bool,
+}
+
+/// Name and type of an exported column
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Column {
+    pub name: &'static str,
+    pub kind: ColumnKind,
+}
+
+const fn column(name: &'static str, kind: ColumnKind) -> Column {
+    Column { name, kind }
+}
+
+/// Value of a single exported cell
+#[derive(Debug, Clone, PartialEq)]
+pub enum Field {
+    Text(String),
+    UInt(u64),
+    Float(f64),
+    Bool(bool),
+    /// Empty CSV cell, Parquet null
+    Missing,
+}
+
+impl Field {
+    fn to_csv(&self) -> String {
+        match self {
+            Field::Text(value) => escape_csv(value),
+            Field::UInt(value) => value.to_string(),
+            Field::Float(value) => value.to_string(),
+            Field::Bool(value) => value.to_string(),
+            Field::Missing => String::new(),
+        }
+    }
+}
+
+impl<T: Into<Field>> From<Option<T>> for Field {
+    fn from(value: Option<T>) -> Self {
+        value.map(Into::into).unwrap_or(Field::Missing)
+    }
+}
+
+impl From<String> for Field {
+    fn from(value: String) -> Self {
+        Field::Text(value)
+    }
+}
+
+impl From<&str> for Field {
+    fn from(value: &str) -> Self {
+        Field::Text(value.to_string())
+    }
+}
+
+impl From<u64> for Field {
+    fn from(value: u64) -> Self {
+        Field::UInt(value)
+    }
+}
+
+impl From<f64> for Field {
+    fn from(value: f64) -> Self {
+        Field::Float(value)
+    }
+}
+
+impl From<bool> for Field {
+    fn from(value: bool) -> Self {
+        Field::Bool(value)
+    }
+}
+
+/// Quotes a CSV value when it contains a separator, quote or line break
+fn escape_csv(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// A type exportable as one row per value
+pub trait ExportRecord {
+    /// Columns of every row, in order
+    fn columns() -> Vec<Column>;
+
+    /// Cells of this row, matching `columns`
+    fn fields(&self) -> Vec<Field>;
+}
+
+impl ExportRecord for TokenInfo {
+    fn columns() -> Vec<Column> {
+        vec![
+            column("address", ColumnKind::Text),
+            column("symbol", ColumnKind::Text),
+            column("name", ColumnKind::Text),
+            column("decimals", ColumnKind::UInt),
+            column("chain_id", ColumnKind::UInt),
+            column("tags", ColumnKind::Text),
+            column("coingecko_id", ColumnKind::Text),
+            column("transfer_fee_bps", ColumnKind::UInt),
+        ]
+    }
+
+    fn fields(&self) -> Vec<Field> {
+        vec![
+            self.address.as_str().into(),
+            self.symbol.as_str().into(),
+            self.name.as_str().into(),
+            (self.decimals as u64).into(),
+            self.chain_id.into(),
+            self.tags.join(";").into(),
+            self.extensions
+                .as_ref()
+                .and_then(|extensions| extensions.coingecko_id.clone())
+                .into(),
+            self.transfer_fee.map(|fee| fee.basis_points as u64).into(),
+        ]
+    }
+}
+
+impl ExportRecord for DepthPoint {
+    fn columns() -> Vec<Column> {
+        vec![
+            column("input_mint", ColumnKind::Text),
+            column("output_mint", ColumnKind::Text),
+            column("amount", ColumnKind::UInt),
+            column("out_amount", ColumnKind::UInt),
+            column("price", ColumnKind::Float),
+            column("price_impact_pct", ColumnKind::Float),
+            column("slippage_pct", ColumnKind::Float),
+            column("hops", ColumnKind::UInt),
+            column("context_slot", ColumnKind::UInt),
+        ]
+    }
+
+    fn fields(&self) -> Vec<Field> {
+        vec![
+            self.quote.input_mint.as_str().into(),
+            self.quote.output_mint.as_str().into(),
+            self.amount.into(),
+            self.out_amount.into(),
+            self.price.into(),
+            self.price_impact_pct.into(),
+            self.slippage_pct.into(),
+            (self.hops as u64).into(),
+            self.quote.context_slot.into(),
+        ]
+    }
+}
+
+/// Flattened swap history record
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SwapRecord {
+    pub signature: Option<String>,
+    pub input_mint: String,
+    pub output_mint: String,
+    pub in_amount: u64,
+    /// Expected output net of transfer fees (raw units)
+    pub expected_out_amount: u64,
+    pub minimum_out_amount: u64,
+    pub price_impact_pct: f64,
+    pub slippage_bps: u16,
+    /// Route labels joined by `>`
+    pub route: String,
+    pub status: Option<String>,
+    pub slot: Option<u64>,
+    pub confirmation_ms: Option<u64>,
+}
+
+impl From<&SwapExecutionResult> for SwapRecord {
+    fn from(result: &SwapExecutionResult) -> Self {
+        let monitor = result.monitor_result.as_ref();
+        SwapRecord {
+            signature: result.signature.clone(),
+            input_mint: result.quote.input_mint.clone(),
+            output_mint: result.quote.output_mint.clone(),
+            in_amount: result.quote.in_amount.parse().unwrap_or(0),
+            expected_out_amount: result.get_expected_output(),
+            minimum_out_amount: result.get_minimum_output(),
+            price_impact_pct: result.get_price_impact(),
+            slippage_bps: result.quote.slippage_bps,
+            route: result
+                .quote
+                .route_plan
+                .iter()
+                .map(|plan| plan.swap_info.label.label().to_string())
+                .collect::<Vec<_>>()
+                .join(">"),
+            status: monitor.map(|m| format!("{:?}", m.status)),
+            slot: monitor.map(|m| m.slot),
+            confirmation_ms: monitor
+                .and_then(|m| m.confirmation_time)
+                .map(|time| time.as_millis() as u64),
+        }
+    }
+}
+
+impl ExportRecord for SwapRecord {
+    fn columns() -> Vec<Column> {
+        vec![
+            column("signature", ColumnKind::Text),
+            column("input_mint", ColumnKind::Text),
+            column("output_mint", ColumnKind::Text),
+            column("in_amount", ColumnKind::UInt),
+            column("expected_out_amount", ColumnKind::UInt),
+            column("minimum_out_amount", ColumnKind::UInt),
+            column("price_impact_pct", ColumnKind::Float),
+            column("slippage_bps", ColumnKind::UInt),
+            column("route", ColumnKind::Text),
+            column("status", ColumnKind::Text),
+            column("slot", ColumnKind::UInt),
+            column("confirmation_ms", ColumnKind::UInt),
+        ]
+    }
+
+    fn fields(&self) -> Vec<Field> {
+        vec![
+            self.signature.clone().into(),
+            self.input_mint.as_str().into(),
+            self.output_mint.as_str().into(),
+            self.in_amount.into(),
+            self.expected_out_amount.into(),
+            self.minimum_out_amount.into(),
+            self.price_impact_pct.into(),
+            (self.slippage_bps as u64).into(),
+            self.route.as_str().into(),
+            self.status.clone().into(),
+            self.slot.into(),
+            self.confirmation_ms.into(),
+        ]
+    }
+}
+
+/// Writes records as CSV with a header row
+///
+/// # Example
+/// ```rust
+/// use jup_sdk::export::{SwapRecord, write_csv};
+///
+/// let mut out = Vec::new();
+/// write_csv(&mut out, &Vec::<SwapRecord>::new()).unwrap();
+/// assert!(String::from_utf8(out).unwrap().starts_with("signature,input_mint"));
+/// ```
+pub fn write_csv<W: Write, R: ExportRecord>(
+    mut writer: W,
+    records: &[R],
+) -> Result<(), JupiterError> {
+    let io_error = |e: std::io::Error| JupiterError::Error(format!("csv export error: {}", e));
+    let header: Vec<&str> = R::columns().iter().map(|column| column.name).collect();
+    writeln!(writer, "{}", header.join(",")).map_err(io_error)?;
+    for record in records {
+        let row: Vec<String> = record.fields().iter().map(Field::to_csv).collect();
+        writeln!(writer, "{}", row.join(",")).map_err(io_error)?;
+    }
+    writer.flush().map_err(io_error)
+}
+
+/// Writes records to a CSV file, replacing it if present
+pub fn export_csv<R: ExportRecord>(path: &Path, records: &[R]) -> Result<(), JupiterError> {
+    let file =
+        File::create(path).map_err(|e| JupiterError::Error(format!("csv export error: {}", e)))?;
+    write_csv(std::io::BufWriter::new(file), records)
+}
+
+/// Writes records to a Parquet file, replacing it if present
+///
+/// Columns are typed per `ColumnKind`; `Field::Missing` becomes null.
+#[cfg(feature = "parquet")]
+pub fn export_parquet<R: ExportRecord>(path: &Path, records: &[R]) -> Result<(), JupiterError> {
+    use arrow::{
+        array::{ArrayRef, BooleanArray, Float64Array, StringArray, UInt64Array},
+        datatypes::{DataType, Field as ArrowField, Schema},
+        record_batch::RecordBatch,
+    };
+    use parquet::arrow::ArrowWriter;
+    use std::sync::Arc;
+
+    let parquet_error = |e: String| JupiterError::Error(format!("parquet export error: {}", e));
+    let columns = R::columns();
+    let rows: Vec<Vec<Field>> = records.iter().map(ExportRecord::fields).collect();
+    let cells = |index: usize| rows.iter().map(move |row| &row[index]);
+    let mut fields = Vec::with_capacity(columns.len());
+    let mut arrays: Vec<ArrayRef> = Vec::with_capacity(columns.len());
+    for (index, column) in columns.iter().enumerate() {
+        let (data_type, array): (DataType, ArrayRef) = match column.kind {
+            ColumnKind::Text => (
+                DataType::Utf8,
+                Arc::new(StringArray::from_iter(cells(index).map(
+                    |cell| match cell {
+                        Field::Text(value) => Some(value.clone()),
+                        _ => None,
+                    },
+                ))),
+            ),
+            ColumnKind::UInt => (
+                DataType::UInt64,
+                Arc::new(UInt64Array::from_iter(cells(index).map(
+                    |cell| match cell {
+                        Field::UInt(value) => Some(*value),
+                        _ => None,
+                    },
+                ))),
+            ),
+            ColumnKind::Float => (
+                DataType::Float64,
+                Arc::new(Float64Array::from_iter(cells(index).map(
+                    |cell| match cell {
+                        Field::Float(value) => Some(*value),
+                        _ => None,
+                    },
+                ))),
+            ),
+            ColumnKind::Bool => (
+                DataType::Boolean,
+                Arc::new(BooleanArray::from_iter(cells(index).map(
+                    |cell| match cell {
+                        Field::Bool(value) => Some(*value),
+                        _ => None,
+                    },
+                ))),
+            ),
+        };
+        fields.push(ArrowField::new(column.name, data_type, true));
+        arrays.push(array);
+    }
+    let schema = Arc::new(Schema::new(fields));
+    let batch =
+        RecordBatch::try_new(schema.clone(), arrays).map_err(|e| parquet_error(e.to_string()))?;
+    let file = File::create(path).map_err(|e| parquet_error(e.to_string()))?;
+    let mut writer =
+        ArrowWriter::try_new(file, schema, None).map_err(|e| parquet_error(e.to_string()))?;
+    writer
+        .write(&batch)
+        .map_err(|e| parquet_error(e.to_string()))?;
+    writer.close().map_err(|e| parquet_error(e.to_string()))?;
+    Ok(())
+}
@@ -0,0 +1,134 @@
+/// Failure reason module.
+/// Classifies failed swap transactions from their error and program logs into typed
+/// reasons, so callers can remediate without reading logs.
+use serde::{Deserialize, Serialize};
+
+/// Jupiter aggregator error number of `SlippageToleranceExceeded`
+const JUPITER_SLIPPAGE_ERROR: u32 = 6001;
+
+/// Why a swap transaction failed on chain
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FailureReason {
+    /// The output fell below the quote's slippage threshold
+    SlippageToleranceExceeded,
+    /// Not enough tokens or lamports for the swap, fees or rent
+    InsufficientFunds,
+    /// A required account, typically a token account, doesn't exist or isn't initialized
+    AccountNotInitialized,
+    /// The transaction ran out of compute units
+    ComputeBudgetExceeded,
+    /// Any other custom program error
+    ProgramError {
+        /// Program that failed, when the logs name it
+        program_id: Option<String>,
+        code: u32,
+    },
+    /// Failed without a recognizable reason; holds the raw error
+    Other(String),
+}
+
+impl FailureReason {
+    /// Classifies a failed transaction from its error and program logs
+    ///
+    /// # Params
+    /// logs - Program logs of the transaction
+    /// error - Transaction error, `None` for transactions that didn't fail
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::failure::FailureReason;
+    ///
+    /// let logs = vec![
+    ///     "Program log: AnchorError occurred. Error Code: SlippageToleranceExceeded. Error Number: 6001.".to_string(),
+    ///     "Program JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4 failed: custom program error: 0x1771".to_string(),
+    /// ];
+    /// let error = "Error processing Instruction 3: custom program error: 0x1771";
+    /// assert_eq!(
+    ///     FailureReason::classify(&logs, Some(error)),
+    ///     Some(FailureReason::SlippageToleranceExceeded)
+    /// );
+    /// assert_eq!(FailureReason::classify(&logs, None), None);
+    /// ```
+    pub fn classify(logs: &[String], error: Option<&str>) -> Option<Self> {
+        let error = error?;
+        let contains = |patterns: &[&str]| {
+            patterns.iter().any(|pattern| {
+                error.contains(pattern) || logs.iter().any(|log| log.contains(pattern))
+            })
+        };
+        let custom = custom_error(logs, error);
+        if contains(&["SlippageToleranceExceeded"])
+            || matches!(&custom, Some((_, code)) if *code == JUPITER_SLIPPAGE_ERROR)
+        {
+            return Some(FailureReason::SlippageToleranceExceeded);
+        }
+        if contains(&[
+            "insufficient funds",
+            "insufficient lamports",
+            "InsufficientFunds",
+            "Attempt to debit an account but found no record of a prior credit",
+        ]) {
+            return Some(FailureReason::InsufficientFunds);
+        }
+        if contains(&[
+            "AccountNotInitialized",
+            "UninitializedAccount",
+            "UninitializedState",
+            "AccountNotFound",
+        ]) {
+            return Some(FailureReason::AccountNotInitialized);
+        }
+        if contains(&["exceeded CUs meter", "ComputationalBudgetExceeded"]) {
+            return Some(FailureReason::ComputeBudgetExceeded);
+        }
+        Some(match custom {
+            Some((program_id, code)) => FailureReason::ProgramError { program_id, code },
+            None => FailureReason::Other(error.to_string()),
+        })
+    }
+
+    /// Whether a fresh quote with more slippage tolerance may succeed
+    pub fn is_slippage(&self) -> bool {
+        matches!(self, FailureReason::SlippageToleranceExceeded)
+    }
+}
+
+impl std::fmt::Display for FailureReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FailureReason::SlippageToleranceExceeded => write!(f, "slippage tolerance exceeded"),
+            FailureReason::InsufficientFunds => write!(f, "insufficient funds"),
+            FailureReason::AccountNotInitialized => write!(f, "account not initialized"),
+            FailureReason::ComputeBudgetExceeded => write!(f, "compute budget exceeded"),
+            FailureReason::ProgramError {
+                program_id: Some(program_id),
+                code,
+            } => write!(f, "program {} failed with error {}", program_id, code),
+            FailureReason::ProgramError {
+                program_id: None,
+                code,
+            } => write!(f, "program error {}", code),
+            FailureReason::Other(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+/// Custom program error code and, from the logs, the failing program
+fn custom_error(logs: &[String], error: &str) -> Option<(Option<String>, u32)> {
+    const MARKER: &str = "custom program error: 0x";
+    let parse = |text: &str| {
+        let start = text.find(MARKER)? + MARKER.len();
+        let hex: String = text[start..]
+            .chars()
+            .take_while(|c| c.is_ascii_hexdigit())
+            .collect();
+        u32::from_str_radix(&hex, 16).ok()
+    };
+    let failed_program = logs.iter().rev().find_map(|log| {
+        let code = parse(log)?;
+        let program_id = log.strip_prefix("Program ")?.split_whitespace().next()?;
+        Some((Some(program_id.to_string()), code))
+    });
+    failed_program.or_else(|| parse(error).map(|code| (None, code)))
+}
@@ -0,0 +1,415 @@
+/// Fee breakdown module.
+/// Itemizes what an executed swap cost: network fees in lamports and swap fees in the output token.
+use std::{
+    collections::{HashMap, VecDeque},
+    str::FromStr,
+    sync::{Arc, Mutex},
+};
+
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{
+    dex::DexId,
+    global::{
+        BASE_SWAP_COMPUTE_UNITS, COMPUTE_UNIT_WINDOW, COMPUTE_UNITS_PER_HOP, JITO_TIP_ACCOUNTS,
+        LAMPORTS_PER_SIGNATURE,
+    },
+    preview::{AccountKey, TransactionPreview},
+    types::{JupiterError, QuoteResponse, SwapExecutionResult},
+};
+
+/// DEX fee charged on one hop of the route
+#[derive(Debug, Clone, PartialEq)]
+pub struct HopFee {
+    pub dex: DexId,
+    pub fee_mint: String,
+    /// Fee in `fee_mint` (raw units)
+    pub fee_amount: u64,
+    /// Fee converted to the output mint at the route's quoted rates (raw units),
+    /// `None` if the fee mint is not on the route
+    pub output_amount: Option<u64>,
+}
+
+/// Itemized cost of an executed swap
+///
+/// Network fees are in lamports. Swap fees are converted to the quote's output mint
+/// at the rates of the quoted route, so they can be summed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeeBreakdown {
+    /// Base fee of the transaction signatures
+    pub signature_fee_lamports: u64,
+    /// Compute unit price times limit, or the actual meta fee above the base fee
+    pub priority_fee_lamports: u64,
+    /// Lamports transferred to Jito tip accounts
+    pub jito_tip_lamports: u64,
+    /// Whether the network fees come from the confirmed transaction's meta rather
+    /// than the unsigned transaction
+    pub from_meta: bool,
+    /// Mint the swap fees are denominated in
+    pub output_mint: String,
+    /// Jupiter platform fee, in output mint raw units
+    pub platform_fee: u64,
+    pub platform_fee_bps: u16,
+    /// DEX fee of each hop, in route order
+    pub dex_fees: Vec<HopFee>,
+}
+
+impl FeeBreakdown {
+    /// Assembles the breakdown from the quote, swap response and, once monitored,
+    /// the transaction meta
+    pub fn from_execution(result: &SwapExecutionResult) -> Self {
+        let preview = TransactionPreview::decode(&result.swap_response.swap_transaction, None).ok();
+        let signature_fee_lamports = preview.as_ref().map_or(LAMPORTS_PER_SIGNATURE, |p| {
+            LAMPORTS_PER_SIGNATURE * p.signers.len() as u64
+        });
+        let jito_tip_lamports = preview.as_ref().map_or(0, jito_tip_lamports);
+        let meta_fee = result.monitor_result.as_ref().and_then(|r| r.fee);
+        let priority_fee_lamports = match meta_fee {
+            Some(fee) => fee.saturating_sub(signature_fee_lamports),
+            None => preview
+                .as_ref()
+                .map(|p| p.compute_budget.priority_fee_lamports())
+                .or(result.swap_response.prioritization_fee_lamports)
+                .unwrap_or(0),
+        };
+        let quote = &result.quote;
+        let (platform_fee, platform_fee_bps) = match &quote.platform_fee {
+            Some(fee) => {
+                let amount: u64 = fee.amount.parse().unwrap_or(0);
+                // ExactOut quotes charge the platform fee in the input mint
+                let fee_mint = if quote.swap_mode == "ExactOut" {
+                    &quote.input_mint
+                } else {
+                    &quote.output_mint
+                };
+                (
+                    to_output_amount(quote, fee_mint, amount).unwrap_or(0),
+                    fee.fee_bps,
+                )
+            }
+            None => (0, 0),
+        };
+        let dex_fees = quote
+            .route_plan
+            .iter()
+            .map(|plan| {
+                let info = &plan.swap_info;
+                let fee_amount: u64 = info.fee_amount.parse().unwrap_or(0);
+                HopFee {
+                    dex: info.label.clone(),
+                    fee_mint: info.fee_mint.clone(),
+                    fee_amount,
+                    output_amount: to_output_amount(quote, &info.fee_mint, fee_amount),
+                }
+            })
+            .collect();
+        Self {
+            signature_fee_lamports,
+            priority_fee_lamports,
+            jito_tip_lamports,
+            from_meta: meta_fee.is_some(),
+            output_mint: quote.output_mint.clone(),
+            platform_fee,
+            platform_fee_bps,
+            dex_fees,
+        }
+    }
+
+    /// Signature fee, priority fee and Jito tip, in lamports
+    pub fn network_fee_lamports(&self) -> u64 {
+        self.signature_fee_lamports
+            .saturating_add(self.priority_fee_lamports)
+            .saturating_add(self.jito_tip_lamports)
+    }
+
+    /// DEX fees of all hops, in output mint raw units
+    pub fn dex_fee_total(&self) -> u64 {
+        self.dex_fees
+            .iter()
+            .filter_map(|fee| fee.output_amount)
+            .fold(0u64, u64::saturating_add)
+    }
+
+    /// Platform fee plus DEX fees, in output mint raw units
+    pub fn swap_fee_total(&self) -> u64 {
+        self.platform_fee.saturating_add(self.dex_fee_total())
+    }
+}
+
+impl SwapExecutionResult {
+    /// Itemized fees of the swap, see `FeeBreakdown::from_execution`
+    pub fn fee_breakdown(&self) -> FeeBreakdown {
+        FeeBreakdown::from_execution(self)
+    }
+}
+
+/// Lamports a transaction transfers to Jito tip accounts
+fn jito_tip_lamports(preview: &TransactionPreview) -> u64 {
+    let tip_accounts: Vec<Pubkey> = JITO_TIP_ACCOUNTS
+        .iter()
+        .filter_map(|account| Pubkey::from_str(account).ok())
+        .collect();
+    preview
+        .balance_changes
+        .iter()
+        .filter(|change| change.mint.is_none() && change.delta > 0)
+        .filter(|change| {
+            matches!(change.account, AccountKey::Static(key) if tip_accounts.contains(&key))
+        })
+        .map(|change| change.delta as u64)
+        .sum()
+}
+
+/// Converts an amount of a mint on the route into output mint raw units
+///
+/// Walks the route forward from the hop consuming `mint`, applying each hop's quoted
+/// rate. Returns `None` if the mint is not on the route.
+///
+/// # Example
+/// ```rust
+/// use jup_sdk::fees::to_output_amount;
+/// # use jup_sdk::types::QuoteResponse;
+/// # fn example(quote: &QuoteResponse) {
+/// let fee_in_output = to_output_amount(quote, &quote.input_mint, 1_000);
+/// # }
+/// ```
+pub fn to_output_amount(quote: &QuoteResponse, mint: &str, amount: u64) -> Option<u64> {
+    let mut mint = mint.to_string();
+    let mut amount = amount as u128;
+    for _ in 0..=quote.route_plan.len() {
+        if mint == quote.output_mint {
+            return u64::try_from(amount).ok();
+        }
+        let info = &quote
+            .route_plan
+            .iter()
+            .find(|plan| plan.swap_info.input_mint == mint)?
+            .swap_info;
+        let in_amount: u128 = info.in_amount.parse().ok()?;
+        let out_amount: u128 = info.out_amount.parse().ok()?;
+        if in_amount == 0 {
+            return None;
+        }
+        amount = amount.checked_mul(out_amount)? / in_amount;
+        mint = info.output_mint.clone();
+    }
+    None
+}
+
+/// Estimated cost of a swap transaction, in lamports
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeeEstimate {
+    /// Signature fee plus priority fee at a low recent priority fee percentile
+    pub min: u64,
+    /// Signature fee plus priority fee at the median recent priority fee
+    pub expected: u64,
+    /// Signature fee plus priority fee at a high recent priority fee percentile
+    pub max: u64,
+    pub signature_fee_lamports: u64,
+    /// Compute units the estimate is priced for
+    pub compute_units: u64,
+    /// Whether `compute_units` comes from simulating the transaction rather than the
+    /// per-route-complexity estimate
+    pub simulated: bool,
+    /// Compute unit prices (micro-lamports) behind `min`, `expected` and `max`
+    pub unit_prices: [u64; 3],
+}
+
+impl FeeEstimate {
+    /// Prices `compute_units` at each of `unit_prices`
+    pub fn new(
+        signature_fee_lamports: u64,
+        compute_units: u64,
+        simulated: bool,
+        unit_prices: [u64; 3],
+    ) -> Self {
+        let [min, expected, max] = unit_prices
+            .map(|price| signature_fee_lamports + priority_fee_lamports(compute_units, price));
+        Self {
+            min,
+            expected,
+            max,
+            signature_fee_lamports,
+            compute_units,
+            simulated,
+            unit_prices,
+        }
+    }
+}
+
+/// Priority fee in lamports for a compute unit budget and price
+pub fn priority_fee_lamports(compute_units: u64, unit_price_micro_lamports: u64) -> u64 {
+    (compute_units as u128 * unit_price_micro_lamports as u128).div_ceil(1_000_000) as u64
+}
+
+/// Nearest-rank percentiles of a set of values, 0 for each when empty
+///
+/// # Example
+/// ```rust
+/// use jup_sdk::fees::percentiles;
+///
+/// assert_eq!(percentiles(vec![40, 10, 30, 20], [0.25, 0.5, 1.0]), [10, 20, 40]);
+/// ```
+pub fn percentiles<const N: usize>(mut values: Vec<u64>, quantiles: [f64; N]) -> [u64; N] {
+    values.sort_unstable();
+    quantiles.map(|q| {
+        if values.is_empty() {
+            return 0;
+        }
+        let rank = (q.clamp(0.0, 1.0) * values.len() as f64).ceil() as usize;
+        values[rank.saturating_sub(1).min(values.len() - 1)]
+    })
+}
+
+/// Rolling compute unit estimator calibrated per route hop count
+///
+/// Fed with compute units consumed by executed swaps; without samples for a hop
+/// count it assumes a base cost plus a per-hop cost. Clones share the same samples.
+#[derive(Debug, Clone)]
+pub struct ComputeUnitEstimator {
+    window: usize,
+    samples: Arc<Mutex<HashMap<usize, VecDeque<u64>>>>,
+}
+
+impl Default for ComputeUnitEstimator {
+    fn default() -> Self {
+        Self::new(COMPUTE_UNIT_WINDOW)
+    }
+}
+
+impl ComputeUnitEstimator {
+    /// Creates an estimator keeping the last `window` samples per hop count
+    pub fn new(window: usize) -> Self {
+        Self {
+            window: window.max(1),
+            samples: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Records the compute units consumed by a swap along `route`
+    pub fn record(&self, route: &QuoteResponse, compute_units: u64) {
+        let mut samples = self.samples.lock().unwrap();
+        let entry = samples.entry(route.route_plan.len()).or_default();
+        entry.push_back(compute_units);
+        while entry.len() > self.window {
+            entry.pop_front();
+        }
+    }
+
+    /// Estimates the compute units of a swap along `route`
+    ///
+    /// Uses the 90th percentile of samples for the route's hop count so the estimate
+    /// rarely falls short, falling back to the base plus per-hop cost.
+    pub fn estimate(&self, route: &QuoteResponse) -> u64 {
+        let hops = route.route_plan.len();
+        let samples = self.samples.lock().unwrap();
+        match samples.get(&hops).filter(|samples| !samples.is_empty()) {
+            Some(samples) => percentiles(samples.iter().copied().collect(), [0.9])[0],
+            None => BASE_SWAP_COMPUTE_UNITS + COMPUTE_UNITS_PER_HOP * hops.max(1) as u64,
+        }
+    }
+}
+
+/// Outcome of a cost/benefit check
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CostCheck {
+    /// Estimated fees the check was made with, in lamports
+    pub fee_lamports: u64,
+    /// Value of the quoted output, in lamports
+    pub notional_lamports: u64,
+    /// Fees relative to the notional, in basis points
+    pub fee_bps: f64,
+    /// Quoted output minus the fees converted to the output mint (raw units)
+    pub net_output: u64,
+}
+
+/// Refuses swaps whose estimated fees are out of proportion to what they trade
+///
+/// # Example
+/// ```rust
+/// use jup_sdk::fees::CostGate;
+///
+/// // Refuse swaps paying more than 1% of their value in fees
+/// let gate = CostGate::new(100).conservative();
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CostGate {
+    /// Maximum fees relative to the swap's output value, in basis points
+    pub max_fee_bps: u16,
+    /// Minimum output (raw units) left after deducting fees, if any
+    pub min_net_output: Option<u64>,
+    /// Check the `max` fee estimate instead of `expected`
+    pub use_max_estimate: bool,
+}
+
+impl CostGate {
+    pub fn new(max_fee_bps: u16) -> Self {
+        Self {
+            max_fee_bps,
+            min_net_output: None,
+            use_max_estimate: false,
+        }
+    }
+
+    pub fn with_min_net_output(mut self, min_net_output: u64) -> Self {
+        self.min_net_output = Some(min_net_output);
+        self
+    }
+
+    /// Checks against the `max` fee estimate
+    pub fn conservative(mut self) -> Self {
+        self.use_max_estimate = true;
+        self
+    }
+
+    /// Checks a quote's estimated fees against its output value
+    ///
+    /// # Params
+    /// quote - Quote to be executed
+    /// estimate - Fee estimate of the swap transaction
+    /// notional_lamports - Value of the quoted output in lamports
+    pub fn check(
+        &self,
+        quote: &QuoteResponse,
+        estimate: &FeeEstimate,
+        notional_lamports: u64,
+    ) -> Result<CostCheck, JupiterError> {
+        let fee_lamports = if self.use_max_estimate {
+            estimate.max
+        } else {
+            estimate.expected
+        };
+        let out_amount: u64 = quote.out_amount.parse().map_err(|_| {
+            JupiterError::ParseError(format!("invalid out_amount {}", quote.out_amount))
+        })?;
+        let (fee_bps, fee_in_output) = if notional_lamports == 0 {
+            (f64::INFINITY, out_amount as u128)
+        } else {
+            (
+                fee_lamports as f64 / notional_lamports as f64 * 10_000.0,
+                fee_lamports as u128 * out_amount as u128 / notional_lamports as u128,
+            )
+        };
+        let check = CostCheck {
+            fee_lamports,
+            notional_lamports,
+            fee_bps,
+            net_output: (out_amount as u128).saturating_sub(fee_in_output) as u64,
+        };
+        if check.fee_bps > self.max_fee_bps as f64 {
+            return Err(JupiterError::ValidationError(format!(
+                "estimated fees of {} lamports are {:.1} bps of the {} lamport notional, above the {} bps limit",
+                fee_lamports, check.fee_bps, notional_lamports, self.max_fee_bps
+            )));
+        }
+        if let Some(min_net_output) = self.min_net_output
+            && check.net_output < min_net_output
+        {
+            return Err(JupiterError::ValidationError(format!(
+                "net output {} after fees is below the minimum {}",
+                check.net_output, min_net_output
+            )));
+        }
+        Ok(check)
+    }
+}
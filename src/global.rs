@@ -1,5 +1,7 @@
 /// Jupiter API base URL - v6 quote API endpoint
 pub const JUPITER_BASE_URL: &str = "https://quote-api.jup.ag/v6";
+/// Jupiter Ultra API base URL (orders filled by AMMs or RFQ market makers)
+pub const JUPITER_ULTRA_BASE_URL: &str = "https://lite-api.jup.ag/ultra/v1";
 /// Default slippage tolerance in basis points (1 basis point = 0.01%)
 /// 50 bps = 0.5% slippage tolerance
 pub const DEFAULT_SLIPPAGE_BPS: u16 = 50;
@@ -18,3 +20,84 @@ pub const MAX_RETRIES: u32 = 3;
 /// Delay between retry attempts in milliseconds
 /// Uses exponential backoff: delay increases with each retry attempt
 pub const RETRY_DELAY_MS: u64 = 500;
+/// Smallest amount (raw units) of the coarse ladder used for liquidity estimation
+pub const LIQUIDITY_PROBE_START_AMOUNT: u64 = 1_000;
+/// Number of coarse ladder steps used for liquidity estimation
+/// Each step multiplies the amount by 10, covering 1e3 to 1e15 raw units
+pub const LIQUIDITY_PROBE_STEPS: usize = 13;
+/// Number of amounts quoted when refining the liquidity estimate between two ladder steps
+pub const LIQUIDITY_REFINE_STEPS: usize = 4;
+/// Number of confirmation latency samples kept per DEX
+pub const LATENCY_WINDOW: usize = 50;
+/// Confirmation latency assumed for a DEX without observed samples, in seconds
+pub const DEFAULT_CONFIRMATION_SECONDS: f64 = 1.5;
+/// Additional execution time per route hop, in seconds
+pub const ROUTE_HOP_OVERHEAD_SECONDS: f64 = 0.05;
+/// Upper bounds of the request latency histogram buckets, in milliseconds
+pub const LATENCY_BUCKETS_MS: [u64; 11] =
+    [5, 10, 25, 50, 100, 250, 500, 1_000, 2_500, 5_000, 10_000];
+/// Input mint (wrapped SOL) of the quote used by health checks
+pub const HEALTH_CHECK_INPUT_MINT: &str = "So11111111111111111111111111111111111111112";
+/// Output mint (USDC) of the quote used by health checks
+pub const HEALTH_CHECK_OUTPUT_MINT: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+/// Input amount (raw units) of the quote used by health checks
+pub const HEALTH_CHECK_AMOUNT: u64 = 1_000_000;
+/// Wrapped SOL mint
+pub const WSOL_MINT: &str = "So11111111111111111111111111111111111111112";
+/// Base fee charged per transaction signature, in lamports
+pub const LAMPORTS_PER_SIGNATURE: u64 = 5_000;
+/// Maximum size of a serialized transaction, in bytes
+pub const MAX_TRANSACTION_SIZE: usize = 1232;
+/// Rent-exempt minimum of an SPL token account (165 bytes), in lamports
+pub const TOKEN_ACCOUNT_RENT_LAMPORTS: u64 = 2_039_280;
+/// Default number of quotes requested concurrently by batch quoting
+pub const DEFAULT_BATCH_CONCURRENCY: usize = 8;
+/// Offset of the decimals byte in SPL Token and Token-2022 mint accounts
+pub const MINT_DECIMALS_OFFSET: usize = 44;
+/// Jito block engine tip accounts
+pub const JITO_TIP_ACCOUNTS: [&str; 8] = [
+    "96gYZGLnJYVFmbjzopPSU6QiEV5fGqZNyN9nmNhvrZU5",
+    "HFqU5x63VTqvQss8hp11i4wVV8bD44PvwucfZ2bU7gRe",
+    "Cw8CFyM9FkoMi7K7Crf6HNQqf4uEMzpKw6QNghXLvLkY",
+    "ADaUMid9yfUytqMBgopwjb2DTLSokTSzL1zt6iGPaS49",
+    "DfXygSm4jCyNCybVYYK6DwvWqjKee8pbDmJGcLWNDXjh",
+    "ADuUkR4vqLUMWXxW9gh6D6L8pMSawimctcNZ5pGwDcEt",
+    "DttWaMuVvTiduZRnguLF7jNxTgiMBZ1hyAumKUiL2KRL",
+    "3AVi9Tg9Uo68tJfuvoKvqKNWKkC5wPdSSdeBnizKZ6jT",
+];
+/// Number of observed compute unit samples kept per route hop count
+pub const COMPUTE_UNIT_WINDOW: usize = 50;
+/// Compute units assumed for a swap before per-hop costs, without observed samples
+pub const BASE_SWAP_COMPUTE_UNITS: u64 = 50_000;
+/// Additional compute units assumed per route hop, without observed samples
+pub const COMPUTE_UNITS_PER_HOP: u64 = 50_000;
+/// Percentiles of recent priority fees used for the minimum, expected and maximum fee estimate
+pub const PRIORITY_FEE_PERCENTILES: [f64; 3] = [0.25, 0.5, 0.9];
+/// Derivation path used for seed phrases by Solana wallets (Phantom, Solflare, ...)
+pub const DEFAULT_DERIVATION_PATH: &str = "m/44'/501'/0'/0'";
+/// Consecutive retriable failures after which a client pool endpoint leaves rotation
+pub const POOL_MAX_CONSECUTIVE_FAILURES: u32 = 3;
+/// Seconds an unhealthy client pool endpoint stays out of rotation
+pub const POOL_COOLDOWN_SECONDS: u64 = 30;
+/// Weight of the newest sample in a client pool endpoint's smoothed latency
+pub const POOL_LATENCY_SMOOTHING: f64 = 0.2;
+/// Default fraction of a scheduled job's interval applied as random jitter
+pub const SCHEDULER_DEFAULT_JITTER: f64 = 0.1;
+/// Default upper bound in seconds of a scheduled job's delay after failures
+pub const SCHEDULER_MAX_BACKOFF_SECONDS: u64 = 600;
+/// Candidate splits of an order across alternative routes, in basis points per route
+pub const DEFAULT_SPLIT_SHARES: &[&[u16]] = &[
+    &[5000, 5000],
+    &[6000, 4000],
+    &[7000, 3000],
+    &[8000, 2000],
+    &[4000, 3000, 3000],
+];
+/// Number of recent quotes kept for quote telemetry
+pub const QUOTE_TELEMETRY_WINDOW: usize = 200;
+/// Average Solana slot duration in milliseconds, used to project the chain tip
+pub const SLOT_DURATION_MS: u64 = 400;
+/// Header carrying the per-call request ID, sent to and echoed by the Jupiter API
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+/// Header identifying the integrating application in Jupiter's analytics
+pub const INTEGRATOR_ID_HEADER: &str = "x-integrator-id";
@@ -0,0 +1,151 @@
+/// Yellowstone gRPC module.
+/// Streams transaction updates from a Yellowstone (Geyser) gRPC endpoint, used by the
+/// monitor and the wallet watcher to learn about landed transactions before the next
+/// RPC poll.
+use futures::{StreamExt, stream::BoxStream};
+use solana_commitment_config::{CommitmentConfig, CommitmentLevel};
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
+use std::{collections::HashMap, time::Duration};
+use yellowstone_grpc_client::{ClientTlsConfig, GeyserGrpcClient};
+use yellowstone_grpc_proto::prelude::{
+    CommitmentLevel as GrpcCommitmentLevel, SubscribeRequest, SubscribeRequestFilterTransactions,
+    subscribe_update::UpdateOneof,
+};
+
+use crate::{secret::SecretString, types::JupiterError};
+
+/// Connection settings of a Yellowstone gRPC endpoint
+#[derive(Debug, Clone)]
+pub struct GrpcConfig {
+    pub endpoint: String,
+    /// Access token sent as `x-token`
+    pub x_token: Option<SecretString>,
+    pub connect_timeout: Duration,
+}
+
+impl GrpcConfig {
+    pub fn new(endpoint: &str) -> Self {
+        Self {
+            endpoint: endpoint.to_string(),
+            x_token: None,
+            connect_timeout: Duration::from_secs(10),
+        }
+    }
+
+    pub fn with_x_token(mut self, token: impl Into<SecretString>) -> Self {
+        self.x_token = Some(token.into());
+        self
+    }
+}
+
+/// Transactions to stream
+#[derive(Debug, Clone)]
+pub enum TransactionFilter {
+    /// A single transaction
+    Signature(Signature),
+    /// Transactions mentioning an account
+    Account(Pubkey),
+}
+
+/// Transaction update received from the gRPC stream
+#[derive(Debug, Clone, PartialEq)]
+pub struct GrpcTransaction {
+    pub signature: Signature,
+    pub slot: u64,
+    /// Whether the transaction failed on chain
+    pub failed: bool,
+}
+
+/// Subscribes to non-vote transactions matching `filter` at `commitment`
+///
+/// The stream ends with an error when the connection drops; callers fall back to
+/// RPC polling then.
+pub async fn subscribe_transactions(
+    config: &GrpcConfig,
+    filter: TransactionFilter,
+    commitment: CommitmentConfig,
+) -> Result<BoxStream<'static, Result<GrpcTransaction, JupiterError>>, JupiterError> {
+    let grpc_error = |e: &dyn std::fmt::Display| JupiterError::NetworkError(format!("grpc: {}", e));
+    let mut client = GeyserGrpcClient::build_from_shared(config.endpoint.clone())
+        .map_err(|e| grpc_error(&e))?
+        .x_token(
+            config
+                .x_token
+                .as_ref()
+                .map(|token| token.expose_secret().to_string()),
+        )
+        .map_err(|e| grpc_error(&e))?
+        .connect_timeout(config.connect_timeout)
+        .tls_config(ClientTlsConfig::new().with_native_roots())
+        .map_err(|e| grpc_error(&e))?
+        .connect()
+        .await
+        .map_err(|e| grpc_error(&e))?;
+
+    let mut transaction_filter = SubscribeRequestFilterTransactions {
+        vote: Some(false),
+        ..Default::default()
+    };
+    match filter {
+        TransactionFilter::Signature(signature) => {
+            transaction_filter.signature = Some(signature.to_string())
+        }
+        TransactionFilter::Account(account) => {
+            transaction_filter.account_include = vec![account.to_string()]
+        }
+    }
+    let request = SubscribeRequest {
+        transactions: HashMap::from([("jup-sdk".to_string(), transaction_filter)]),
+        commitment: Some(grpc_commitment(commitment) as i32),
+        ..Default::default()
+    };
+    let (sink, updates) = client
+        .subscribe_with_request(Some(request))
+        .await
+        .map_err(|e| grpc_error(&e))?;
+
+    let transactions = updates.filter_map(move |update| {
+        // The request sink must outlive the stream, or the server ends the subscription
+        let _ = &sink;
+        let transaction = match update {
+            Ok(update) => match update.update_oneof {
+                Some(UpdateOneof::Transaction(update)) => update.transaction.and_then(|info| {
+                    Some(Ok(GrpcTransaction {
+                        signature: Signature::try_from(info.signature.as_slice()).ok()?,
+                        slot: update.slot,
+                        failed: info.meta.is_some_and(|meta| meta.err.is_some()),
+                    }))
+                }),
+                _ => None,
+            },
+            Err(status) => Some(Err(grpc_error(&status))),
+        };
+        async move { transaction }
+    });
+    Ok(transactions.boxed())
+}
+
+/// Waits until `signature` lands at `commitment`
+pub async fn wait_for_signature(
+    config: &GrpcConfig,
+    signature: &Signature,
+    commitment: CommitmentConfig,
+) -> Result<GrpcTransaction, JupiterError> {
+    let mut transactions =
+        subscribe_transactions(config, TransactionFilter::Signature(*signature), commitment)
+            .await?;
+    match transactions.next().await {
+        Some(transaction) => transaction,
+        None => Err(JupiterError::NetworkError(
+            "grpc: transaction stream closed".to_string(),
+        )),
+    }
+}
+
+fn grpc_commitment(commitment: CommitmentConfig) -> GrpcCommitmentLevel {
+    match commitment.commitment {
+        CommitmentLevel::Processed => GrpcCommitmentLevel::Processed,
+        CommitmentLevel::Confirmed => GrpcCommitmentLevel::Confirmed,
+        CommitmentLevel::Finalized => GrpcCommitmentLevel::Finalized,
+    }
+}
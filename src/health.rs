@@ -0,0 +1,80 @@
+/// Health module.
+/// Per-component health and readiness report of the Jupiter APIs and the Solana RPC.
+use std::{
+    future::Future,
+    time::{Duration, Instant},
+};
+
+use crate::types::JupiterError;
+
+/// Component checked by a health report
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HealthComponent {
+    QuoteApi,
+    PriceApi,
+    TokenApi,
+    SolanaRpc,
+}
+
+impl std::fmt::Display for HealthComponent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            HealthComponent::QuoteApi => "quote_api",
+            HealthComponent::PriceApi => "price_api",
+            HealthComponent::TokenApi => "token_api",
+            HealthComponent::SolanaRpc => "solana_rpc",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Health of a single component
+#[derive(Debug, Clone)]
+pub struct ComponentHealth {
+    pub component: HealthComponent,
+    pub healthy: bool,
+    /// Time taken by the check
+    pub latency: Duration,
+    /// Error returned by the check, if it failed
+    pub error: Option<String>,
+}
+
+impl ComponentHealth {
+    /// Runs a check and records its latency and outcome
+    pub(crate) async fn check<T, Fut>(component: HealthComponent, check: Fut) -> Self
+    where
+        Fut: Future<Output = Result<T, JupiterError>>,
+    {
+        let started = Instant::now();
+        let result = check.await;
+        Self {
+            component,
+            healthy: result.is_ok(),
+            latency: started.elapsed(),
+            error: result.err().map(|e| e.to_string()),
+        }
+    }
+}
+
+/// Health of every component checked by `JupiterClient::health_report`
+#[derive(Debug, Clone)]
+pub struct HealthReport {
+    pub components: Vec<ComponentHealth>,
+}
+
+impl HealthReport {
+    /// Whether every component is healthy, suitable for a readiness probe
+    pub fn is_healthy(&self) -> bool {
+        self.components.iter().all(|component| component.healthy)
+    }
+
+    /// Health of a single component
+    pub fn component(&self, component: HealthComponent) -> Option<&ComponentHealth> {
+        self.components.iter().find(|c| c.component == component)
+    }
+
+    /// Components whose check failed
+    pub fn unhealthy(&self) -> Vec<&ComponentHealth> {
+        self.components.iter().filter(|c| !c.healthy).collect()
+    }
+}
@@ -0,0 +1,89 @@
+/// Identifier module.
+/// Collision-resistant, time-ordered identifiers for requests, idempotency keys and orders.
+use rand::Rng;
+use std::{
+    sync::Mutex,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Bits of the per-millisecond sequence (UUIDv7 `rand_a`)
+const SEQUENCE_BITS: u32 = 12;
+/// Upper bound of the random sequence start, leaving room to increment
+const SEQUENCE_SEED_MAX: u16 = 1 << (SEQUENCE_BITS - 1);
+
+/// Last issued (millisecond, sequence) pair
+static CLOCK: Mutex<(u64, u16)> = Mutex::new((0, 0));
+
+/// Returns a strictly increasing (millisecond, sequence) pair
+///
+/// The sequence starts at a random value each millisecond and increments within
+/// it; when exhausted the timestamp is advanced, so identifiers stay ordered even
+/// if the system clock goes backwards.
+fn next_tick() -> (u64, u16) {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0);
+    let mut clock = CLOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let (last_ms, sequence) = *clock;
+    *clock = if now > last_ms {
+        (now, rand::thread_rng().gen_range(0..SEQUENCE_SEED_MAX))
+    } else if sequence + 1 < (1 << SEQUENCE_BITS) {
+        (last_ms, sequence + 1)
+    } else {
+        (
+            last_ms + 1,
+            rand::thread_rng().gen_range(0..SEQUENCE_SEED_MAX),
+        )
+    };
+    *clock
+}
+
+/// Generates a UUIDv7 string
+///
+/// 48-bit millisecond timestamp, 12-bit monotonic sequence and 62 random bits, so
+/// identifiers sort by creation time and do not collide across processes.
+///
+/// # Example
+/// ```rust
+/// use jup_sdk::id::uuid_v7;
+///
+/// let (a, b) = (uuid_v7(), uuid_v7());
+/// assert_eq!(a.len(), 36);
+/// assert!(a < b);
+/// ```
+pub fn uuid_v7() -> String {
+    let (ms, sequence) = next_tick();
+    let random: u64 = rand::thread_rng().r#gen();
+    let value: u128 = ((ms as u128 & 0xFFFF_FFFF_FFFF) << 80)
+        | (0x7 << 76)
+        | ((sequence as u128) << 64)
+        | (0b10 << 62)
+        | (random as u128 & 0x3FFF_FFFF_FFFF_FFFF);
+    let hex = format!("{:032x}", value);
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
+
+/// Generates a prefixed identifier, e.g. `req_0190f6c4-...`
+///
+/// # Params
+/// prefix - Kind of object identified, such as `req`, `idem` or `ord`
+pub fn prefixed_id(prefix: &str) -> String {
+    format!("{}_{}", prefix, uuid_v7())
+}
+
+/// Generates a strictly increasing 64-bit nonce
+///
+/// 44-bit millisecond timestamp, 12-bit sequence and 8 random bits.
+pub fn unique_u64() -> u64 {
+    let (ms, sequence) = next_tick();
+    let random: u8 = rand::thread_rng().r#gen();
+    (ms << 20) | ((sequence as u64) << 8) | random as u64
+}
@@ -1,26 +1,157 @@
-use reqwest::Client;
+use futures::{StreamExt, future::join_all, stream};
+use reqwest::{
+    Client, StatusCode,
+    header::{ETAG, HeaderValue, IF_NONE_MATCH, RETRY_AFTER},
+};
+use serde::{Serialize, de::DeserializeOwned};
+use solana_client::rpc_config::RpcSimulateTransactionConfig;
+use solana_commitment_config::CommitmentConfig;
 use solana_network_sdk::Solana;
-use std::{collections::HashMap, time::Duration};
-use tokio::time;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::Signature,
+    signer::Signer,
+    transaction::{Transaction, VersionedTransaction},
+};
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant, SystemTime},
+};
+use tokio_util::sync::CancellationToken;
+use tracing::Instrument;
 
 use crate::{
-    global::{DEFAULT_SLIPPAGE_BPS, JUPITER_BASE_URL},
-    monitor::{Monitor, TransactionMonitorConfig, TransactionMonitorResult},
-    retry::RetryConfig,
-    router::RouteAnalysis,
-    tool::{is_valid_mint_address, validate_pubkey, validate_slippage_bps},
+    alt::{LookupTable, LookupTableCache},
+    amount::TokenAmount,
+    ata::{AtaStatus, create_associated_token_account_idempotent},
+    cache::{Cache, CacheTtlConfig, ConditionalCache, LruCache, content_hash},
+    coalesce::SingleFlight,
+    compose::TransactionComposer,
+    conversion::{
+        AmountConversion, ConversionPlan, OutputQuote, OutputQuoteTable, Rounding, convert_raw,
+        usd_value,
+    },
+    dex::ProgramIdList,
+    fees::{ComputeUnitEstimator, CostGate, FeeEstimate, percentiles},
+    global::{
+        DEFAULT_SLIPPAGE_BPS, HEALTH_CHECK_AMOUNT, HEALTH_CHECK_INPUT_MINT,
+        HEALTH_CHECK_OUTPUT_MINT, INTEGRATOR_ID_HEADER, JUPITER_BASE_URL, JUPITER_ULTRA_BASE_URL,
+        LAMPORTS_PER_SIGNATURE, LIQUIDITY_PROBE_START_AMOUNT, LIQUIDITY_PROBE_STEPS,
+        LIQUIDITY_REFINE_STEPS, MINT_DECIMALS_OFFSET, PRIORITY_FEE_PERCENTILES, REQUEST_ID_HEADER,
+        TOKEN_ACCOUNT_RENT_LAMPORTS, WSOL_MINT,
+    },
+    health::{ComponentHealth, HealthComponent, HealthReport},
+    id::prefixed_id,
+    liquidity::{DepthCurve, LiquidityEstimate, geometric_ladder, linear_ladder},
+    logger::RequestLogConfig,
+    market::TwoSidedQuote,
+    metrics::{Metrics, QuoteTelemetry, QuoteTelemetryStats},
+    mev::MevProtection,
+    monitor::{
+        LatencyTracker, Monitor, TransactionMonitorConfig, TransactionMonitorResult,
+        TransactionStatus,
+    },
+    multileg::{MultiLegPlan, validate_legs},
+    oracle::OracleGuard,
+    payment::{PaymentOption, PaymentReceipt, sort_options},
+    policy::{ExecutionAttempt, RetryAdjustment},
+    preflight::{Deficiency, PreflightReport, SolRequirement},
+    pretrade::{PreTradeCheck, PreTradeDecision},
+    preview::TransactionPreview,
+    priority::BroadcastStrategy,
+    reliability::VenueReliability,
+    retry::{
+        ExponentialBackoff, RetryBudget, RetryBudgetConfig, RetryConfig, RetryStrategy, hedge,
+        retry_with_budget,
+    },
+    rfq::{RfqExecuteRequest, RfqExecuteResponse, RfqOrder, RfqOrderRequest},
+    router::{ConfidenceInputs, RouteAnalysis, RouteGraph, route_key},
+    scheduler::{JobConfig, RefresherConfig, Scheduler},
+    secret::SecretString,
+    slippage::SlippageAdvisor,
+    split::{SplitConfig, SplitExecution, SplitLeg, SplitPlan, leg_params, split_amounts},
+    storage::{IDEMPOTENCY_KEY_PREFIX, IdempotencyRecord, MemoryStorage, Storage},
+    task::BackgroundTask,
+    throttle::{AdaptiveThrottle, AdaptiveThrottleConfig},
+    token_map::{SharedTokenMap, TokenMap},
+    token_policy::{TokenPolicy, quote_mints},
+    token2022::{TransferFee, TransferFeeConfig},
+    tool::{
+        associated_token_address, is_valid_mint_address, run_cancellable, validate_pubkey,
+        validate_slippage_bps,
+    },
+    tx::{SwapTransaction, decode_swap_transaction, partial_sign_transaction, serialize_base64},
     types::{
-        JupiterError, PriceResponse, QuoteRequest, QuoteResponse, SwapRequest, SwapResponse,
-        TokenInfo,
+        AdvancedSwapConfig, BatchQuoteOptions, BatchQuoteRequest, BatchQuoteResponse,
+        IndexedRouteMapResponse, JupiterError, PriceResponse, QuoteRequest, QuoteResponse,
+        RateLimiter, SendOptions, SwapExecutionResult, SwapInstructionsResponse, SwapRequest,
+        SwapResponse, TokenInfo,
     },
+    verify::verify_swap_transaction,
+    watcher::{WalletWatcher, WalletWatcherConfig},
 };
 
+pub mod alt;
+pub mod amount;
+pub mod ata;
+pub mod cache;
+pub mod coalesce;
+pub mod compose;
+pub mod conversion;
+pub mod dex;
+pub mod export;
+pub mod failure;
+pub mod fees;
 pub mod global;
+#[cfg(feature = "grpc")]
+pub mod grpc;
+pub mod health;
+pub mod id;
+pub mod liquidity;
+pub mod logger;
+pub mod market;
+pub mod metrics;
+pub mod mev;
 pub mod monitor;
+pub mod multileg;
+pub mod notify;
+pub mod oracle;
+pub mod order;
+pub mod payment;
+pub mod policy;
+pub mod pool;
+pub mod preflight;
+pub mod pretrade;
+pub mod preview;
+pub mod priority;
+pub mod reliability;
 pub mod retry;
+pub mod rfq;
 pub mod router;
+pub mod scheduler;
+#[cfg(feature = "schema")]
+pub mod schema;
+pub mod secret;
+#[cfg(feature = "signer-utils")]
+pub mod signer;
+pub mod slippage;
+pub mod split;
+pub mod storage;
+pub mod summary;
+pub mod task;
+#[cfg(feature = "otel")]
+mod telemetry;
+pub mod throttle;
+pub mod token2022;
+pub mod token_map;
+pub mod token_policy;
 pub mod tool;
+pub mod tx;
+pub mod typed;
 pub mod types;
+pub mod verify;
+pub mod watcher;
 
 /// Configuration for Jupiter API client
 #[derive(Debug, Clone)]
@@ -34,12 +165,39 @@ pub struct ClientConfig {
     pub max_retries: u32,
     pub retry_delay: Duration,
     pub rate_limit_requests_per_second: Option<u32>,
+    /// Client-wide retry budget; `None` disables it
+    pub retry_budget: Option<RetryBudgetConfig>,
+    /// Start a hedged second attempt when a retried request exceeds this latency
+    pub hedge_after: Option<Duration>,
+    /// Share one upstream call between identical concurrent quote/price/token requests
+    pub coalesce_requests: bool,
+    /// Per-endpoint TTLs of the response cache
+    pub cache_ttl: CacheTtlConfig,
+    /// Maximum number of entries of the default in-memory cache
+    pub cache_capacity: usize,
+    /// Negotiate gzip/brotli compressed responses
+    pub compression: bool,
+    /// TCP keep-alive interval for pooled connections
+    pub tcp_keepalive: Option<Duration>,
+    /// Redacting request/response logger, disabled when `None`
+    pub request_log: Option<RequestLogConfig>,
+    /// Tightens request rates per endpoint on 429 responses, disabled when `None`
+    pub adaptive_throttle: Option<AdaptiveThrottleConfig>,
+    /// Ultra API base URL for RFQ-capable orders; `None` if the API tier lacks it
+    pub ultra_base_url: Option<String>,
+    /// API key sent as `x-api-key`, required by the paid API tiers
+    pub api_key: Option<SecretString>,
+    /// Commitment of Solana reads: signature statuses, transactions and balances
+    pub commitment: CommitmentConfig,
+    /// Integrator tag sent as `x-integrator-id` on every API call, identifying the
+    /// application in Jupiter's analytics
+    pub integrator_id: Option<String>,
 }
 
 impl Default for ClientConfig {
     fn default() -> Self {
         Self {
-            base_url: crate::global::JUPITER_BASE_URL.to_string(),
+            base_url: JUPITER_BASE_URL.to_string(),
             timeout: Duration::from_secs(30),
             connect_timeout: Duration::from_secs(10),
             pool_idle_timeout: Duration::from_secs(90),
@@ -48,16 +206,67 @@ impl Default for ClientConfig {
             max_retries: 3,
             retry_delay: Duration::from_millis(500),
             rate_limit_requests_per_second: Some(10), // Jupiter API 限制
+            retry_budget: Some(RetryBudgetConfig::default()),
+            hedge_after: None,
+            coalesce_requests: true,
+            cache_ttl: CacheTtlConfig::default(),
+            cache_capacity: 1024,
+            compression: true,
+            tcp_keepalive: Some(Duration::from_secs(60)),
+            request_log: None,
+            adaptive_throttle: Some(AdaptiveThrottleConfig::default()),
+            ultra_base_url: Some(JUPITER_ULTRA_BASE_URL.to_string()),
+            api_key: None,
+            commitment: CommitmentConfig::confirmed(),
+            integrator_id: None,
         }
     }
 }
 
+/// Rate limiter, adaptive throttle, retry budget and metrics of a client
+///
+/// All parts share their state between clones, so handing the same limits to several
+/// clients, e.g. for different hosts, enforces one budget against the Jupiter API.
+#[derive(Debug, Clone)]
+pub struct SharedLimits {
+    pub rate_limiter: Option<RateLimiter>,
+    pub adaptive_throttle: Option<AdaptiveThrottle>,
+    pub retry_budget: Option<RetryBudget>,
+    pub metrics: Metrics,
+}
+
 /// Main client for interacting with Jupiter API
+///
+/// Cloning is cheap and clones share rate limits, retry budget, metrics, caches and
+/// in-flight request coalescing.
+#[derive(Clone)]
 pub struct JupiterClient {
     client: Client,
     base_url: String,
     config: ClientConfig,
-    solana: Solana,
+    solana: Arc<Solana>,
+    rate_limiter: Option<RateLimiter>,
+    adaptive_throttle: Option<AdaptiveThrottle>,
+    latency_tracker: LatencyTracker,
+    quote_telemetry: QuoteTelemetry,
+    compute_unit_estimator: ComputeUnitEstimator,
+    metrics: Metrics,
+    lookup_tables: LookupTableCache,
+    retry_budget: Option<RetryBudget>,
+    quote_flight: Arc<SingleFlight<String, QuoteResponse>>,
+    price_flight: Arc<SingleFlight<String, HashMap<String, PriceResponse>>>,
+    token_flight: Arc<SingleFlight<(), Vec<TokenInfo>>>,
+    token_cache: Arc<ConditionalCache<Vec<TokenInfo>>>,
+    token_map: SharedTokenMap,
+    oracle_guard: Option<OracleGuard>,
+    cost_gate: Option<CostGate>,
+    token_policy: Option<TokenPolicy>,
+    pre_trade_checks: Vec<Arc<dyn PreTradeCheck>>,
+    idempotency_storage: Arc<dyn Storage>,
+    slippage_advisor: SlippageAdvisor,
+    venue_reliability: VenueReliability,
+    route_map_cache: Arc<ConditionalCache<IndexedRouteMapResponse>>,
+    cache: Arc<dyn Cache>,
 }
 
 impl JupiterClient {
@@ -70,13 +279,7 @@ impl JupiterClient {
     /// let client = JupiterClient::new().unwrap();
     /// ```
     pub fn new() -> Result<Self, JupiterError> {
-        Ok(Self {
-            client: Client::new(),
-            base_url: JUPITER_BASE_URL.to_string(),
-            config: ClientConfig::default(),
-            solana: Solana::new(solana_network_sdk::types::Mode::MAIN)
-                .map_err(|e| JupiterError::Error(format!("create solana client error: {:?}", e)))?,
-        })
+        Self::from_parts(Client::new(), ClientConfig::default())
     }
 
     /// create a client based on the URL, using the default configuration.
@@ -88,24 +291,16 @@ impl JupiterClient {
     /// let client = JupiterClient::from_base_url("https://quote-api.jup.ag".to_string()).unwrap();
     /// ```
     pub fn from_base_url(base_url: String) -> Result<Self, JupiterError> {
-        Ok(Self {
-            client: Client::new(),
+        let config = ClientConfig {
             base_url,
-            config: ClientConfig::default(),
-            solana: Solana::new(solana_network_sdk::types::Mode::MAIN)
-                .map_err(|e| JupiterError::Error(format!("create solana client error: {:?}", e)))?,
-        })
+            ..ClientConfig::default()
+        };
+        Self::from_parts(Client::new(), config)
     }
 
     /// reate a client based on an existing client, using the default configuration.
     pub fn from_client(client: Client) -> Result<Self, JupiterError> {
-        Ok(Self {
-            client,
-            base_url: JUPITER_BASE_URL.to_string(),
-            config: ClientConfig::default(),
-            solana: Solana::new(solana_network_sdk::types::Mode::MAIN)
-                .map_err(|e| JupiterError::Error(format!("create solana client error: {:?}", e)))?,
-        })
+        Self::from_parts(client, ClientConfig::default())
     }
 
     /// create a client using configuration
@@ -116,17 +311,271 @@ impl JupiterClient {
             .pool_idle_timeout(config.pool_idle_timeout)
             .pool_max_idle_per_host(config.pool_max_idle_per_host)
             .user_agent(&config.user_agent)
+            .gzip(config.compression)
+            .brotli(config.compression)
+            .tcp_keepalive(config.tcp_keepalive)
             .build()
             .map_err(|e| crate::types::JupiterError::NetworkError(e.to_string()))?;
+        Self::from_parts(client, config)
+    }
+
+    /// Assembles a client from an HTTP client and configuration
+    fn from_parts(client: Client, config: ClientConfig) -> Result<Self, JupiterError> {
         Ok(Self {
             client,
             base_url: config.base_url.clone(),
-            config: config,
-            solana: Solana::new(solana_network_sdk::types::Mode::MAIN)
-                .map_err(|e| JupiterError::Error(format!("create solana client error: {:?}", e)))?,
+            rate_limiter: config.rate_limit_requests_per_second.map(RateLimiter::new),
+            adaptive_throttle: config.adaptive_throttle.clone().map(|throttle| {
+                AdaptiveThrottle::new(throttle, config.rate_limit_requests_per_second)
+            }),
+            latency_tracker: LatencyTracker::default(),
+            quote_telemetry: QuoteTelemetry::default(),
+            compute_unit_estimator: ComputeUnitEstimator::default(),
+            metrics: Metrics::default(),
+            lookup_tables: LookupTableCache::new(),
+            retry_budget: config.retry_budget.clone().map(RetryBudget::new),
+            quote_flight: Arc::new(SingleFlight::new()),
+            price_flight: Arc::new(SingleFlight::new()),
+            token_flight: Arc::new(SingleFlight::new()),
+            token_cache: Arc::new(ConditionalCache::new()),
+            token_map: SharedTokenMap::default(),
+            oracle_guard: None,
+            cost_gate: None,
+            token_policy: None,
+            pre_trade_checks: Vec::new(),
+            idempotency_storage: Arc::new(MemoryStorage::new()),
+            slippage_advisor: SlippageAdvisor::default(),
+            venue_reliability: VenueReliability::default(),
+            route_map_cache: Arc::new(ConditionalCache::new()),
+            cache: Arc::new(LruCache::new(config.cache_capacity)),
+            config,
+            solana: Arc::new(
+                Solana::new(solana_network_sdk::types::Mode::MAIN).map_err(|e| {
+                    JupiterError::Error(format!("create solana client error: {:?}", e))
+                })?,
+            ),
         })
     }
 
+    /// Replaces the response cache backend, e.g. with a shared Redis cache
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::{JupiterClient, cache::LruCache};
+    /// use std::sync::Arc;
+    ///
+    /// let client = JupiterClient::new()
+    ///     .unwrap()
+    ///     .with_cache(Arc::new(LruCache::new(10_000)));
+    /// ```
+    pub fn with_cache(mut self, cache: Arc<dyn Cache>) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    /// Gets the rate limiter, adaptive throttle, retry budget and metrics, e.g. to
+    /// share them with another client via `with_shared_limits`
+    pub fn shared_limits(&self) -> SharedLimits {
+        SharedLimits {
+            rate_limiter: self.rate_limiter.clone(),
+            adaptive_throttle: self.adaptive_throttle.clone(),
+            retry_budget: self.retry_budget.clone(),
+            metrics: self.metrics.clone(),
+        }
+    }
+
+    /// Uses another client's limits so both enforce a single budget
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::{ClientConfig, JupiterClient};
+    ///
+    /// let primary = JupiterClient::new().unwrap();
+    /// let secondary = JupiterClient::from_config(ClientConfig {
+    ///     base_url: "https://api.jup.ag/swap/v1".to_string(),
+    ///     ..ClientConfig::default()
+    /// })
+    /// .unwrap()
+    /// .with_shared_limits(primary.shared_limits());
+    /// ```
+    pub fn with_shared_limits(mut self, limits: SharedLimits) -> Self {
+        self.rate_limiter = limits.rate_limiter;
+        self.adaptive_throttle = limits.adaptive_throttle;
+        self.retry_budget = limits.retry_budget;
+        self.metrics = limits.metrics;
+        self
+    }
+
+    /// Cross-checks quotes against a price oracle before building swap transactions
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::{JupiterClient, oracle::{OracleGuard, PythOracle}};
+    /// use std::sync::Arc;
+    ///
+    /// let client = JupiterClient::new()
+    ///     .unwrap()
+    ///     .with_oracle_guard(OracleGuard::new(Arc::new(PythOracle::new()), 150));
+    /// ```
+    pub fn with_oracle_guard(mut self, guard: OracleGuard) -> Self {
+        self.oracle_guard = Some(guard);
+        self
+    }
+
+    /// Refuses to build swaps whose estimated fees are out of proportion to their value
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::{JupiterClient, fees::CostGate};
+    ///
+    /// let client = JupiterClient::new()
+    ///     .unwrap()
+    ///     .with_cost_gate(CostGate::new(100));
+    /// ```
+    pub fn with_cost_gate(mut self, gate: CostGate) -> Self {
+        self.cost_gate = Some(gate);
+        self
+    }
+
+    /// Restricts the mints this client quotes and swaps
+    ///
+    /// Quotes, swap transactions and RFQ orders trading a rejected mint, including
+    /// through an intermediate hop, fail with `JupiterError::PolicyViolation`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::{JupiterClient, token_policy::TokenPolicy};
+    ///
+    /// let client = JupiterClient::new()
+    ///     .unwrap()
+    ///     .with_token_policy(TokenPolicy::new().verified_only());
+    /// ```
+    pub fn with_token_policy(mut self, policy: TokenPolicy) -> Self {
+        self.token_policy = Some(policy);
+        self
+    }
+
+    /// Token policy enforced by this client, if any
+    pub fn token_policy(&self) -> Option<&TokenPolicy> {
+        self.token_policy.as_ref()
+    }
+
+    /// Registers a check approving every trade before it is executed
+    ///
+    /// Checks run in registration order on swaps, split and multi-leg plans and RFQ
+    /// orders; the first veto aborts with `JupiterError::PreTradeVeto`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::{JupiterClient, pretrade::PreTradeCheck};
+    /// use std::sync::Arc;
+    ///
+    /// fn example(kyc: Arc<dyn PreTradeCheck>, limits: Arc<dyn PreTradeCheck>) -> JupiterClient {
+    ///     JupiterClient::new()
+    ///         .unwrap()
+    ///         .with_pre_trade_check(kyc)
+    ///         .with_pre_trade_check(limits)
+    /// }
+    /// ```
+    pub fn with_pre_trade_check(mut self, check: Arc<dyn PreTradeCheck>) -> Self {
+        self.pre_trade_checks.push(check);
+        self
+    }
+
+    /// Sets the commitment of Solana reads
+    ///
+    /// Clones share everything else, so a single call can use another commitment.
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::JupiterClient;
+    /// use solana_commitment_config::CommitmentConfig;
+    /// use solana_sdk::pubkey::Pubkey;
+    ///
+    /// async fn finalized_balance(client: &JupiterClient, wallet: Pubkey) {
+    ///     let finalized = client.clone().with_commitment(CommitmentConfig::finalized());
+    ///     let balance = finalized.get_balance(&wallet).await;
+    /// }
+    /// ```
+    pub fn with_commitment(mut self, commitment: CommitmentConfig) -> Self {
+        self.config.commitment = commitment;
+        self
+    }
+
+    /// Tags every API call with an integrator ID
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::JupiterClient;
+    ///
+    /// let client = JupiterClient::new().unwrap().with_integrator_id("my-wallet-app");
+    /// assert_eq!(client.integrator_id(), Some("my-wallet-app"));
+    /// ```
+    pub fn with_integrator_id(mut self, integrator_id: &str) -> Self {
+        self.config.integrator_id = Some(integrator_id.to_string());
+        self
+    }
+
+    pub fn integrator_id(&self) -> Option<&str> {
+        self.config.integrator_id.as_deref()
+    }
+
+    /// Commitment of Solana reads
+    pub fn commitment(&self) -> CommitmentConfig {
+        self.config.commitment
+    }
+
+    /// Monitoring config used when none is given, at the client's commitment
+    fn default_monitor_config(&self) -> TransactionMonitorConfig {
+        TransactionMonitorConfig {
+            commitment: self.config.commitment,
+            ..TransactionMonitorConfig::default()
+        }
+    }
+
+    /// Records idempotency keys in durable storage, so double submissions are refused
+    /// across restarts; the default in-memory storage only covers this process
+    pub fn with_idempotency_storage(mut self, storage: Arc<dyn Storage>) -> Self {
+        self.idempotency_storage = storage;
+        self
+    }
+
+    /// Replaces the venue reliability tracker, e.g. with one persisted to durable
+    /// storage
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::{JupiterClient, reliability::VenueReliability, storage::FileStorage};
+    /// use std::sync::Arc;
+    ///
+    /// async fn client() -> Result<JupiterClient, Box<dyn std::error::Error>> {
+    ///     let reliability = VenueReliability::default()
+    ///         .with_storage(Arc::new(FileStorage::new("venue-stats")?));
+    ///     reliability.load().await?;
+    ///     Ok(JupiterClient::new()?.with_venue_reliability(reliability))
+    /// }
+    /// ```
+    pub fn with_venue_reliability(mut self, reliability: VenueReliability) -> Self {
+        self.venue_reliability = reliability;
+        self
+    }
+
+    /// Gets the per-venue success rates and confirmation times of monitored swaps
+    pub fn venue_reliability(&self) -> &VenueReliability {
+        &self.venue_reliability
+    }
+
+    /// Replaces the slippage advisor, e.g. to share samples between clients
+    pub fn with_slippage_advisor(mut self, advisor: SlippageAdvisor) -> Self {
+        self.slippage_advisor = advisor;
+        self
+    }
+
+    /// Slippage advisor fed with the prices of quotes made through `get_quote_with_config`
+    pub fn slippage_advisor(&self) -> &SlippageAdvisor {
+        &self.slippage_advisor
+    }
+
     /// create a client with rate limiting
     pub fn with_rate_limit(requests_per_second: u32) -> Result<Self, crate::types::JupiterError> {
         let mut config = ClientConfig::default();
@@ -154,12 +603,30 @@ impl JupiterClient {
         solana: &Solana,
         config: Option<TransactionMonitorConfig>,
     ) -> Result<TransactionMonitorResult, JupiterError> {
+        let config = config.unwrap_or_else(|| self.default_monitor_config());
         let monitor = Monitor;
         monitor
-            .monitor_transaction_status(signature, solana, config)
+            .monitor_transaction_status(signature, solana, Some(config))
             .await
     }
 
+    /// Watches `wallet` and emits its Jupiter swap fills as they land
+    ///
+    /// # Params
+    /// wallet - Wallet to follow
+    /// config - Watcher configuration, polling at the client's commitment when `None`
+    pub fn watch_wallet(
+        &self,
+        wallet: &Pubkey,
+        config: Option<WalletWatcherConfig>,
+    ) -> WalletWatcher {
+        let config = config.unwrap_or_else(|| WalletWatcherConfig {
+            commitment: self.config.commitment,
+            ..WalletWatcherConfig::default()
+        });
+        WalletWatcher::start(self.solana.clone(), *wallet, config)
+    }
+
     /// Monitors multiple transactions in batch
     pub async fn monitor_transactions_batch(
         &self,
@@ -167,14 +634,100 @@ impl JupiterClient {
         solana: &Solana,
         config: Option<TransactionMonitorConfig>,
     ) -> Result<Vec<TransactionMonitorResult>, JupiterError> {
+        let config = config.unwrap_or_else(|| self.default_monitor_config());
         let monitor = Monitor;
         monitor
-            .monitor_transactions_batch(signatures, solana, config)
+            .monitor_transactions_batch(signatures, solana, Some(config))
             .await
     }
 
+    /// Monitors a swap transaction and feeds its confirmation latency into the
+    /// per-DEX latency tracker used for route execution-time estimates, and its
+    /// outcome into the venue reliability tracker
+    #[tracing::instrument(skip(self, solana, route, config))]
+    pub async fn monitor_swap_transaction(
+        &self,
+        signature: &str,
+        solana: &Solana,
+        route: &QuoteResponse,
+        config: Option<TransactionMonitorConfig>,
+    ) -> Result<TransactionMonitorResult, JupiterError> {
+        let result = self.monitor_transaction(signature, solana, config).await?;
+        let confirmed = matches!(
+            result.status,
+            TransactionStatus::Confirmed | TransactionStatus::Finalized
+        );
+        if let (true, Some(confirmation_time)) = (confirmed, result.confirmation_time) {
+            self.latency_tracker.record_route(route, confirmation_time);
+        }
+        if let Some(compute_units) = result.compute_units_consumed {
+            self.compute_unit_estimator.record(route, compute_units);
+        }
+        if let Err(e) = self.venue_reliability.record(route, &result).await {
+            tracing::warn!(error = %e, "failed to persist venue stats");
+        }
+        Ok(result)
+    }
+
+    /// Gets the per-DEX confirmation latency tracker
+    pub fn latency_tracker(&self) -> &LatencyTracker {
+        &self.latency_tracker
+    }
+
+    /// Rolling staleness (`context_slot` lag) and router latency (`time_taken`) of
+    /// recent quotes, to detect a degraded upstream router before fills fail
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::JupiterClient;
+    ///
+    /// fn router_degraded(client: &JupiterClient) -> bool {
+    ///     let stats = client.quote_telemetry();
+    ///     stats.samples > 10 && stats.slot_lag_p90 > 10
+    /// }
+    /// ```
+    pub fn quote_telemetry(&self) -> QuoteTelemetryStats {
+        self.quote_telemetry.stats()
+    }
+
+    /// Gets the compute unit estimator calibrated on executed swaps
+    pub fn compute_unit_estimator(&self) -> &ComputeUnitEstimator {
+        &self.compute_unit_estimator
+    }
+
+    /// Gets the adaptive throttle, if enabled, e.g. to inspect current per-endpoint rates
+    pub fn adaptive_throttle(&self) -> Option<&AdaptiveThrottle> {
+        self.adaptive_throttle.as_ref()
+    }
+
+    /// Per-endpoint request counts, error rates and latency histograms
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::JupiterClient;
+    ///
+    /// async fn example(client: JupiterClient) {
+    ///     let snapshot = client.metrics().take_snapshot();
+    ///     if let Some(quote) = snapshot.endpoint("quote") {
+    ///         println!("quote error rate: {:.2}, p99: {:?}", quote.error_rate(), quote.latency.quantile(0.99));
+    ///     }
+    /// }
+    /// ```
+    pub fn metrics(&self) -> &Metrics {
+        &self.metrics
+    }
+
+    /// Renders the client metrics in the Prometheus text exposition format,
+    /// ready to be served from a `/metrics` scrape endpoint
+    #[cfg(feature = "metrics")]
+    pub fn prometheus_metrics(&self) -> String {
+        self.metrics.snapshot().to_prometheus()
+    }
+
     /// Gets a quote for token swap
     ///
+    /// Identical requests are memoized for `CacheTtlConfig::quotes`, if set.
+    ///
     /// # Example
     /// ```rust
     /// use jupiter_sdk::{JupiterClient, QuoteRequest};
@@ -195,34 +748,308 @@ impl JupiterClient {
     /// Ok(())
     /// }
     /// ```
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            input_mint = %request.input_mint,
+            output_mint = %request.output_mint,
+            amount = request.amount,
+        )
+    )]
     pub async fn get_quote(&self, request: &QuoteRequest) -> Result<QuoteResponse, JupiterError> {
+        let key = serde_json::to_string(request).map_err(|e| JupiterError::Error(e.to_string()))?;
+        let cache_key = format!("quote:{}", key);
+        // Enforced around the cache too, since a shared cache may hold quotes fetched
+        // by a client with another policy
+        self.enforce_token_policy(&[&request.input_mint, &request.output_mint])
+            .await?;
+        let quote = self
+            .cached(&cache_key, self.config.cache_ttl.quotes, || async {
+                if !self.config.coalesce_requests {
+                    return self.send_quote(request, None).await;
+                }
+                self.quote_flight
+                    .run(key, || self.send_quote(request, None))
+                    .await
+            })
+            .await?;
+        self.enforce_token_policy(&quote_mints(&quote)).await?;
+        Ok(quote)
+    }
+
+    /// Gets an ExactOut quote, where `request.amount` is the exact output amount
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            input_mint = %request.input_mint,
+            output_mint = %request.output_mint,
+            amount = request.amount,
+        )
+    )]
+    pub async fn get_quote_exact_out(
+        &self,
+        request: &QuoteRequest,
+    ) -> Result<QuoteResponse, JupiterError> {
+        self.send_policed_quote(request, Some("ExactOut"), None)
+            .await
+    }
+
+    /// Two-sided quoting - Simultaneous buy and sell quotes for market making
+    /// Quotes selling `size` base (ExactIn) and buying `size` base (ExactOut) concurrently,
+    /// and derives the mid price and spread in display units.
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::JupiterClient;
+    ///
+    /// async fn example(client: JupiterClient) -> Result<(), Box<dyn std::error::Error>> {
+    /// let quote = client
+    ///     .get_two_sided_quotes(
+    ///         "So11111111111111111111111111111111111111112",
+    ///         "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
+    ///         10_000_000_000,
+    ///     )
+    ///     .await?;
+    /// println!("mid {} spread {:.1} bps", quote.mid_price, quote.spread_bps);
+    /// Ok(())
+    /// }
+    /// ```
+    #[tracing::instrument(skip(self))]
+    pub async fn get_two_sided_quotes(
+        &self,
+        base: &str,
+        quote_mint: &str,
+        size: u64,
+    ) -> Result<TwoSidedQuote, JupiterError> {
+        let request = |input_mint: &str, output_mint: &str| QuoteRequest {
+            input_mint: input_mint.to_string(),
+            output_mint: output_mint.to_string(),
+            amount: size,
+            slippage_bps: DEFAULT_SLIPPAGE_BPS,
+            fee_bps: None,
+            only_direct_routes: None,
+            as_legacy_transaction: None,
+            restrict_middle_tokens: None,
+        };
+        let (sell, buy) = (request(base, quote_mint), request(quote_mint, base));
+        let (bid, ask, base_decimals, quote_decimals) = tokio::try_join!(
+            self.get_quote(&sell),
+            self.get_quote_exact_out(&buy),
+            self.mint_decimals(base),
+            self.mint_decimals(quote_mint)
+        )?;
+        TwoSidedQuote::from_quotes(size, bid, ask, base_decimals, quote_decimals)
+    }
+
+    /// Gets a quote with a per-call timeout overriding `ClientConfig::timeout`
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            input_mint = %request.input_mint,
+            output_mint = %request.output_mint,
+            amount = request.amount,
+        )
+    )]
+    pub async fn get_quote_with_timeout(
+        &self,
+        request: &QuoteRequest,
+        timeout: Duration,
+    ) -> Result<QuoteResponse, JupiterError> {
+        self.send_policed_quote(request, None, Some(timeout)).await
+    }
+
+    /// Gets a quote that must complete before `deadline`, including rate limiter waits
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::JupiterClient;
+    /// use jup_sdk::types::QuoteRequest;
+    /// use std::time::{Duration, Instant};
+    ///
+    /// async fn example(client: JupiterClient, request: QuoteRequest) -> Result<(), Box<dyn std::error::Error>> {
+    /// // A quote older than a second is worthless for this strategy
+    /// let deadline = Instant::now() + Duration::from_secs(1);
+    /// let quote = client.get_quote_with_deadline(&request, deadline).await?;
+    /// Ok(())
+    /// }
+    /// ```
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            input_mint = %request.input_mint,
+            output_mint = %request.output_mint,
+            amount = request.amount,
+        )
+    )]
+    pub async fn get_quote_with_deadline(
+        &self,
+        request: &QuoteRequest,
+        deadline: Instant,
+    ) -> Result<QuoteResponse, JupiterError> {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return Err(Self::deadline_exceeded());
+        }
+        tokio::time::timeout_at(
+            tokio::time::Instant::from_std(deadline),
+            self.send_policed_quote(request, None, Some(remaining)),
+        )
+        .await
+        .unwrap_or_else(|_| Err(Self::deadline_exceeded()))
+    }
+
+    /// Sends a quote request, enforcing the token policy on the request mints and on
+    /// every mint of the returned route
+    async fn send_policed_quote(
+        &self,
+        request: &QuoteRequest,
+        swap_mode: Option<&str>,
+        timeout: Option<Duration>,
+    ) -> Result<QuoteResponse, JupiterError> {
+        self.enforce_token_policy(&[&request.input_mint, &request.output_mint])
+            .await?;
+        let quote = self
+            .send_quote_with_mode(request, swap_mode, timeout)
+            .await?;
+        self.enforce_token_policy(&quote_mints(&quote)).await?;
+        Ok(quote)
+    }
+
+    /// Sends a quote request without the token policy, which callers enforce
+    async fn send_quote(
+        &self,
+        request: &QuoteRequest,
+        timeout: Option<Duration>,
+    ) -> Result<QuoteResponse, JupiterError> {
+        self.send_quote_with_mode(request, None, timeout).await
+    }
+
+    async fn send_quote_with_mode(
+        &self,
+        request: &QuoteRequest,
+        swap_mode: Option<&str>,
+        timeout: Option<Duration>,
+    ) -> Result<QuoteResponse, JupiterError> {
         self.validate_quote_request(request)?;
         let url = format!("{}/quote", self.base_url);
-        let response = self
-            .client
-            .get(&url)
-            .query(&request)
-            .send()
-            .await
-            .map_err(|e| JupiterError::NetworkError(e.to_string()))?;
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .map_err(|e| JupiterError::NetworkError(e.to_string()))?;
-            return Err(JupiterError::RequestFailed(format!(
-                "HTTP {}: {}",
-                status, error_text
-            )));
+        let mut request_builder = self.client.get(&url).query(&request.to_query_params());
+        if let Some(swap_mode) = swap_mode {
+            request_builder = request_builder.query(&[("swapMode", swap_mode)]);
+        }
+        if let Some(timeout) = timeout {
+            request_builder = request_builder.timeout(timeout);
+        }
+        let response = self.send("quote", request_builder).await?;
+        if !response.status().is_success() {
+            return Err(Self::response_error(response).await);
         }
         let quote: QuoteResponse = response
             .json()
             .await
             .map_err(|e| JupiterError::ParseError(e.to_string()))?;
+        self.quote_telemetry.record(&quote);
         Ok(quote)
     }
 
+    /// Gets an Ultra order, which may be filled by AMMs or an RFQ market maker (Jupiter Z)
+    ///
+    /// Check `RfqOrder::fill` to tell the two apart; either way the order must be
+    /// executed through `execute_rfq_order`, not the `/swap` flow.
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::{JupiterClient, rfq::RfqOrderRequest};
+    ///
+    /// async fn example(client: JupiterClient, taker: String) -> Result<(), Box<dyn std::error::Error>> {
+    /// let order = client
+    ///     .get_rfq_order(&RfqOrderRequest {
+    ///         input_mint: "So11111111111111111111111111111111111111112".to_string(),
+    ///         output_mint: "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v".to_string(),
+    ///         amount: 1_000_000_000,
+    ///         taker: Some(taker),
+    ///     })
+    ///     .await?;
+    /// println!("{:?} fill, out {}", order.fill(), order.out_amount);
+    /// Ok(())
+    /// }
+    /// ```
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            input_mint = %request.input_mint,
+            output_mint = %request.output_mint,
+            amount = request.amount,
+        )
+    )]
+    pub async fn get_rfq_order(&self, request: &RfqOrderRequest) -> Result<RfqOrder, JupiterError> {
+        self.validate_mint_address(&request.input_mint)?;
+        self.validate_mint_address(&request.output_mint)?;
+        if let Some(taker) = &request.taker {
+            self.validate_pubkey(taker)?;
+        }
+        self.enforce_token_policy(&[&request.input_mint, &request.output_mint])
+            .await?;
+        let url = format!("{}/order", self.ultra_base_url()?);
+        let response = self
+            .send("order", self.client.get(&url).query(request))
+            .await?;
+        if !response.status().is_success() {
+            return Err(Self::response_error(response).await);
+        }
+        response
+            .json()
+            .await
+            .map_err(|e| JupiterError::ParseError(e.to_string()))
+    }
+
+    /// Signs an Ultra order's transaction and submits it through the `/execute` endpoint
+    ///
+    /// RFQ fills are co-signed by the market maker on execution, so the transaction
+    /// is partially signed and never sent to the RPC directly.
+    #[tracing::instrument(skip_all, fields(request_id = %order.request_id))]
+    pub async fn execute_rfq_order(
+        &self,
+        order: &RfqOrder,
+        signer: &dyn Signer,
+    ) -> Result<RfqExecuteResponse, JupiterError> {
+        self.enforce_token_policy(&[&order.input_mint, &order.output_mint])
+            .await?;
+        self.run_pre_trade_checks(&order.as_quote(), &signer.pubkey())
+            .await?;
+        let encoded = order.transaction.as_deref().ok_or_else(|| {
+            JupiterError::InvalidInput(
+                order
+                    .error_message
+                    .clone()
+                    .unwrap_or_else(|| "order has no transaction; set taker".to_string()),
+            )
+        })?;
+        let transaction = partial_sign_transaction(decode_swap_transaction(encoded)?, &[signer])?;
+        let body = RfqExecuteRequest {
+            signed_transaction: serialize_base64(&transaction)?,
+            request_id: order.request_id.clone(),
+        };
+        let url = format!("{}/execute", self.ultra_base_url()?);
+        let response = self
+            .send("execute", self.client.post(&url).json(&body))
+            .await?;
+        if !response.status().is_success() {
+            return Err(Self::response_error(response).await);
+        }
+        let result: RfqExecuteResponse = response
+            .json()
+            .await
+            .map_err(|e| JupiterError::ParseError(e.to_string()))?;
+        tracing::info!(status = %result.status, signature = ?result.signature, "order executed");
+        Ok(result)
+    }
+
+    fn ultra_base_url(&self) -> Result<&str, JupiterError> {
+        self.config.ultra_base_url.as_deref().ok_or_else(|| {
+            JupiterError::InvalidInput("Ultra API is not configured for this client".to_string())
+        })
+    }
+
     /// Gets swap transaction data
     ///
     /// # Example
@@ -238,34 +1065,27 @@ impl JupiterClient {
     ///     wrap_and_unwrap_sol: Some(true),
     ///     compute_unit_price: None,
     ///     prioritization_fee_lamports: None,
+    ///     as_legacy_transaction: None,
+    ///     destination_token_account: None,
     /// };
     /// let swap_response = client.get_swap_transaction(&request).await?;
     /// Ok(())
     /// }
     /// ```
+    #[tracing::instrument(skip_all, fields(user = %request.user_public_key))]
     pub async fn get_swap_transaction_data(
         &self,
         request: &SwapRequest,
     ) -> Result<SwapResponse, JupiterError> {
         self.validate_swap_request(request)?;
+        self.enforce_token_policy(&quote_mints(&request.quote_response))
+            .await?;
         let url = format!("{}/swap", self.base_url);
         let response = self
-            .client
-            .post(&url)
-            .json(&request)
-            .send()
-            .await
-            .map_err(|e| JupiterError::NetworkError(e.to_string()))?;
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .map_err(|e| JupiterError::NetworkError(e.to_string()))?;
-            return Err(JupiterError::RequestFailed(format!(
-                "HTTP {}: {}",
-                status, error_text
-            )));
+            .send("swap", self.client.post(&url).json(&request))
+            .await?;
+        if !response.status().is_success() {
+            return Err(Self::response_error(response).await);
         }
         let swap_response: SwapResponse = response
             .json()
@@ -274,34 +1094,127 @@ impl JupiterClient {
         Ok(swap_response)
     }
 
-    /// Gets list of all supported tokens
-    pub async fn get_tokens(&self) -> Result<Vec<TokenInfo>, JupiterError> {
-        let url = format!("{}/tokens", self.base_url);
+    /// Gets the individual instructions of a swap instead of a serialized transaction,
+    /// for composing with other instructions
+    #[tracing::instrument(skip_all, fields(user = %request.user_public_key))]
+    pub async fn get_swap_instructions(
+        &self,
+        request: &SwapRequest,
+    ) -> Result<SwapInstructionsResponse, JupiterError> {
+        self.validate_swap_request(request)?;
+        self.enforce_token_policy(&quote_mints(&request.quote_response))
+            .await?;
+        let url = format!("{}/swap-instructions", self.base_url);
         let response = self
+            .send("swap_instructions", self.client.post(&url).json(&request))
+            .await?;
+        if !response.status().is_success() {
+            return Err(Self::response_error(response).await);
+        }
+        response
+            .json()
+            .await
+            .map_err(|e| JupiterError::ParseError(e.to_string()))
+    }
+
+    /// Composes an unsigned swap transaction with the composer's user instructions,
+    /// fetching the swap instructions, Jupiter's lookup tables and a recent blockhash
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::{JupiterClient, compose::TransactionComposer, types::SwapRequest};
+    /// use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
+    ///
+    /// async fn swap_then_deposit(client: JupiterClient, request: SwapRequest, user: Pubkey, deposit: Instruction) -> Result<(), Box<dyn std::error::Error>> {
+    /// let composer = TransactionComposer::new(user)
+    ///     .with_cleanup_instructions(vec![deposit])
+    ///     .with_extra_compute_units(60_000);
+    /// let transaction = client.compose_swap_transaction(&request, composer).await?;
+    /// Ok(())
+    /// }
+    /// ```
+    pub async fn compose_swap_transaction(
+        &self,
+        request: &SwapRequest,
+        composer: TransactionComposer,
+    ) -> Result<VersionedTransaction, JupiterError> {
+        let swap = self.get_swap_instructions(request).await?;
+        let keys = swap
+            .address_lookup_table_addresses
+            .iter()
+            .map(|address| {
+                validate_pubkey(address).map_err(|e| JupiterError::ParseError(e.to_string()))
+            })
+            .collect::<Result<Vec<Pubkey>, JupiterError>>()?;
+        let tables = self
+            .lookup_tables
+            .load(&self.solana, self.config.commitment, &keys)
+            .await?;
+        let blockhash = self
+            .solana
             .client
-            .get(&url)
-            .send()
+            .clone()
+            .ok_or(JupiterError::Error("solana client error".to_string()))?
+            .get_latest_blockhash()
             .await
             .map_err(|e| JupiterError::NetworkError(e.to_string()))?;
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .map_err(|e| JupiterError::NetworkError(e.to_string()))?;
-            return Err(JupiterError::RequestFailed(format!(
-                "HTTP {}: {}",
-                status, error_text
-            )));
+        composer
+            .with_additional_lookup_tables(tables)
+            .compose(&swap, blockhash)
+    }
+
+    /// Gets list of all supported tokens
+    #[tracing::instrument(skip_all)]
+    pub async fn get_tokens(&self) -> Result<Vec<TokenInfo>, JupiterError> {
+        self.cached("tokens", self.config.cache_ttl.tokens, || async {
+            if !self.config.coalesce_requests {
+                return self.send_tokens(None).await;
+            }
+            self.token_flight.run((), || self.send_tokens(None)).await
+        })
+        .await
+    }
+
+    /// Gets the token list as a `TokenMap` indexed by address, symbol and tag
+    ///
+    /// The map is rebuilt when older than `CacheTtlConfig::tokens`, and is shared
+    /// by the token lookup helpers.
+    pub async fn get_token_map(&self) -> Result<Arc<TokenMap>, JupiterError> {
+        let ttl = self.config.cache_ttl.tokens.unwrap_or_default();
+        if let Some(map) = self.token_map.get(ttl) {
+            return Ok(map);
         }
-        let tokens: Vec<TokenInfo> = response
-            .json()
+        let map = Arc::new(TokenMap::from_tokens(self.get_tokens().await?));
+        self.token_map.set(map.clone());
+        Ok(map)
+    }
+
+    /// Drops the cached `TokenMap`, so the next lookup refetches the token list
+    pub fn invalidate_token_map(&self) {
+        self.token_map.clear();
+    }
+
+    /// Gets list of all supported tokens with a per-call timeout overriding
+    /// `ClientConfig::timeout`, since the full list can take minutes on slow links
+    pub async fn get_tokens_with_timeout(
+        &self,
+        timeout: Duration,
+    ) -> Result<Vec<TokenInfo>, JupiterError> {
+        self.send_tokens(Some(timeout)).await
+    }
+
+    async fn send_tokens(&self, timeout: Option<Duration>) -> Result<Vec<TokenInfo>, JupiterError> {
+        let url = format!("{}/tokens", self.base_url);
+        let mut request_builder = self.client.get(&url);
+        if let Some(timeout) = timeout {
+            request_builder = request_builder.timeout(timeout);
+        }
+        self.send_conditional("tokens", request_builder, &self.token_cache)
             .await
-            .map_err(|e| JupiterError::ParseError(e.to_string()))?;
-        Ok(tokens)
     }
 
     /// Gets prices for multiple tokens
+    #[tracing::instrument(skip_all, fields(ids = ids.len()))]
     pub async fn get_price(
         &self,
         ids: &[String],
@@ -311,26 +1224,31 @@ impl JupiterClient {
                 "No token IDs provided".to_string(),
             ));
         }
+        let ids = ids.join(",");
+        let key = format!("price:{}", ids);
+        self.cached(&key, self.config.cache_ttl.prices, || async {
+            if !self.config.coalesce_requests {
+                return self.send_price(ids).await;
+            }
+            self.price_flight
+                .run(ids.clone(), || self.send_price(ids))
+                .await
+        })
+        .await
+    }
+
+    async fn send_price(
+        &self,
+        ids: String,
+    ) -> Result<HashMap<String, PriceResponse>, JupiterError> {
         let url = format!("{}/price", self.base_url);
         let mut params = HashMap::new();
-        params.insert("ids", ids.join(","));
+        params.insert("ids", ids);
         let response = self
-            .client
-            .get(&url)
-            .query(&params)
-            .send()
-            .await
-            .map_err(|e| JupiterError::NetworkError(e.to_string()))?;
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .map_err(|e| JupiterError::NetworkError(e.to_string()))?;
-            return Err(JupiterError::RequestFailed(format!(
-                "HTTP {}: {}",
-                status, error_text
-            )));
+            .send("price", self.client.get(&url).query(&params))
+            .await?;
+        if !response.status().is_success() {
+            return Err(Self::response_error(response).await);
         }
         let prices: HashMap<String, PriceResponse> = response
             .json()
@@ -340,6 +1258,11 @@ impl JupiterClient {
     }
 
     /// Gets multiple routes for token swap
+    /// Issues the default quote alongside variants (direct routes only, restricted
+    /// intermediate tokens, and the default route's DEXes excluded) concurrently, then
+    /// deduplicates them so each returned route is genuinely distinct.
+    /// Routes are ordered by output amount, best first.
+    #[tracing::instrument(skip(self))]
     pub async fn get_routes(
         &self,
         input_mint: &str,
@@ -350,36 +1273,373 @@ impl JupiterClient {
         self.validate_mint_address(input_mint)?;
         self.validate_mint_address(output_mint)?;
         validate_slippage_bps(slippage_bps).map_err(|e| JupiterError::Error(format!("{:?}", e)))?;
-        let url = format!("{}/quote", self.base_url);
-        let params = [
-            ("inputMint", input_mint),
-            ("outputMint", output_mint),
-            ("amount", &amount.to_string()),
-            ("slippageBps", &slippage_bps.to_string()),
+        let base_params = vec![
+            ("inputMint", input_mint.to_string()),
+            ("outputMint", output_mint.to_string()),
+            ("amount", amount.to_string()),
+            ("slippageBps", slippage_bps.to_string()),
         ];
-        let response = self
+        let base_route = self.get_quote_with_params(&base_params).await?;
+        let mut variants = vec![
+            vec![("onlyDirectRoutes", "true".to_string())],
+            vec![("restrictIntermediateTokens", "true".to_string())],
+        ];
+        let mut used_dexes: Vec<String> = Vec::new();
+        for plan in &base_route.route_plan {
+            let label = plan.swap_info.label.label().to_string();
+            if !used_dexes.contains(&label) {
+                used_dexes.push(label);
+            }
+        }
+        for label in &used_dexes {
+            variants.push(vec![("excludeDexes", label.clone())]);
+        }
+        if used_dexes.len() > 1 {
+            variants.push(vec![("excludeDexes", used_dexes.join(","))]);
+        }
+        let variant_params: Vec<Vec<(&str, String)>> = variants
+            .into_iter()
+            .map(|extra| base_params.iter().cloned().chain(extra).collect())
+            .collect();
+        let variant_routes = join_all(
+            variant_params
+                .iter()
+                .map(|params| self.get_quote_with_params(params)),
+        )
+        .await;
+        let mut routes = vec![base_route];
+        for route in variant_routes.into_iter().flatten() {
+            let key = route_key(&route);
+            if !routes.iter().any(|existing| route_key(existing) == key) {
+                routes.push(route);
+            }
+        }
+        routes.sort_by_key(|route| std::cmp::Reverse(route.out_amount.parse::<u64>().unwrap_or(0)));
+        Ok(routes)
+    }
+
+    /// Plans splitting an order across the top alternative routes
+    ///
+    /// Alternative routes are found by excluding the DEXes of every better route, so
+    /// legs don't trade against the same pools. Each candidate split in `config` is
+    /// quoted at its leg sizes; the best one is returned if it beats the single best
+    /// route by at least `config.min_improvement_bps`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::{JupiterClient, split::SplitConfig, types::QuoteRequest};
+    ///
+    /// # async fn example(client: JupiterClient, request: QuoteRequest) -> Result<(), Box<dyn std::error::Error>> {
+    /// if let Some(plan) = client.plan_split(&request, &SplitConfig::default()).await? {
+    ///     println!("{} legs, +{:.1} bps", plan.legs.len(), plan.improvement_bps);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(skip_all, fields(input_mint = %request.input_mint, output_mint = %request.output_mint, amount = request.amount))]
+    pub async fn plan_split(
+        &self,
+        request: &QuoteRequest,
+        config: &SplitConfig,
+    ) -> Result<Option<SplitPlan>, JupiterError> {
+        let mut excluded: Vec<String> = Vec::new();
+        let mut routes: Vec<(Vec<String>, QuoteResponse)> = Vec::new();
+        for _ in 0..config.max_routes.max(1) {
+            let params = leg_params(request, request.amount, &excluded);
+            let route = match self.get_quote_with_params(&params).await {
+                Ok(route) => route,
+                Err(e) if routes.is_empty() => return Err(e),
+                Err(_) => break,
+            };
+            let venues: Vec<String> = route
+                .route_plan
+                .iter()
+                .map(|plan| plan.swap_info.label.label().to_string())
+                .collect();
+            routes.push((excluded.clone(), route));
+            for venue in venues {
+                if !excluded.contains(&venue) {
+                    excluded.push(venue);
+                }
+            }
+        }
+        let single_route_out: u64 = routes[0].1.out_amount.parse().unwrap_or(0);
+        let plans = join_all(config.candidate_splits(routes.len()).map(|shares| {
+            let routes = &routes;
+            async move {
+                let amounts = split_amounts(request.amount, shares);
+                let quotes = join_all(amounts.iter().zip(routes).map(|(amount, (excluded, _))| {
+                    let params = leg_params(request, *amount, excluded);
+                    async move { self.get_quote_with_params(&params).await }
+                }))
+                .await;
+                let legs = shares
+                    .iter()
+                    .zip(amounts)
+                    .zip(routes)
+                    .zip(quotes)
+                    .map(|(((share, amount), (excluded, _)), quote)| {
+                        Ok(SplitLeg {
+                            share_bps: *share,
+                            amount,
+                            excluded_dexes: excluded.clone(),
+                            quote: quote?,
+                        })
+                    })
+                    .collect::<Result<Vec<SplitLeg>, JupiterError>>()?;
+                Ok::<_, JupiterError>(SplitPlan::new(legs, single_route_out, config.execution))
+            }
+        }))
+        .await;
+        let best = plans
+            .into_iter()
+            .filter_map(|plan| {
+                plan.inspect_err(|e| tracing::debug!(error = %e, "split candidate failed"))
+                    .ok()
+            })
+            .max_by_key(|plan| plan.total_out);
+        Ok(best.filter(|plan| plan.improvement_bps >= config.min_improvement_bps))
+    }
+
+    /// Executes a split plan, atomically or leg by leg per `plan.execution`
+    ///
+    /// # Returns
+    /// One monitoring result per submitted transaction; sequential execution stops at
+    /// the first leg that doesn't confirm.
+    pub async fn execute_split(
+        &self,
+        plan: &SplitPlan,
+        signer: &dyn Signer,
+        send_options: &SendOptions,
+    ) -> Result<Vec<TransactionMonitorResult>, JupiterError> {
+        for leg in &plan.legs {
+            self.run_pre_trade_checks(&leg.quote, &signer.pubkey())
+                .await?;
+        }
+        let requests = plan.swap_requests(&signer.pubkey().to_string());
+        let mut results = Vec::new();
+        match plan.execution {
+            SplitExecution::Sequential => {
+                for request in &requests {
+                    let swap = self.get_swap_transaction_data(request).await?;
+                    let transaction =
+                        SwapTransaction::decode(&swap.swap_transaction, false)?.sign(&[signer])?;
+                    transaction.validate_size()?;
+                    let signature = self
+                        .send_swap_transaction(&transaction, send_options)
+                        .await?
+                        .to_string();
+                    let result = self
+                        .monitor_swap_transaction(
+                            &signature,
+                            &self.solana,
+                            &request.quote_response,
+                            None,
+                        )
+                        .await?;
+                    let confirmed = matches!(
+                        result.status,
+                        TransactionStatus::Confirmed | TransactionStatus::Finalized
+                    );
+                    results.push(result);
+                    if !confirmed {
+                        break;
+                    }
+                }
+            }
+            SplitExecution::Atomic => {
+                let transaction = self.compose_swaps(&requests, signer).await?;
+                let signature = self
+                    .send_swap_transaction(&transaction, send_options)
+                    .await?
+                    .to_string();
+                let result = self
+                    .monitor_swap_transaction(&signature, &self.solana, &plan.legs[0].quote, None)
+                    .await?;
+                results.push(result);
+            }
+        }
+        Ok(results)
+    }
+
+    /// Composes the swaps of `requests` into one signed atomic transaction
+    ///
+    /// Fails with `JupiterError::ValidationError` when they don't fit one transaction.
+    async fn compose_swaps(
+        &self,
+        requests: &[SwapRequest],
+        signer: &dyn Signer,
+    ) -> Result<SwapTransaction, JupiterError> {
+        let swaps = futures::future::try_join_all(
+            requests
+                .iter()
+                .map(|request| self.get_swap_instructions(request)),
+        )
+        .await?;
+        let keys = swaps
+            .iter()
+            .flat_map(|swap| &swap.address_lookup_table_addresses)
+            .map(|address| {
+                validate_pubkey(address).map_err(|e| JupiterError::ParseError(e.to_string()))
+            })
+            .collect::<Result<Vec<Pubkey>, JupiterError>>()?;
+        let tables = self
+            .lookup_tables
+            .load(&self.solana, self.config.commitment, &keys)
+            .await?;
+        let blockhash = self
+            .solana
             .client
-            .get(&url)
-            .query(&params)
-            .send()
+            .clone()
+            .ok_or(JupiterError::Error("solana client error".to_string()))?
+            .get_latest_blockhash()
             .await
             .map_err(|e| JupiterError::NetworkError(e.to_string()))?;
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .map_err(|e| JupiterError::NetworkError(e.to_string()))?;
-            return Err(JupiterError::RequestFailed(format!(
-                "HTTP {}: {}",
-                status, error_text
-            )));
+        let transaction = TransactionComposer::new(signer.pubkey())
+            .with_additional_lookup_tables(tables)
+            .compose_many(&swaps, blockhash)?;
+        SwapTransaction::Versioned(transaction).sign(&[signer])
+    }
+
+    /// Quotes a swap through explicit intermediate mints chosen by the caller
+    ///
+    /// Legs are quoted in order with the default slippage, each spending the previous
+    /// leg's minimum output.
+    ///
+    /// # Params
+    /// legs - `(input_mint, output_mint)` pairs, each starting from the previous output
+    /// amount - Input amount of the first leg (raw units)
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::JupiterClient;
+    ///
+    /// async fn example(client: JupiterClient) -> Result<(), Box<dyn std::error::Error>> {
+    /// let sol = "So11111111111111111111111111111111111111112";
+    /// let usdc = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+    /// let bonk = "DezXAZ8z7PnrnRJjz3wXBoRgixCa6xjnB7YaB1pPB263";
+    /// let plan = client.quote_multi_leg(&[(sol, usdc), (usdc, bonk)], 1_000_000_000).await?;
+    /// println!("{:?}: {} → {}", plan.path(), plan.in_amount, plan.out_amount);
+    /// Ok(())
+    /// }
+    /// ```
+    pub async fn quote_multi_leg(
+        &self,
+        legs: &[(&str, &str)],
+        amount: u64,
+    ) -> Result<MultiLegPlan, JupiterError> {
+        validate_legs(legs)?;
+        let mut quotes = Vec::with_capacity(legs.len());
+        let mut leg_amount = amount;
+        for (input_mint, output_mint) in legs {
+            let quote = self
+                .get_quote(&QuoteRequest {
+                    input_mint: input_mint.to_string(),
+                    output_mint: output_mint.to_string(),
+                    amount: leg_amount,
+                    slippage_bps: DEFAULT_SLIPPAGE_BPS,
+                    fee_bps: None,
+                    only_direct_routes: None,
+                    as_legacy_transaction: None,
+                    restrict_middle_tokens: None,
+                })
+                .await?;
+            leg_amount = quote
+                .other_amount_threshold
+                .parse()
+                .map_err(|e| JupiterError::ParseError(format!("other amount threshold: {}", e)))?;
+            quotes.push(quote);
         }
-        let routes: Vec<QuoteResponse> = response
+        MultiLegPlan::new(quotes)
+    }
+
+    /// Executes a multi-leg plan, atomically in one transaction when the legs fit and
+    /// leg by leg otherwise
+    ///
+    /// # Returns
+    /// One monitoring result per submitted transaction; leg-by-leg execution stops at
+    /// the first leg that doesn't confirm.
+    pub async fn execute_multi_leg(
+        &self,
+        plan: &MultiLegPlan,
+        signer: &dyn Signer,
+        send_options: &SendOptions,
+    ) -> Result<Vec<TransactionMonitorResult>, JupiterError> {
+        for leg in &plan.legs {
+            self.run_pre_trade_checks(leg, &signer.pubkey()).await?;
+        }
+        let requests = plan.swap_requests(&signer.pubkey().to_string());
+        match self.compose_swaps(&requests, signer).await {
+            Ok(transaction) => {
+                let signature = self
+                    .send_swap_transaction(&transaction, send_options)
+                    .await?
+                    .to_string();
+                let result = self
+                    .monitor_swap_transaction(&signature, &self.solana, &plan.legs[0], None)
+                    .await?;
+                Ok(vec![result])
+            }
+            Err(JupiterError::ValidationError(reason)) => {
+                tracing::info!(reason = %reason, "legs don't fit one transaction, executing leg by leg");
+                let mut results = Vec::new();
+                for request in &requests {
+                    let swap = self.get_swap_transaction_data(request).await?;
+                    let transaction =
+                        SwapTransaction::decode(&swap.swap_transaction, false)?.sign(&[signer])?;
+                    transaction.validate_size()?;
+                    let signature = self
+                        .send_swap_transaction(&transaction, send_options)
+                        .await?
+                        .to_string();
+                    let result = self
+                        .monitor_swap_transaction(
+                            &signature,
+                            &self.solana,
+                            &request.quote_response,
+                            None,
+                        )
+                        .await?;
+                    let confirmed = matches!(
+                        result.status,
+                        TransactionStatus::Confirmed | TransactionStatus::Finalized
+                    );
+                    results.push(result);
+                    if !confirmed {
+                        break;
+                    }
+                }
+                Ok(results)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Gets a quote from raw API query parameters
+    async fn get_quote_with_params(
+        &self,
+        params: &[(&str, String)],
+    ) -> Result<QuoteResponse, JupiterError> {
+        let mints: Vec<&str> = params
+            .iter()
+            .filter(|(key, _)| matches!(*key, "inputMint" | "outputMint"))
+            .map(|(_, mint)| mint.as_str())
+            .collect();
+        self.enforce_token_policy(&mints).await?;
+        let url = format!("{}/quote", self.base_url);
+        let response = self
+            .send("quote", self.client.get(&url).query(params))
+            .await?;
+        if !response.status().is_success() {
+            return Err(Self::response_error(response).await);
+        }
+        let quote: QuoteResponse = response
             .json()
             .await
             .map_err(|e| JupiterError::ParseError(e.to_string()))?;
-        Ok(routes)
+        self.quote_telemetry.record(&quote);
+        self.enforce_token_policy(&quote_mints(&quote)).await?;
+        Ok(quote)
     }
 
     /// Simple method to get swap quote
@@ -423,11 +1683,10 @@ impl JupiterClient {
         &self,
         symbol: &str,
     ) -> Result<Option<TokenInfo>, JupiterError> {
-        let tokens = self.get_tokens().await?;
-        let token = tokens
-            .into_iter()
-            .find(|token| token.symbol.to_lowercase() == symbol.to_lowercase());
-        Ok(token)
+        let tokens = self.get_token_map().await?;
+        Ok(tokens
+            .first_by_symbol(symbol)
+            .map(|token| token.as_ref().clone()))
     }
 
     /// Finds token by address
@@ -436,9 +1695,8 @@ impl JupiterClient {
         address: &str,
     ) -> Result<Option<TokenInfo>, JupiterError> {
         self.validate_mint_address(address)?;
-        let tokens = self.get_tokens().await?;
-        let token = tokens.into_iter().find(|token| token.address == address);
-        Ok(token)
+        let tokens = self.get_token_map().await?;
+        Ok(tokens.get(address).map(|token| token.as_ref().clone()))
     }
 
     /// Gets price for a single token
@@ -462,113 +1720,1638 @@ impl JupiterClient {
             wrap_and_unwrap_sol,
             compute_unit_price: None,
             prioritization_fee_lamports: None,
+            as_legacy_transaction: None,
+            destination_token_account: None,
         };
         self.get_swap_transaction_data(&request).await
     }
 
-    pub async fn get_quotes_batch(
+    /// Gets a quote honoring an AdvancedSwapConfig
+    /// Preferred/excluded AMMs become `dexes`/`excludeDexes`, `use_versioned_transaction`
+    /// controls `asLegacyTransaction`, and quotes exceeding the slippage or price impact
+    /// limits are rejected with a ValidationError.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            input_mint = %request.input_mint,
+            output_mint = %request.output_mint,
+            amount = request.amount,
+        )
+    )]
+    pub async fn get_quote_with_config(
+        &self,
+        request: &QuoteRequest,
+        config: &AdvancedSwapConfig,
+    ) -> Result<QuoteResponse, JupiterError> {
+        self.validate_quote_request(request)?;
+        if request.slippage_bps > config.max_slippage_bps {
+            return Err(JupiterError::ValidationError(format!(
+                "Slippage {} bps exceeds maximum {} bps",
+                request.slippage_bps, config.max_slippage_bps
+            )));
+        }
+        let mut params = request.to_query_params();
+        if config.auto_slippage {
+            let slippage_bps = self
+                .slippage_advisor
+                .recommend(&request.input_mint, &request.output_mint)
+                .min(config.max_slippage_bps);
+            tracing::debug!(slippage_bps, "using advised slippage");
+            params.retain(|(key, _)| *key != "slippageBps");
+            params.push(("slippageBps", slippage_bps.to_string()));
+        }
+        params.retain(|(key, _)| *key != "asLegacyTransaction");
+        params.push((
+            "asLegacyTransaction",
+            (!config.use_versioned_transaction).to_string(),
+        ));
+        if config.exact_out {
+            params.push(("swapMode", "ExactOut".to_string()));
+        }
+        if !config.preferred_amms.is_empty() {
+            params.push(("dexes", config.preferred_amms.join(",")));
+        }
+        if !config.excluded_amms.is_empty() {
+            params.push(("excludeDexes", config.excluded_amms.join(",")));
+        }
+        let quote = self.get_quote_with_params(&params).await?;
+        self.slippage_advisor.observe_quote(&quote);
+        let price_impact_bps = quote.price_impact_pct.parse::<f64>().unwrap_or(0.0) * 100.0;
+        if price_impact_bps > config.max_price_impact_bps as f64 {
+            return Err(JupiterError::ValidationError(format!(
+                "Price impact {:.2} bps exceeds maximum {} bps",
+                price_impact_bps, config.max_price_impact_bps
+            )));
+        }
+        Ok(quote)
+    }
+
+    /// Quotes and builds a swap transaction honoring an AdvancedSwapConfig
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            input_mint = %request.input_mint,
+            output_mint = %request.output_mint,
+            amount = request.amount,
+            user = %user_public_key,
+        )
+    )]
+    pub async fn execute_swap_with_config(
+        &self,
+        request: &QuoteRequest,
+        user_public_key: &str,
+        config: &AdvancedSwapConfig,
+    ) -> Result<SwapExecutionResult, JupiterError> {
+        self.prepare_swap(request, user_public_key, config, false)
+            .await
+            .map(|(result, _)| result)
+    }
+
+    /// Quotes and builds a swap like `execute_swap_with_config`, also returning the
+    /// destination associated token account when `config` sets a recipient or, with
+    /// `check_destination_ata`, asks for the account to be ensured
+    async fn prepare_swap(
+        &self,
+        request: &QuoteRequest,
+        user_public_key: &str,
+        config: &AdvancedSwapConfig,
+        check_destination_ata: bool,
+    ) -> Result<(SwapExecutionResult, Option<AtaStatus>), JupiterError> {
+        self.validate_pubkey(user_public_key)?;
+        let quote = self.get_quote_with_config(request, config).await?;
+        if let Some(guard) = &self.oracle_guard {
+            let (input_decimals, output_decimals) = tokio::try_join!(
+                self.mint_decimals(&quote.input_mint),
+                self.mint_decimals(&quote.output_mint)
+            )?;
+            let check = guard.check(&quote, input_decimals, output_decimals).await?;
+            tracing::debug!(deviation_bps = check.deviation_bps, "oracle check passed");
+        }
+        let wallet = validate_pubkey(user_public_key).map_err(JupiterError::InvalidInput)?;
+        self.run_pre_trade_checks(&quote, &wallet).await?;
+        let destination_ata = if config.recipient.is_some()
+            || (check_destination_ata && config.ensure_destination_ata)
+        {
+            let output_mint =
+                validate_pubkey(&quote.output_mint).map_err(JupiterError::InvalidInput)?;
+            let owner = config.recipient.unwrap_or(wallet);
+            Some(self.ensure_ata(&owner, &output_mint).await?)
+        } else {
+            None
+        };
+        let destination_token_account = config
+            .recipient
+            .and(destination_ata.as_ref())
+            .map(|status| status.address.to_string());
+        let mut swap_request = SwapRequest {
+            quote_response: quote.clone(),
+            user_public_key: user_public_key.to_string(),
+            wrap_and_unwrap_sol: None,
+            compute_unit_price: None,
+            prioritization_fee_lamports: None,
+            as_legacy_transaction: Some(!config.use_versioned_transaction),
+            destination_token_account,
+        };
+        if let Some(priority) = config.priority {
+            priority.apply(&mut swap_request);
+        }
+        let swap_response = self.get_swap_transaction_data(&swap_request).await?;
+        if let Some(gate) = &self.cost_gate {
+            let (estimate, notional_lamports) = tokio::try_join!(
+                self.estimate_transaction_fee(&quote, Some(&swap_response)),
+                self.notional_lamports(&quote)
+            )?;
+            let check = gate.check(&quote, &estimate, notional_lamports)?;
+            tracing::debug!(fee_bps = check.fee_bps, "cost check passed");
+        }
+        // Transfer fees only refine the expected output, so lookup failures are ignored
+        let input_transfer_fee = match validate_pubkey(&quote.input_mint) {
+            Ok(mint) => self.get_transfer_fee(&mint).await.ok().flatten(),
+            Err(_) => None,
+        };
+        let output_transfer_fee = match validate_pubkey(&quote.output_mint) {
+            Ok(mint) => self.get_transfer_fee(&mint).await.ok().flatten(),
+            Err(_) => None,
+        };
+        let result = SwapExecutionResult {
+            quote,
+            swap_response,
+            config: config.clone(),
+            signature: None,
+            monitor_result: None,
+            input_transfer_fee,
+            output_transfer_fee,
+            attempts: Vec::new(),
+        };
+        Ok((result, destination_ata))
+    }
+
+    /// Executes a swap end to end
+    /// Quotes and builds the transaction honoring `config`, signs it with `signer`, sends it
+    /// through the client's Solana RPC and monitors it until a final state.
+    /// Legacy transactions are used when `config.use_versioned_transaction` is false.
+    /// With `config.execution_policy`, slippage failures and timeouts are re-quoted and
+    /// retried with adjusted parameters, each attempt recorded in `attempts`.
+    /// With `config.mev_protection`, the transaction and its rebroadcasts go only to the
+    /// configured private relays; settings that would still expose the swap are logged
+    /// as warnings.
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::{JupiterClient, types::{AdvancedSwapConfig, QuoteRequest}};
+    /// use solana_sdk::signature::Keypair;
+    ///
+    /// async fn example(request: QuoteRequest, keypair: Keypair) -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = JupiterClient::new()?;
+    /// let result = client
+    ///     .execute_swap(&request, &keypair, &AdvancedSwapConfig::default(), None)
+    ///     .await?;
+    /// println!("Signature: {:?}, confirmed: {}", result.signature, result.is_confirmed());
+    /// Ok(())
+    /// }
+    /// ```
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            input_mint = %request.input_mint,
+            output_mint = %request.output_mint,
+            amount = request.amount,
+            user = %signer.pubkey(),
+        )
+    )]
+    pub async fn execute_swap(
+        &self,
+        request: &QuoteRequest,
+        signer: &dyn Signer,
+        config: &AdvancedSwapConfig,
+        monitor_config: Option<TransactionMonitorConfig>,
+    ) -> Result<SwapExecutionResult, JupiterError> {
+        let Some(policy) = &config.execution_policy else {
+            return self
+                .execute_swap_attempt(request, signer, config, monitor_config)
+                .await;
+        };
+        let mut request = request.clone();
+        let mut config = config.clone();
+        let idempotency_key = config.idempotency_key.clone();
+        let mut attempts: Vec<ExecutionAttempt> = Vec::new();
+        let mut adjustment = None;
+        loop {
+            let mut result = self
+                .execute_swap_attempt(&request, signer, &config, monitor_config.clone())
+                .await?;
+            let attempt = ExecutionAttempt {
+                attempt: attempts.len() as u32 + 1,
+                slippage_bps: result.quote.slippage_bps,
+                priority: config.priority,
+                signature: result.signature.clone(),
+                status: result.monitor_result.as_ref().map(|r| r.status.clone()),
+                failure_reason: result
+                    .monitor_result
+                    .as_ref()
+                    .and_then(|r| r.failure_reason.clone()),
+                adjustment,
+            };
+            adjustment = policy.next_adjustment(&attempt);
+            attempts.push(attempt);
+            let Some(next) = adjustment else {
+                result.attempts = attempts;
+                return Ok(result);
+            };
+            tracing::info!(attempt = attempts.len(), adjustment = ?next, "retrying swap");
+            match next {
+                RetryAdjustment::Slippage { to_bps, .. } => {
+                    request.slippage_bps = to_bps;
+                    config.max_slippage_bps = config.max_slippage_bps.max(to_bps);
+                    config.auto_slippage = false;
+                }
+                RetryAdjustment::Priority { to, .. } => config.priority = Some(to),
+            }
+            // Each attempt is a new transaction, so it needs its own idempotency key
+            config.idempotency_key = idempotency_key
+                .as_ref()
+                .map(|key| format!("{}#{}", key, attempts.len() + 1));
+        }
+    }
+
+    /// Executes a swap whose output is delivered to `recipient` instead of the signer,
+    /// e.g. for payments and payouts
+    ///
+    /// The recipient's associated token account for the output mint is created first
+    /// if missing, paid by `signer`. SOL output is delivered as wrapped SOL, since only
+    /// the signer's own account can be unwrapped.
+    ///
+    /// # Params
+    /// recipient - Owner receiving the output
+    /// request - Quote request, with `amount` in the input token
+    /// signer - Wallet paying the input and fees
+    /// config - Swap configuration; its `recipient` is replaced
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::{JupiterClient, types::{AdvancedSwapConfig, QuoteRequest}};
+    /// use solana_sdk::{pubkey::Pubkey, signature::Keypair};
+    ///
+    /// async fn example(request: QuoteRequest, keypair: Keypair, merchant: Pubkey) -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = JupiterClient::new()?;
+    /// let result = client
+    ///     .execute_swap_to(&merchant, &request, &keypair, &AdvancedSwapConfig::default(), None)
+    ///     .await?;
+    /// println!("Paid in {:?}", result.signature);
+    /// Ok(())
+    /// }
+    /// ```
+    pub async fn execute_swap_to(
+        &self,
+        recipient: &Pubkey,
+        request: &QuoteRequest,
+        signer: &dyn Signer,
+        config: &AdvancedSwapConfig,
+        monitor_config: Option<TransactionMonitorConfig>,
+    ) -> Result<SwapExecutionResult, JupiterError> {
+        let config = AdvancedSwapConfig {
+            recipient: Some(*recipient),
+            ..config.clone()
+        };
+        self.execute_swap(request, signer, &config, monitor_config)
+            .await
+    }
+
+    /// Pays an exact amount of `output_mint`, funded by the cheapest accepted input
+    ///
+    /// Every accepted input mint is quoted ExactOut and valued at its USD price; the
+    /// cheapest one is executed ExactOut through `execute_swap`, honoring `config`.
+    /// Payments go to `config.recipient`, e.g. a merchant, or to the payer without
+    /// one. Input mints that can't be quoted or priced, e.g. without a route or with an
+    /// unreadable mint account, are skipped.
+    ///
+    /// # Params
+    /// output_mint - Mint of the payment
+    /// exact_amount - Amount to deliver (raw units)
+    /// payer - Wallet paying the input and fees
+    /// accepted_input_mints - Mints the payer is willing to spend
+    /// config - Swap configuration; `exact_out` is always set
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::{JupiterClient, types::AdvancedSwapConfig};
+    /// use solana_sdk::{pubkey::Pubkey, signature::Keypair};
+    ///
+    /// async fn example(client: JupiterClient, payer: Keypair, merchant: Pubkey) -> Result<(), Box<dyn std::error::Error>> {
+    /// let usdc = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+    /// let accepted = [
+    ///     "So11111111111111111111111111111111111111112",
+    ///     "DezXAZ8z7PnrnRJjz3wXBoRgixCa6xjnB7YaB1pPB263",
+    /// ];
+    /// let config = AdvancedSwapConfig {
+    ///     recipient: Some(merchant),
+    ///     ..AdvancedSwapConfig::default()
+    /// };
+    /// let receipt = client.pay_exact(usdc, 25_000_000, &payer, &accepted, &config).await?;
+    /// println!("Paid {} with {} of {}", receipt.amount_delivered, receipt.amount_paid, receipt.input_mint);
+    /// Ok(())
+    /// }
+    /// ```
+    #[tracing::instrument(
+        skip_all,
+        fields(output_mint = %output_mint, exact_amount = exact_amount, payer = %payer.pubkey())
+    )]
+    pub async fn pay_exact(
+        &self,
+        output_mint: &str,
+        exact_amount: u64,
+        payer: &dyn Signer,
+        accepted_input_mints: &[&str],
+        config: &AdvancedSwapConfig,
+    ) -> Result<PaymentReceipt, JupiterError> {
+        self.validate_mint_address(output_mint)?;
+        let candidates: Vec<&str> = accepted_input_mints
+            .iter()
+            .copied()
+            .filter(|mint| *mint != output_mint)
+            .collect();
+        if candidates.is_empty() {
+            return Err(JupiterError::InvalidInput(
+                "no accepted input mint other than the output mint".to_string(),
+            ));
+        }
+        let request = |input_mint: &str| QuoteRequest {
+            input_mint: input_mint.to_string(),
+            output_mint: output_mint.to_string(),
+            amount: exact_amount,
+            slippage_bps: DEFAULT_SLIPPAGE_BPS.min(config.max_slippage_bps),
+            fee_bps: None,
+            only_direct_routes: None,
+            as_legacy_transaction: None,
+            restrict_middle_tokens: None,
+        };
+        let ids: Vec<String> = candidates.iter().map(|mint| mint.to_string()).collect();
+        let (quotes, prices) = tokio::join!(
+            join_all(
+                candidates
+                    .iter()
+                    .map(|mint| self.get_quote_exact_out(&request(mint)))
+            ),
+            self.get_price(&ids)
+        );
+        let prices = prices
+            .inspect_err(|e| tracing::debug!(error = %e, "payment prices unavailable"))
+            .unwrap_or_default();
+        let mut options = Vec::new();
+        for (mint, quote) in candidates.iter().zip(quotes) {
+            let quote = match quote {
+                Ok(quote) => quote,
+                Err(e) => {
+                    tracing::debug!(input_mint = %mint, error = %e, "payment input not quotable");
+                    continue;
+                }
+            };
+            let Ok(in_amount) = quote.in_amount.parse::<u64>() else {
+                tracing::debug!(input_mint = %mint, in_amount = %quote.in_amount, "payment quote has an invalid input amount");
+                continue;
+            };
+            let cost_usd = match prices.get(*mint) {
+                Some(price) => match self.mint_decimals(mint).await {
+                    Ok(decimals) => Some(usd_value(in_amount, decimals, price.price)),
+                    Err(e) => {
+                        tracing::debug!(input_mint = %mint, error = %e, "payment input decimals unavailable");
+                        continue;
+                    }
+                },
+                None => None,
+            };
+            options.push(PaymentOption {
+                input_mint: mint.to_string(),
+                quote,
+                cost_usd,
+            });
+        }
+        sort_options(&mut options);
+        let best = match options.as_slice() {
+            [] => {
+                return Err(JupiterError::ValidationError(
+                    "no accepted input mint can fund the payment".to_string(),
+                ));
+            }
+            [best] => best,
+            [best, ..] if best.cost_usd.is_some() => best,
+            _ => {
+                return Err(JupiterError::ValidationError(
+                    "accepted input mints can't be compared without prices".to_string(),
+                ));
+            }
+        };
+        tracing::info!(input_mint = %best.input_mint, cost_usd = ?best.cost_usd, "paying with cheapest input");
+        let config = AdvancedSwapConfig {
+            exact_out: true,
+            ..config.clone()
+        };
+        let result = self
+            .execute_swap(&request(&best.input_mint), payer, &config, None)
+            .await?;
+        Ok(PaymentReceipt {
+            input_mint: best.input_mint.clone(),
+            output_mint: output_mint.to_string(),
+            recipient: config.recipient.unwrap_or_else(|| payer.pubkey()),
+            amount_delivered: result.quote.out_amount.parse().unwrap_or(exact_amount),
+            amount_paid: result
+                .quote
+                .in_amount
+                .parse()
+                .unwrap_or_else(|_| best.in_amount()),
+            max_amount_paid: result
+                .quote
+                .other_amount_threshold
+                .parse()
+                .unwrap_or_else(|_| best.max_in_amount()),
+            cost_usd: best.cost_usd,
+            signature: result.signature,
+            monitor_result: result.monitor_result,
+            options,
+        })
+    }
+
+    /// Executes a single swap attempt of `execute_swap`
+    async fn execute_swap_attempt(
+        &self,
+        request: &QuoteRequest,
+        signer: &dyn Signer,
+        config: &AdvancedSwapConfig,
+        monitor_config: Option<TransactionMonitorConfig>,
+    ) -> Result<SwapExecutionResult, JupiterError> {
+        if let Some(key) = &config.idempotency_key
+            && let Some(record) = self.idempotency_record(key).await?
+        {
+            return Err(JupiterError::AlreadySubmitted {
+                key: key.clone(),
+                signature: record.signature,
+            });
+        }
+        if let Some(protection) = &config.mev_protection {
+            protection.validate()?;
+            for warning in protection.warnings(config) {
+                tracing::warn!(warning = %warning, "swap may be exposed publicly");
+            }
+        }
+        let (mut result, destination_ata) = self
+            .prepare_swap(request, &signer.pubkey().to_string(), config, true)
+            .await?;
+        // Created once pre-trade checks approved the swap, so a veto sends nothing
+        if let Some(status) = destination_ata.filter(|status| !status.exists) {
+            self.create_ata(signer, &status).await?;
+        }
+        let transaction = SwapTransaction::decode(
+            &result.swap_response.swap_transaction,
+            !config.use_versioned_transaction,
+        )?
+        .sign(&[signer])?;
+        transaction.validate_size()?;
+        if let Some(key) = &config.idempotency_key {
+            self.claim_idempotency_key(key, &transaction).await?;
+        }
+        let signature = self
+            .submit_swap_transaction(&transaction, config)
+            .await?
+            .to_string();
+        tracing::info!(signature = %signature, "swap transaction sent");
+        result.signature = Some(signature.clone());
+        let monitor =
+            self.monitor_swap_transaction(&signature, &self.solana, &result.quote, monitor_config);
+        let broadcast = config.priority.unwrap_or_default().preset().broadcast;
+        let monitor_result = match broadcast {
+            BroadcastStrategy::Single => monitor.await?,
+            BroadcastStrategy::Rebroadcast {
+                interval,
+                max_attempts,
+            } => {
+                let rebroadcast = async {
+                    for attempt in 1..max_attempts {
+                        tokio::time::sleep(interval).await;
+                        if let Err(e) = self.submit_swap_transaction(&transaction, config).await {
+                            tracing::debug!(attempt, error = %e, "rebroadcast failed");
+                        }
+                    }
+                    std::future::pending::<()>().await
+                };
+                tokio::select! {
+                    monitor_result = monitor => monitor_result?,
+                    _ = rebroadcast => unreachable!("rebroadcast never completes"),
+                }
+            }
+        };
+        result.monitor_result = Some(monitor_result);
+        Ok(result)
+    }
+
+    /// Gets the transaction recorded under an idempotency key, if any
+    pub async fn idempotency_record(
+        &self,
+        key: &str,
+    ) -> Result<Option<IdempotencyRecord>, JupiterError> {
+        let Some(value) = self
+            .idempotency_storage
+            .get(&format!("{}{}", IDEMPOTENCY_KEY_PREFIX, key))
+            .await?
+        else {
+            return Ok(None);
+        };
+        serde_json::from_slice(&value)
+            .map(Some)
+            .map_err(|e| JupiterError::ParseError(e.to_string()))
+    }
+
+    /// Releases an idempotency key, e.g. after verifying its transaction expired
+    /// without landing
+    pub async fn release_idempotency_key(&self, key: &str) -> Result<(), JupiterError> {
+        self.idempotency_storage
+            .delete(&format!("{}{}", IDEMPOTENCY_KEY_PREFIX, key))
+            .await
+    }
+
+    /// Records the transaction's signature under `key` before it is sent, failing if
+    /// the key was already used
+    ///
+    /// The record is kept even if sending fails, since the transaction may still
+    /// have reached the network.
+    async fn claim_idempotency_key(
+        &self,
+        key: &str,
+        transaction: &SwapTransaction,
+    ) -> Result<(), JupiterError> {
+        let signature = transaction
+            .signature()
+            .ok_or_else(|| JupiterError::SigningError("transaction is not signed".to_string()))?
+            .to_string();
+        let record = IdempotencyRecord {
+            signature,
+            submitted_at: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_millis() as u64)
+                .unwrap_or(0),
+        };
+        let value = serde_json::to_vec(&record).map_err(|e| JupiterError::Error(e.to_string()))?;
+        let claimed = self
+            .idempotency_storage
+            .put_if_absent(&format!("{}{}", IDEMPOTENCY_KEY_PREFIX, key), value)
+            .await?;
+        if claimed {
+            return Ok(());
+        }
+        let signature = self
+            .idempotency_record(key)
+            .await?
+            .map(|record| record.signature)
+            .unwrap_or_default();
+        Err(JupiterError::AlreadySubmitted {
+            key: key.to_string(),
+            signature,
+        })
+    }
+
+    /// Sends a signed swap transaction through the private relays of
+    /// `config.mev_protection`, or through the client's Solana RPC without it
+    async fn submit_swap_transaction(
+        &self,
+        transaction: &SwapTransaction,
+        config: &AdvancedSwapConfig,
+    ) -> Result<Signature, JupiterError> {
+        match &config.mev_protection {
+            Some(protection) => self.send_private_transaction(transaction, protection).await,
+            None => {
+                self.send_swap_transaction(transaction, &config.send_options)
+                    .await
+            }
+        }
+    }
+
+    /// Sends a signed swap transaction to every private relay, succeeding if any
+    /// accepts it
+    ///
+    /// Relays are called directly rather than through `send`, so they never receive
+    /// the Jupiter API key.
+    async fn send_private_transaction(
+        &self,
+        transaction: &SwapTransaction,
+        protection: &MevProtection,
+    ) -> Result<Signature, JupiterError> {
+        protection.validate()?;
+        let signature = *transaction
+            .signature()
+            .ok_or_else(|| JupiterError::SigningError("transaction is not signed".to_string()))?;
+        let encoded = transaction.serialize_base64()?;
+        let results = join_all(protection.relays.iter().map(|relay| {
+            self.metrics.observe("relay:send_transaction", async {
+                let response = self
+                    .client
+                    .post(relay.send_url())
+                    .json(&relay.send_body(&encoded))
+                    .send()
+                    .await
+                    .map_err(|e| JupiterError::NetworkError(e.to_string()))?;
+                let status = response.status();
+                let body: serde_json::Value = response
+                    .json()
+                    .await
+                    .map_err(|e| JupiterError::ParseError(e.to_string()))?;
+                if !status.is_success() || body.get("error").is_some() {
+                    return Err(JupiterError::TransactionFailed(format!(
+                        "relay {} rejected transaction: {}",
+                        relay.url(),
+                        body.get("error").unwrap_or(&body)
+                    )));
+                }
+                Ok(())
+            })
+        }))
+        .await;
+        let mut last_error = None;
+        for (relay, result) in protection.relays.iter().zip(results) {
+            match result {
+                Ok(()) => return Ok(signature),
+                Err(e) => {
+                    tracing::debug!(relay = %relay.url(), error = %e, "relay send failed");
+                    last_error = Some(e);
+                }
+            }
+        }
+        Err(last_error.unwrap_or_else(|| {
+            JupiterError::TransactionFailed("no relay accepted the transaction".to_string())
+        }))
+    }
+
+    /// Sends a signed swap transaction through the client's Solana RPC
+    async fn send_swap_transaction(
+        &self,
+        transaction: &SwapTransaction,
+        options: &SendOptions,
+    ) -> Result<Signature, JupiterError> {
+        let rpc_client = self
+            .solana
+            .client
+            .clone()
+            .ok_or(JupiterError::Error("solana client error".to_string()))?;
+        self.metrics
+            .observe("rpc:send_transaction", async {
+                let config = options.to_rpc_config();
+                match transaction {
+                    SwapTransaction::Legacy(transaction) => {
+                        rpc_client
+                            .send_transaction_with_config(transaction, config)
+                            .await
+                    }
+                    SwapTransaction::Versioned(transaction) => {
+                        rpc_client
+                            .send_transaction_with_config(transaction, config)
+                            .await
+                    }
+                }
+                .map_err(|e| JupiterError::TransactionFailed(e.to_string()))
+            })
+            .await
+    }
+
+    /// Checks whether `owner`'s associated token account for `mint` exists, resolving
+    /// the mint's token program, and returns an idempotent creation instruction paid
+    /// by the owner if it is missing
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::JupiterClient;
+    /// use solana_sdk::pubkey::Pubkey;
+    ///
+    /// async fn example(client: JupiterClient, owner: Pubkey, mint: Pubkey) -> Result<(), Box<dyn std::error::Error>> {
+    /// let status = client.ensure_ata(&owner, &mint).await?;
+    /// if let Some(instruction) = status.create_instruction {
+    ///     // prepend `instruction` to the transaction, e.g. via TransactionComposer
+    /// }
+    /// Ok(())
+    /// }
+    /// ```
+    pub async fn ensure_ata(
+        &self,
+        owner: &Pubkey,
+        mint: &Pubkey,
+    ) -> Result<AtaStatus, JupiterError> {
+        let rpc_client = self
+            .solana
+            .client
+            .clone()
+            .ok_or(JupiterError::Error("solana client error".to_string()))?;
+        let mint_account = rpc_client
+            .get_multiple_accounts_with_commitment(&[*mint], self.config.commitment)
+            .await
+            .map_err(|e| JupiterError::NetworkError(e.to_string()))?
+            .value
+            .into_iter()
+            .next()
+            .flatten()
+            .ok_or_else(|| JupiterError::InvalidInput(format!("mint {} not found", mint)))?;
+        let token_program = mint_account.owner;
+        let address = associated_token_address(owner, mint, &token_program);
+        let exists = rpc_client
+            .get_multiple_accounts_with_commitment(&[address], self.config.commitment)
+            .await
+            .map_err(|e| JupiterError::NetworkError(e.to_string()))?
+            .value
+            .into_iter()
+            .next()
+            .flatten()
+            .is_some();
+        Ok(AtaStatus {
+            owner: *owner,
+            mint: *mint,
+            address,
+            token_program,
+            exists,
+            create_instruction: (!exists).then(|| {
+                create_associated_token_account_idempotent(owner, owner, mint, &token_program)
+            }),
+        })
+    }
+
+    /// Creates `owner`'s associated token account for `mint` if it is missing, paid by
+    /// `payer`, and waits for confirmation
+    ///
+    /// # Returns
+    /// The creation transaction signature, or `None` if the account already existed
+    pub async fn create_ata_if_missing(
+        &self,
+        payer: &dyn Signer,
+        owner: &Pubkey,
+        mint: &Pubkey,
+    ) -> Result<Option<Signature>, JupiterError> {
+        let status = self.ensure_ata(owner, mint).await?;
+        if status.exists {
+            return Ok(None);
+        }
+        self.create_ata(payer, &status).await.map(Some)
+    }
+
+    /// Creates the associated token account of `status`, paid by `payer`, and waits
+    /// for confirmation
+    async fn create_ata(
+        &self,
+        payer: &dyn Signer,
+        status: &AtaStatus,
+    ) -> Result<Signature, JupiterError> {
+        let rpc_client = self
+            .solana
+            .client
+            .clone()
+            .ok_or(JupiterError::Error("solana client error".to_string()))?;
+        let instruction = create_associated_token_account_idempotent(
+            &payer.pubkey(),
+            &status.owner,
+            &status.mint,
+            &status.token_program,
+        );
+        let blockhash = rpc_client
+            .get_latest_blockhash()
+            .await
+            .map_err(|e| JupiterError::NetworkError(e.to_string()))?;
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&payer.pubkey()),
+            &[payer],
+            blockhash,
+        );
+        let signature = rpc_client
+            .send_and_confirm_transaction(&transaction)
+            .await
+            .map_err(|e| JupiterError::TransactionFailed(e.to_string()))?;
+        tracing::info!(address = %status.address, signature = %signature, "associated token account created");
+        Ok(signature)
+    }
+
+    /// Gets the Token-2022 transfer fee in effect for a mint at the current epoch
+    ///
+    /// # Returns
+    /// `None` for SPL Token mints and Token-2022 mints without the transfer-fee extension
+    pub async fn get_transfer_fee(
+        &self,
+        mint: &Pubkey,
+    ) -> Result<Option<TransferFee>, JupiterError> {
+        let rpc_client = self
+            .solana
+            .client
+            .clone()
+            .ok_or(JupiterError::Error("solana client error".to_string()))?;
+        let account = rpc_client
+            .get_multiple_accounts_with_commitment(&[*mint], self.config.commitment)
+            .await
+            .map_err(|e| JupiterError::NetworkError(e.to_string()))?
+            .value
+            .into_iter()
+            .next()
+            .flatten()
+            .ok_or_else(|| JupiterError::InvalidInput(format!("mint {} not found", mint)))?;
+        let Some(config) = TransferFeeConfig::from_mint_data(&account.data) else {
+            return Ok(None);
+        };
+        let epoch = rpc_client
+            .get_epoch_info()
+            .await
+            .map_err(|e| JupiterError::NetworkError(e.to_string()))?
+            .epoch;
+        Ok(Some(config.fee_for_epoch(epoch)))
+    }
+
+    /// Gets the SOL balance of `wallet` in lamports at the client's commitment
+    pub async fn get_balance(&self, wallet: &Pubkey) -> Result<u64, JupiterError> {
+        self.solana
+            .client
+            .clone()
+            .ok_or(JupiterError::Error("solana client error".to_string()))?
+            .get_balance_with_commitment(wallet, self.config.commitment)
+            .await
+            .map(|balance| balance.value)
+            .map_err(|e| JupiterError::NetworkError(e.to_string()))
+    }
+
+    /// Pre-trade checks - Verifies that `wallet` holds enough input tokens, plus SOL for
+    /// fees, rent of token accounts the swap creates and wrapping when selling SOL
+    ///
+    /// Deficiencies are reported as a typed list instead of letting the transaction
+    /// fail on-chain.
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::{JupiterClient, types::QuoteResponse};
+    /// use solana_sdk::pubkey::Pubkey;
+    ///
+    /// async fn example(client: JupiterClient, quote: QuoteResponse, wallet: Pubkey) -> Result<(), Box<dyn std::error::Error>> {
+    /// let report = client.preflight_checks(&quote, &wallet).await?;
+    /// for deficiency in &report.deficiencies {
+    ///     println!("{}", deficiency);
+    /// }
+    /// Ok(())
+    /// }
+    /// ```
+    pub async fn preflight_checks(
+        &self,
+        quote: &QuoteResponse,
+        wallet: &Pubkey,
+    ) -> Result<PreflightReport, JupiterError> {
+        let input_mint = validate_pubkey(&quote.input_mint).map_err(JupiterError::InvalidInput)?;
+        let output_mint =
+            validate_pubkey(&quote.output_mint).map_err(JupiterError::InvalidInput)?;
+        let in_amount: u64 = quote.in_amount.parse().map_err(|_| {
+            JupiterError::ParseError(format!("invalid in_amount {}", quote.in_amount))
+        })?;
+        let rpc_client = self
+            .solana
+            .client
+            .clone()
+            .ok_or(JupiterError::Error("solana client error".to_string()))?;
+        let sol_balance = self.get_balance(wallet).await?;
+        let mut deficiencies = Vec::new();
+        let mut sol_required = SolRequirement {
+            fees: self.estimate_transaction_fee(quote, None).await?.expected,
+            ..SolRequirement::default()
+        };
+
+        let (input_ata, output_ata) = tokio::try_join!(
+            self.ensure_ata(wallet, &input_mint),
+            self.ensure_ata(wallet, &output_mint)
+        )?;
+        let input_balance = if quote.input_mint == WSOL_MINT {
+            // SOL is wrapped into a token account the swap creates if needed
+            sol_required.wrap = in_amount;
+            if !input_ata.exists {
+                sol_required.rent += TOKEN_ACCOUNT_RENT_LAMPORTS;
+            }
+            sol_balance
+        } else if !input_ata.exists {
+            deficiencies.push(Deficiency::MissingInputTokenAccount { mint: input_mint });
+            0
+        } else {
+            let balance = rpc_client
+                .get_token_account_balance_with_commitment(
+                    &input_ata.address,
+                    self.config.commitment,
+                )
+                .await
+                .map_err(|e| JupiterError::NetworkError(e.to_string()))?
+                .value;
+            let available: u64 = balance.amount.parse().unwrap_or(0);
+            if available < in_amount {
+                deficiencies.push(Deficiency::InsufficientInputToken {
+                    mint: input_mint,
+                    required: in_amount,
+                    available,
+                });
+            }
+            available
+        };
+        if !output_ata.exists {
+            sol_required.rent += TOKEN_ACCOUNT_RENT_LAMPORTS;
+        }
+        if sol_balance < sol_required.total() {
+            deficiencies.push(Deficiency::InsufficientSol {
+                required: sol_required.total(),
+                available: sol_balance,
+            });
+        }
+        let (input_fee, output_fee) = tokio::try_join!(
+            self.get_transfer_fee(&input_mint),
+            self.get_transfer_fee(&output_mint)
+        )?;
+        Ok(PreflightReport {
+            wallet: *wallet,
+            sol_balance,
+            input_balance,
+            sol_required,
+            input_transfer_fee: input_fee.map_or(0, |fee| fee.calculate_fee(in_amount)),
+            expected_output: quote.net_out_amount(input_fee.as_ref(), output_fee.as_ref()),
+            deficiencies,
+        })
+    }
+
+    /// Gets quotes for several requests concurrently with default `BatchQuoteOptions`
+    ///
+    /// Results are returned in the order of `requests`.
+    pub async fn get_quotes_batch(
+        &self,
+        requests: &[QuoteRequest],
+    ) -> Result<Vec<Result<QuoteResponse, JupiterError>>, JupiterError> {
+        self.get_quotes_batch_with_options(
+            requests,
+            &BatchQuoteOptions::default(),
+            &CancellationToken::new(),
+        )
+        .await
+    }
+
+    /// Gets quotes for a `BatchQuoteRequest`, reporting failures per request
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::{JupiterClient, types::BatchQuoteRequest};
+    ///
+    /// # async fn example(client: JupiterClient, batch: BatchQuoteRequest) -> Result<(), Box<dyn std::error::Error>> {
+    /// let response = client.get_quotes(&batch).await?;
+    /// for error in &response.errors {
+    ///     println!("request {} failed: {}", error.index, error.message);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_quotes(
+        &self,
+        batch: &BatchQuoteRequest,
+    ) -> Result<BatchQuoteResponse, JupiterError> {
+        let results = self.get_quotes_batch(&batch.requests).await?;
+        Ok(BatchQuoteResponse::from_results(&batch.requests, results))
+    }
+
+    /// Gets quotes for several requests, stopping early when `token` is cancelled
+    /// Requests not yet completed when the token fires report JupiterError::Cancelled.
+    pub async fn get_quotes_batch_cancellable(
+        &self,
+        requests: &[QuoteRequest],
+        token: &CancellationToken,
+    ) -> Result<Vec<Result<QuoteResponse, JupiterError>>, JupiterError> {
+        self.get_quotes_batch_with_options(requests, &BatchQuoteOptions::default(), token)
+            .await
+    }
+
+    /// Gets quotes for several requests with bounded parallelism
+    ///
+    /// Up to `options.concurrency` quotes are in flight at once, each still subject
+    /// to the rate limiter. Results are returned in the order of `requests`. With
+    /// `options.fail_fast` the remaining requests are cancelled on the first error,
+    /// which is returned instead.
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::{JupiterClient, types::BatchQuoteOptions};
+    /// use tokio_util::sync::CancellationToken;
+    ///
+    /// # async fn example(client: JupiterClient, requests: Vec<jup_sdk::types::QuoteRequest>) -> Result<(), Box<dyn std::error::Error>> {
+    /// let options = BatchQuoteOptions { concurrency: 16, fail_fast: false };
+    /// let results = client
+    ///     .get_quotes_batch_with_options(&requests, &options, &CancellationToken::new())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        skip_all,
+        fields(requests = requests.len(), concurrency = options.concurrency)
+    )]
+    pub async fn get_quotes_batch_with_options(
+        &self,
+        requests: &[QuoteRequest],
+        options: &BatchQuoteOptions,
+        token: &CancellationToken,
+    ) -> Result<Vec<Result<QuoteResponse, JupiterError>>, JupiterError> {
+        let batch_token = token.child_token();
+        let mut quotes = stream::iter(requests.iter().enumerate())
+            .map(|(index, request)| {
+                let batch_token = &batch_token;
+                async move {
+                    let result = run_cancellable(Some(batch_token), self.get_quote(request))
+                        .await
+                        .and_then(|result| result);
+                    (index, result)
+                }
+            })
+            .buffer_unordered(options.concurrency.max(1));
+        let mut results: Vec<Option<Result<QuoteResponse, JupiterError>>> =
+            requests.iter().map(|_| None).collect();
+        while let Some((index, result)) = quotes.next().await {
+            if options.fail_fast
+                && let Err(e) = &result
+            {
+                batch_token.cancel();
+                return Err(e.clone());
+            }
+            results[index] = Some(result);
+        }
+        Ok(results.into_iter().flatten().collect())
+    }
+
+    /// Quotes one input against many outputs concurrently and ranks the outputs by
+    /// USD value, e.g. to pick what to rotate a position into
+    ///
+    /// # Params
+    /// input_mint - Mint to sell
+    /// amount - Input amount (raw units)
+    /// output_mints - Candidate output mints
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::JupiterClient;
+    ///
+    /// # async fn example(client: JupiterClient) -> Result<(), Box<dyn std::error::Error>> {
+    /// let outputs = vec![
+    ///     "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v".to_string(),
+    ///     "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB".to_string(),
+    /// ];
+    /// let table = client
+    ///     .quote_many_outputs("So11111111111111111111111111111111111111112", 1_000_000_000, &outputs)
+    ///     .await?;
+    /// for row in &table.rows {
+    ///     println!("{}: {:?} USD", row.output_mint, row.output_usd);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(skip_all, fields(input_mint = %input_mint, outputs = output_mints.len()))]
+    pub async fn quote_many_outputs(
+        &self,
+        input_mint: &str,
+        amount: u64,
+        output_mints: &[String],
+    ) -> Result<OutputQuoteTable, JupiterError> {
+        let requests: Vec<QuoteRequest> = output_mints
+            .iter()
+            .map(|output_mint| QuoteRequest {
+                input_mint: input_mint.to_string(),
+                output_mint: output_mint.clone(),
+                amount,
+                slippage_bps: DEFAULT_SLIPPAGE_BPS,
+                fee_bps: None,
+                only_direct_routes: None,
+                as_legacy_transaction: None,
+                restrict_middle_tokens: None,
+            })
+            .collect();
+        let mut ids = output_mints.to_vec();
+        ids.push(input_mint.to_string());
+        ids.sort();
+        ids.dedup();
+        let (quotes, prices, decimals) = tokio::join!(
+            self.get_quotes_batch_with_options(
+                &requests,
+                &BatchQuoteOptions::default(),
+                &CancellationToken::new()
+            ),
+            self.get_price(&ids),
+            join_all(ids.iter().map(|mint| self.mint_decimals(mint)))
+        );
+        // Without prices the outputs are still quoted, just not ranked
+        let prices = prices
+            .inspect_err(|e| tracing::warn!(error = %e, "prices unavailable for ranking"))
+            .unwrap_or_default();
+        let value = |mint: &str, amount: u64| {
+            let index = ids.binary_search_by(|id| id.as_str().cmp(mint)).ok()?;
+            let decimals = decimals[index].as_ref().ok()?;
+            Some(usd_value(amount, *decimals, prices.get(mint)?.price))
+        };
+        let rows = output_mints
+            .iter()
+            .zip(quotes?)
+            .map(|(output_mint, quote)| {
+                let out_amount = quote
+                    .as_ref()
+                    .ok()
+                    .and_then(|quote| quote.out_amount.parse().ok())
+                    .unwrap_or(0);
+                OutputQuote {
+                    output_mint: output_mint.clone(),
+                    output_usd: quote
+                        .is_ok()
+                        .then(|| value(output_mint, out_amount))
+                        .flatten(),
+                    quote,
+                    out_amount,
+                }
+            })
+            .collect();
+        Ok(OutputQuoteTable::new(
+            input_mint,
+            amount,
+            value(input_mint, amount),
+            rows,
+        ))
+    }
+
+    /// Finds the candidate token that gives `usd_target` of exposure at the lowest
+    /// cost, sizing the input from the price API
+    ///
+    /// # Params
+    /// input_mint - Mint to sell
+    /// usd_target - USD value to convert
+    /// candidates - Candidate output mints or registry symbols
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::JupiterClient;
+    ///
+    /// # async fn example(client: JupiterClient) -> Result<(), Box<dyn std::error::Error>> {
+    /// let candidates = vec!["USDC".to_string(), "USDT".to_string()];
+    /// let plan = client
+    ///     .find_best_conversion("So11111111111111111111111111111111111111112", 500.0, &candidates)
+    ///     .await?;
+    /// println!("{} costs {:.1} bps", plan.best.output_mint, plan.cost_bps);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(skip_all, fields(input_mint = %input_mint, usd_target = usd_target))]
+    pub async fn find_best_conversion(
+        &self,
+        input_mint: &str,
+        usd_target: f64,
+        candidates: &[String],
+    ) -> Result<ConversionPlan, JupiterError> {
+        if !(usd_target.is_finite() && usd_target > 0.0) {
+            return Err(JupiterError::InvalidInput(format!(
+                "invalid USD target {}",
+                usd_target
+            )));
+        }
+        let tokens = self.get_token_map().await?;
+        let outputs = candidates
+            .iter()
+            .map(|candidate| {
+                if tokens.get(candidate).is_some() || validate_pubkey(candidate).is_ok() {
+                    return Ok(candidate.clone());
+                }
+                tokens
+                    .first_by_symbol(candidate)
+                    .map(|token| token.address.clone())
+                    .ok_or_else(|| {
+                        JupiterError::InvalidInput(format!("unknown token {}", candidate))
+                    })
+            })
+            .collect::<Result<Vec<String>, JupiterError>>()?;
+        let ids = [input_mint.to_string()];
+        let (prices, decimals) =
+            tokio::try_join!(self.get_price(&ids), self.mint_decimals(input_mint))?;
+        let price = prices
+            .get(input_mint)
+            .map(|price| price.price)
+            .filter(|price| *price > 0.0)
+            .ok_or_else(|| JupiterError::Error(format!("no price for {}", input_mint)))?;
+        let amount = (usd_target / price * 10f64.powi(decimals as i32)).round() as u64;
+        if amount == 0 {
+            return Err(JupiterError::InvalidInput(format!(
+                "USD target {} is below one unit of {}",
+                usd_target, input_mint
+            )));
+        }
+        let table = self
+            .quote_many_outputs(input_mint, amount, &outputs)
+            .await?;
+        ConversionPlan::from_table(table, usd_target)
+    }
+
+    /// Converts a raw amount between tokens at price API rates, e.g. to show a
+    /// balance in SOL (`WSOL_MINT`) or another quote token
+    ///
+    /// Decimals come from the token registry or the mint account; prices go through
+    /// the client's price cache, whose TTL bounds their staleness. The result also
+    /// carries the USD value of the amount.
+    ///
+    /// # Params
+    /// amount - Raw amount of `from_mint`
+    /// from_mint - Mint to convert from
+    /// to_mint - Mint to convert to
+    /// rounding - Rounding to whole raw units of `to_mint`
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::{JupiterClient, conversion::Rounding};
+    ///
+    /// # async fn example(client: JupiterClient) -> Result<(), Box<dyn std::error::Error>> {
+    /// let conversion = client
+    ///     .convert_amount(
+    ///         1_500_000_000,
+    ///         "So11111111111111111111111111111111111111112",
+    ///         "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
+    ///         Rounding::Down,
+    ///     )
+    ///     .await?;
+    /// println!("{} USDC (${:.2})", conversion.to, conversion.usd_value);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(skip_all, fields(from_mint = %from_mint, to_mint = %to_mint, amount = amount))]
+    pub async fn convert_amount(
+        &self,
+        amount: u64,
+        from_mint: &str,
+        to_mint: &str,
+        rounding: Rounding,
+    ) -> Result<AmountConversion, JupiterError> {
+        let mut ids = vec![from_mint.to_string(), to_mint.to_string()];
+        ids.dedup();
+        let (prices, from_decimals, to_decimals) = tokio::try_join!(
+            self.get_price(&ids),
+            self.mint_decimals(from_mint),
+            self.mint_decimals(to_mint)
+        )?;
+        let price = |mint: &str| {
+            prices
+                .get(mint)
+                .map(|price| price.price)
+                .filter(|price| *price > 0.0)
+                .ok_or_else(|| JupiterError::Error(format!("no price for {}", mint)))
+        };
+        let (from_price, to_price) = (price(from_mint)?, price(to_mint)?);
+        let converted = convert_raw(
+            amount,
+            from_decimals,
+            from_price,
+            to_decimals,
+            to_price,
+            rounding,
+        )
+        .ok_or_else(|| {
+            JupiterError::InvalidInput(format!(
+                "{} of {} overflows raw units of {}",
+                amount, from_mint, to_mint
+            ))
+        })?;
+        Ok(AmountConversion {
+            from_mint: from_mint.to_string(),
+            to_mint: to_mint.to_string(),
+            from: TokenAmount::new(amount, from_decimals),
+            to: TokenAmount::new(converted, to_decimals),
+            usd_value: usd_value(amount, from_decimals, from_price),
+            rate: from_price / to_price,
+            rounding,
+            priced_at: SystemTime::now(),
+            max_staleness: self.config.cache_ttl.prices,
+        })
+    }
+
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            input_mint = %request.input_mint,
+            output_mint = %request.output_mint,
+            amount = request.amount,
+            max_retries,
+        )
+    )]
+    pub async fn get_quote_with_retry(
+        &self,
+        request: &QuoteRequest,
+        max_retries: u32,
+    ) -> Result<QuoteResponse, JupiterError> {
+        let strategy = ExponentialBackoff {
+            max_retries,
+            initial_delay: Duration::from_millis(200),
+            ..ExponentialBackoff::from(RetryConfig::default())
+        };
+        self.run_with_retry(&strategy, || self.get_quote(request))
+            .await
+    }
+
+    /// Get Route Map - Used to understand all available transaction paths
+    /// Gets all token pairs and routing information supported by Jupiter
+    #[tracing::instrument(skip_all)]
+    pub async fn get_indexed_route_map(&self) -> Result<IndexedRouteMapResponse, JupiterError> {
+        self.cached("route_map", self.config.cache_ttl.route_map, || async {
+            let url = format!("{}/indexed-route-map", self.base_url);
+            self.send_conditional("route_map", self.client.get(&url), &self.route_map_cache)
+                .await
+        })
+        .await
+    }
+
+    /// Get Route Graph - Decoded route map for offline connectivity queries
+    pub async fn get_route_graph(&self) -> Result<RouteGraph, JupiterError> {
+        let route_map = self.get_indexed_route_map().await?;
+        Ok(RouteGraph::from_route_map(&route_map))
+    }
+
+    /// Get a list of program IDs - used to verify the programs involved in a transaction
+    /// Gets all programs that may be invoked by a Jupiter swap, labeled with their venue
+    /// and category
+    #[tracing::instrument(skip_all)]
+    pub async fn get_program_ids(&self) -> Result<ProgramIdList, JupiterError> {
+        self.cached("program_ids", self.config.cache_ttl.program_ids, || async {
+            let url = format!("{}/program-id-to-label", self.base_url);
+            let response = self.send("program_ids", self.client.get(&url)).await?;
+            if !response.status().is_success() {
+                return Err(Self::response_error(response).await);
+            }
+            let labels: HashMap<String, String> = response
+                .json()
+                .await
+                .map_err(|e| JupiterError::ParseError(e.to_string()))?;
+            Ok(ProgramIdList::from_labels(labels))
+        })
+        .await
+    }
+
+    /// Whether a program is the Jupiter aggregator, a known venue or a core program
+    pub async fn is_known_program(&self, program_id: &Pubkey) -> Result<bool, JupiterError> {
+        Ok(self.get_program_ids().await?.is_known_program(program_id))
+    }
+
+    /// Verifies a swap transaction returned by the API before signing it
+    ///
+    /// Checks that the user is the fee payer and only signer, that every instruction
+    /// invokes a known program, and that no top-level transfer sends funds outside the
+    /// user's own accounts. Fails with `JupiterError::ValidationError` otherwise.
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::{JupiterClient, types::SwapResponse};
+    /// use solana_sdk::pubkey::Pubkey;
+    ///
+    /// async fn example(client: JupiterClient, swap: SwapResponse, user: Pubkey, input: Pubkey, output: Pubkey) -> Result<(), Box<dyn std::error::Error>> {
+    /// client.verify_swap_transaction(&swap, &user, &[input, output]).await?;
+    /// Ok(())
+    /// }
+    /// ```
+    pub async fn verify_swap_transaction(
         &self,
-        requests: &[QuoteRequest],
-    ) -> Result<Vec<Result<QuoteResponse, JupiterError>>, JupiterError> {
-        let mut results = Vec::new();
-        for request in requests {
-            let result = self.get_quote(request).await;
-            results.push(result);
-        }
-        Ok(results)
+        swap: &SwapResponse,
+        expected_user: &Pubkey,
+        expected_mints: &[Pubkey],
+    ) -> Result<(), JupiterError> {
+        let programs = self.get_program_ids().await?;
+        verify_swap_transaction(swap, expected_user, expected_mints, &programs)
     }
 
-    pub async fn get_quote_with_retry(
+    /// Gets the address lookup tables referenced by a swap transaction, fetching
+    /// tables not yet cached from the Solana RPC
+    pub async fn get_lookup_tables(
         &self,
-        request: &QuoteRequest,
-        max_retries: u32,
-    ) -> Result<QuoteResponse, JupiterError> {
-        for attempt in 0..=max_retries {
-            match self.get_quote(request).await {
-                Ok(quote) => return Ok(quote),
-                Err(e) if attempt == max_retries => return Err(e),
-                Err(e) if e.is_retriable() => {
-                    let delay_ms = 200 * (attempt + 1) as u64;
-                    tokio::time::sleep(tokio::time::Duration::from_millis(delay_ms)).await;
-                    continue;
-                }
-                Err(e) => return Err(e),
-            }
-        }
-        unreachable!()
+        swap: &SwapResponse,
+    ) -> Result<Vec<LookupTable>, JupiterError> {
+        let transaction = decode_swap_transaction(&swap.swap_transaction)?;
+        let keys: Vec<Pubkey> = transaction
+            .message
+            .address_table_lookups()
+            .map(|lookups| lookups.iter().map(|lookup| lookup.account_key).collect())
+            .unwrap_or_default();
+        self.lookup_tables
+            .load(&self.solana, self.config.commitment, &keys)
+            .await
     }
 
-    /// Get Route Map - Used to understand all available transaction paths
-    /// Gets all token pairs and routing information supported by Jupiter
-    pub async fn get_indexed_route_map(
+    /// Resolves every account key of a swap transaction, including addresses loaded
+    /// from lookup tables, in message order
+    pub async fn resolve_transaction_accounts(
         &self,
-    ) -> Result<crate::types::IndexedRouteMapResponse, JupiterError> {
-        let url = format!("{}/indexed-route-map", self.base_url);
-        let response = self
-            .client
-            .get(&url)
-            .send()
-            .await
-            .map_err(|e| JupiterError::NetworkError(e.to_string()))?;
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .map_err(|e| JupiterError::NetworkError(e.to_string()))?;
-            return Err(JupiterError::RequestFailed(format!(
-                "HTTP {}: {}",
-                status, error_text
-            )));
-        }
-        let route_map: crate::types::IndexedRouteMapResponse = response
-            .json()
+        swap: &SwapResponse,
+    ) -> Result<Vec<Pubkey>, JupiterError> {
+        let transaction = decode_swap_transaction(&swap.swap_transaction)?;
+        self.lookup_tables
+            .resolve(&self.solana, self.config.commitment, &transaction.message)
             .await
-            .map_err(|e| JupiterError::ParseError(e.to_string()))?;
-        Ok(route_map)
     }
 
-    /// Get a list of program IDs - used to verify the programs involved in a transaction
-    /// Get all Solana program IDs involved in a Jupiter exchange
-    pub async fn get_program_ids(&self) -> Result<Vec<String>, JupiterError> {
-        let url = format!("{}/program-ids", self.base_url);
-        let response = self
-            .client
-            .get(&url)
-            .send()
-            .await
-            .map_err(|e| JupiterError::NetworkError(e.to_string()))?;
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .map_err(|e| JupiterError::NetworkError(e.to_string()))?;
-            return Err(JupiterError::RequestFailed(format!(
-                "HTTP {}: {}",
-                status, error_text
-            )));
+    /// Gets the address lookup table cache, e.g. to invalidate a table after extending it
+    pub fn lookup_tables(&self) -> &LookupTableCache {
+        &self.lookup_tables
+    }
+
+    /// Decodes a swap transaction into a human-readable preview of its instructions,
+    /// accounts, compute budget and approximate balance changes
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::{JupiterClient, types::{QuoteResponse, SwapResponse}};
+    ///
+    /// async fn example(client: JupiterClient, swap: SwapResponse, quote: QuoteResponse) -> Result<(), Box<dyn std::error::Error>> {
+    /// let preview = client.preview_swap_transaction(&swap, Some(&quote)).await?;
+    /// println!("{}", preview);
+    /// Ok(())
+    /// }
+    /// ```
+    pub async fn preview_swap_transaction(
+        &self,
+        swap: &SwapResponse,
+        quote: Option<&QuoteResponse>,
+    ) -> Result<TransactionPreview, JupiterError> {
+        // Program names are cosmetic, so a failed lookup still yields a preview
+        let programs = self.get_program_ids().await.ok();
+        let mut preview = TransactionPreview::decode(&swap.swap_transaction, programs.as_ref())?;
+        if let Ok(account_keys) = self.resolve_transaction_accounts(swap).await {
+            preview = preview.with_resolved_accounts(&account_keys);
         }
-        let program_ids: Vec<String> = response
-            .json()
-            .await
-            .map_err(|e| JupiterError::ParseError(e.to_string()))?;
-        Ok(program_ids)
+        Ok(match quote {
+            Some(quote) => preview.with_quote(quote),
+            None => preview,
+        })
     }
 
+    #[tracing::instrument(skip_all)]
     pub async fn health(&self) -> Result<bool, JupiterError> {
         let url = format!("{}/health", self.base_url);
-        let response = self
-            .client
-            .get(&url)
-            .send()
-            .await
-            .map_err(|e| JupiterError::NetworkError(e.to_string()))?;
+        let response = self.send("health", self.client.get(&url)).await?;
         Ok(response.status().is_success())
     }
 
+    /// Readiness check - Checks the quote, price and token APIs and the Solana RPC
+    /// concurrently and reports per-component status with latencies
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::JupiterClient;
+    ///
+    /// async fn ready(client: &JupiterClient) -> bool {
+    ///     let report = client.health_report().await;
+    ///     for component in report.unhealthy() {
+    ///         eprintln!("{} unhealthy: {:?}", component.component, component.error);
+    ///     }
+    ///     report.is_healthy()
+    /// }
+    /// ```
+    #[tracing::instrument(skip_all)]
+    pub async fn health_report(&self) -> HealthReport {
+        let quote_request = QuoteRequest {
+            input_mint: HEALTH_CHECK_INPUT_MINT.to_string(),
+            output_mint: HEALTH_CHECK_OUTPUT_MINT.to_string(),
+            amount: HEALTH_CHECK_AMOUNT,
+            slippage_bps: DEFAULT_SLIPPAGE_BPS,
+            fee_bps: None,
+            only_direct_routes: None,
+            as_legacy_transaction: None,
+            restrict_middle_tokens: None,
+        };
+        let quote = ComponentHealth::check(
+            HealthComponent::QuoteApi,
+            // Probes the API only, so the token policy doesn't apply
+            self.send_quote(&quote_request, None),
+        );
+        let price = ComponentHealth::check(
+            HealthComponent::PriceApi,
+            self.send_price(HEALTH_CHECK_INPUT_MINT.to_string()),
+        );
+        let tokens = ComponentHealth::check(HealthComponent::TokenApi, async {
+            // HEAD avoids downloading the full token list
+            let url = format!("{}/tokens", self.base_url);
+            let response = self.send("tokens", self.client.head(&url)).await?;
+            if !response.status().is_success() {
+                return Err(Self::response_error(response).await);
+            }
+            Ok(())
+        });
+        let rpc = ComponentHealth::check(HealthComponent::SolanaRpc, async {
+            self.solana
+                .client
+                .clone()
+                .ok_or(JupiterError::Error("solana client error".to_string()))?
+                .get_health()
+                .await
+                .map_err(|e| JupiterError::NetworkError(e.to_string()))
+        });
+        let (quote, price, tokens, rpc) = tokio::join!(quote, price, tokens, rpc);
+        HealthReport {
+            components: vec![quote, price, tokens, rpc],
+        }
+    }
+
+    /// Connection warm-up - Pre-resolves DNS and opens connections to the Jupiter API
+    /// and the Solana RPC, so the first real request doesn't pay connect and TLS latency.
+    #[tracing::instrument(skip_all)]
+    pub async fn warm_up(&self) -> Result<(), JupiterError> {
+        let jupiter = async {
+            // Any HTTP response means the pooled connection is established
+            self.client
+                .head(&self.base_url)
+                .send()
+                .await
+                .map(|_| ())
+                .map_err(|e| JupiterError::NetworkError(e.to_string()))
+        };
+        let rpc = async {
+            self.solana
+                .client
+                .clone()
+                .ok_or(JupiterError::Error("solana client error".to_string()))?
+                .get_slot()
+                .await
+                .map(|_| ())
+                .map_err(|e| JupiterError::NetworkError(e.to_string()))
+        };
+        let (jupiter, rpc) = tokio::join!(jupiter, rpc);
+        jupiter.and(rpc)
+    }
+
+    /// Starts periodic keep-alive pings to the Jupiter API so pooled connections stay
+    /// open while the client is idle
+    ///
+    /// The pings stop when the returned handle is shut down or dropped.
+    pub fn start_keep_alive(&self, interval: Duration) -> BackgroundTask {
+        let client = self.client.clone();
+        let base_url = self.base_url.clone();
+        BackgroundTask::spawn(move |token| async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                tokio::select! {
+                    _ = token.cancelled() => break,
+                    _ = ticker.tick() => {
+                        let _ = client.head(&base_url).send().await;
+                    }
+                }
+            }
+        })
+    }
+
+    /// Starts the background refresh jobs enabled in `config` on a `Scheduler`
+    /// recording into the client's metrics
+    ///
+    /// The jobs stop when the returned scheduler is shut down or dropped.
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::{JupiterClient, scheduler::RefresherConfig};
+    /// use std::time::Duration;
+    ///
+    /// async fn run(client: JupiterClient) {
+    ///     let config = RefresherConfig::default()
+    ///         .with_token_map(Duration::from_secs(3600))
+    ///         .with_latency_probe(Duration::from_secs(30));
+    ///     let refreshers = client.start_refreshers(config);
+    ///     // ...
+    ///     refreshers.shutdown().await;
+    /// }
+    /// ```
+    pub fn start_refreshers(&self, config: RefresherConfig) -> Scheduler {
+        let mut scheduler = Scheduler::new(self.metrics.clone());
+        if let Some(interval) = config.token_map {
+            let client = self.clone();
+            scheduler.schedule(JobConfig::new("token_map", interval), move || {
+                let client = client.clone();
+                Box::pin(async move {
+                    // Swap the map only once the new list is in, so lookups keep
+                    // working while a refresh fails
+                    let map = Arc::new(TokenMap::from_tokens(client.get_tokens().await?));
+                    client.token_map.set(map);
+                    Ok(())
+                })
+            });
+        }
+        if let Some(interval) = config.route_map {
+            let client = self.clone();
+            scheduler.schedule(JobConfig::new("route_map", interval), move || {
+                let client = client.clone();
+                Box::pin(async move { client.get_indexed_route_map().await.map(|_| ()) })
+            });
+        }
+        if let Some(interval) = config.prices
+            && !config.price_mints.is_empty()
+        {
+            let client = self.clone();
+            let mints = config.price_mints;
+            scheduler.schedule(JobConfig::new("prices", interval), move || {
+                let client = client.clone();
+                let mints = mints.clone();
+                Box::pin(async move { client.get_price(&mints).await.map(|_| ()) })
+            });
+        }
+        if let Some(interval) = config.latency_probe {
+            let client = self.clone();
+            scheduler.schedule(JobConfig::new("latency_probe", interval), move || {
+                let client = client.clone();
+                Box::pin(async move { client.health().await.map(|_| ()) })
+            });
+        }
+        scheduler
+    }
+
     /// Batch Price Retrieval - Retrieve prices of multiple tokens at once
     /// Efficiently retrieve price information for multiple tokens, reducing the number of API calls.
+    #[tracing::instrument(skip_all, fields(pairs = token_pairs.len()))]
     pub async fn get_prices_batch(
         &self,
         token_pairs: &[(&str, &str)], // (mint_address, vs_token)
@@ -584,22 +3367,10 @@ impl JupiterClient {
         params.insert("ids", ids.join(","));
         let url = format!("{}/price", self.base_url);
         let response = self
-            .client
-            .get(&url)
-            .query(&params)
-            .send()
-            .await
-            .map_err(|e| JupiterError::NetworkError(e.to_string()))?;
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .map_err(|e| JupiterError::NetworkError(e.to_string()))?;
-            return Err(JupiterError::RequestFailed(format!(
-                "HTTP {}: {}",
-                status, error_text
-            )));
+            .send("price", self.client.get(&url).query(&params))
+            .await?;
+        if !response.status().is_success() {
+            return Err(Self::response_error(response).await);
         }
         let prices: HashMap<String, crate::types::PriceResponse> = response
             .json()
@@ -614,6 +3385,7 @@ impl JupiterClient {
 
     /// Advanced Route Analysis - Compare multiple routes and select the optimal one
     //  Analyze metrics such as price impact, slippage, and execution time of different routes.
+    #[tracing::instrument(skip(self))]
     pub async fn analyze_routes(
         &self,
         input_mint: &str,
@@ -627,15 +3399,125 @@ impl JupiterClient {
         }
         let best_route = routes.first().unwrap().clone();
         let mut analysis = RouteAnalysis::new(best_route);
+        analysis.estimated_time = self
+            .latency_tracker
+            .estimate_route_time(&analysis.best_route);
         if routes.len() > 1 {
             let max_alt = max_routes.unwrap_or(3).min(routes.len() - 1);
             analysis.alternative_routes = routes[1..=max_alt].to_vec();
         }
-        if let Ok(price_impact) = analysis.best_route.price_impact_pct.parse::<f64>() {
-            analysis.confidence_score = (100.0 - price_impact.max(0.0)) / 100.0;
-            analysis.confidence_score = analysis.confidence_score.max(0.1).min(1.0);
+        let inputs = ConfidenceInputs {
+            venue_reliability: self.venue_reliability.success_rates(),
+            slot_lag: Some(self.quote_telemetry.slot_lag(&analysis.best_route)),
+            ..Default::default()
+        };
+        Ok(analysis.with_confidence(&inputs))
+    }
+
+    /// Amount-ladder depth probing - Quote a pair across several input amounts
+    /// Quotes are issued concurrently under the client rate limiter and combined into a
+    /// slippage/price-impact curve, useful for sizing orders and estimating pair liquidity.
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::JupiterClient;
+    ///
+    /// async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = JupiterClient::new()?;
+    /// let curve = client
+    ///     .probe_depth(
+    ///         "So11111111111111111111111111111111111111112",
+    ///         "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
+    ///         &[1_000_000_000, 10_000_000_000, 100_000_000_000],
+    ///     )
+    ///     .await?;
+    /// for point in curve.points {
+    ///     println!("{} -> impact {}%", point.amount, point.price_impact_pct);
+    /// }
+    /// Ok(())
+    /// }
+    /// ```
+    #[tracing::instrument(skip(self, amounts), fields(amounts = amounts.len()))]
+    pub async fn probe_depth(
+        &self,
+        input_mint: &str,
+        output_mint: &str,
+        amounts: &[u64],
+    ) -> Result<DepthCurve, JupiterError> {
+        self.validate_mint_address(input_mint)?;
+        self.validate_mint_address(output_mint)?;
+        if amounts.is_empty() {
+            return Err(JupiterError::InvalidInput(
+                "No probe amounts provided".to_string(),
+            ));
+        }
+        let requests = amounts
+            .iter()
+            .map(|amount| QuoteRequest {
+                input_mint: input_mint.to_string(),
+                output_mint: output_mint.to_string(),
+                amount: *amount,
+                slippage_bps: DEFAULT_SLIPPAGE_BPS,
+                fee_bps: None,
+                only_direct_routes: None,
+                as_legacy_transaction: None,
+                restrict_middle_tokens: None,
+            })
+            .collect();
+        let quotes = self
+            .get_quotes(&BatchQuoteRequest { requests })
+            .await?
+            .into_results();
+        let curve = DepthCurve::from_quotes(
+            input_mint,
+            output_mint,
+            amounts.iter().copied().zip(quotes).collect(),
+        );
+        if curve.points.is_empty() && !curve.failed.is_empty() {
+            return Err(curve.failed[0].1.clone());
+        }
+        Ok(curve)
+    }
+
+    /// Pair liquidity estimation - How much can be traded within a price impact budget
+    /// Probes a coarse geometric ladder, refines between the amounts bracketing the budget,
+    /// and interpolates, e.g. "up to ~X SOL within 1% impact".
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::JupiterClient;
+    ///
+    /// async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = JupiterClient::new()?;
+    /// let estimate = client
+    ///     .estimate_liquidity(
+    ///         "So11111111111111111111111111111111111111112",
+    ///         "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
+    ///         100, // 1% impact
+    ///     )
+    ///     .await?;
+    /// println!("Tradable within 1%: {}", estimate.max_amount);
+    /// Ok(())
+    /// }
+    /// ```
+    #[tracing::instrument(skip(self))]
+    pub async fn estimate_liquidity(
+        &self,
+        input_mint: &str,
+        output_mint: &str,
+        max_impact_bps: u16,
+    ) -> Result<LiquidityEstimate, JupiterError> {
+        let ladder = geometric_ladder(LIQUIDITY_PROBE_START_AMOUNT, 10, LIQUIDITY_PROBE_STEPS);
+        let mut curve = self.probe_depth(input_mint, output_mint, &ladder).await?;
+        let refine = match curve.impact_bracket(max_impact_bps) {
+            (Some(lo), Some(hi)) => linear_ladder(lo.amount, hi.amount, LIQUIDITY_REFINE_STEPS),
+            _ => Vec::new(),
+        };
+        // An empty refinement ladder is rejected by probe_depth and simply skipped here
+        if let Ok(refined) = self.probe_depth(input_mint, output_mint, &refine).await {
+            curve.merge(refined);
         }
-        Ok(analysis)
+        Ok(curve.estimate_liquidity(max_impact_bps))
     }
 
     /// Paginated token list - Use pagination when retrieving a large number of tokens
@@ -653,57 +3535,123 @@ impl JupiterClient {
         if let Some(page_size) = page_size {
             request_builder = request_builder.query(&[("pageSize", page_size)]);
         }
-        let response = request_builder
-            .send()
-            .await
-            .map_err(|e| JupiterError::NetworkError(e.to_string()))?;
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .map_err(|e| JupiterError::NetworkError(e.to_string()))?;
-            return Err(JupiterError::RequestFailed(format!(
-                "HTTP {}: {}",
-                status, error_text
-            )));
+        // Pages bypass the conditional cache, which holds the full token list
+        let response = self.send("tokens", request_builder).await?;
+        if !response.status().is_success() {
+            return Err(Self::response_error(response).await);
         }
-        let tokens: Vec<TokenInfo> = response
+        response
             .json()
             .await
-            .map_err(|e| JupiterError::ParseError(e.to_string()))?;
-        Ok(tokens)
+            .map_err(|e| JupiterError::ParseError(e.to_string()))
     }
 
     /// Filter tokens by tag - Get tokens categorized by purpose
     /// Filter tokens by tag (e.g., stablecoin, defi, etc.)
     pub async fn get_tokens_by_tag(&self, tag: &str) -> Result<Vec<TokenInfo>, JupiterError> {
-        let all_tokens = self.get_tokens().await?;
-        let filtered: Vec<TokenInfo> = all_tokens
+        let tokens = self.get_token_map().await?;
+        Ok(tokens
+            .get_by_tag(tag)
             .into_iter()
-            .filter(|token| token.tags.iter().any(|t| t == tag))
-            .collect();
-        Ok(filtered)
+            .map(|token| token.as_ref().clone())
+            .collect())
     }
 
     /// Calculate transaction fees - Estimate transaction execution costs
-    /// Estimate transaction fees based on transaction complexity and current network status
+    ///
+    /// Combines the signature fee, the compute units of the swap and recent priority
+    /// fee percentiles for the route's AMM accounts. Compute units come from simulating
+    /// `swap` when given, falling back to the estimator calibrated on executed swaps.
+    ///
+    /// # Params
+    /// quote - Quote of the swap
+    /// swap - Swap transaction built for the quote, if available
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::{JupiterClient, types::QuoteResponse};
+    ///
+    /// async fn example(client: JupiterClient, quote: QuoteResponse) -> Result<(), Box<dyn std::error::Error>> {
+    /// let estimate = client.estimate_transaction_fee(&quote, None).await?;
+    /// println!("fee: {} lamports (up to {})", estimate.expected, estimate.max);
+    /// Ok(())
+    /// }
+    /// ```
     pub async fn estimate_transaction_fee(
         &self,
         quote: &QuoteResponse,
-        priority_fee: Option<u64>,
-    ) -> Result<u64, JupiterError> {
-        // Base compute unit fee in micro-lamports per CU
-        let base_fee = 5000; // micro-lamports per CU
-        // Estimate compute units based on route complexity
-        let compute_units = match quote.route_plan.len() {
-            1 => 100_000, // Simple swap
-            2 => 150_000, // Medium complexity
-            _ => 200_000, // Complex route
+        swap: Option<&SwapResponse>,
+    ) -> Result<FeeEstimate, JupiterError> {
+        let rpc_client = self
+            .solana
+            .client
+            .clone()
+            .ok_or(JupiterError::Error("solana client error".to_string()))?;
+        let transaction = match swap {
+            Some(swap) => Some(SwapTransaction::decode(&swap.swap_transaction, false)?),
+            None => None,
+        };
+        let signatures = transaction
+            .as_ref()
+            .map_or(1, |transaction| match transaction {
+                SwapTransaction::Legacy(transaction) => transaction.signatures.len(),
+                SwapTransaction::Versioned(transaction) => transaction.signatures.len(),
+            });
+        let simulated_units = match &transaction {
+            Some(transaction) => {
+                let config = RpcSimulateTransactionConfig {
+                    sig_verify: false,
+                    replace_recent_blockhash: true,
+                    ..RpcSimulateTransactionConfig::default()
+                };
+                let simulation = match transaction {
+                    SwapTransaction::Legacy(transaction) => {
+                        rpc_client
+                            .simulate_transaction_with_config(transaction, config)
+                            .await
+                    }
+                    SwapTransaction::Versioned(transaction) => {
+                        rpc_client
+                            .simulate_transaction_with_config(transaction, config)
+                            .await
+                    }
+                };
+                match simulation {
+                    Ok(response) if response.value.err.is_none() => response.value.units_consumed,
+                    Ok(response) => {
+                        tracing::debug!(error = ?response.value.err, "fee estimate simulation failed");
+                        None
+                    }
+                    Err(e) => {
+                        tracing::debug!(error = %e, "fee estimate simulation failed");
+                        None
+                    }
+                }
+            }
+            None => None,
         };
-        let total_fee = base_fee * compute_units / 1_000_000; // 转换为 lamports
-        let priority_fee = priority_fee.unwrap_or(0);
-        Ok(total_fee + priority_fee)
+        let accounts: Vec<Pubkey> = quote
+            .route_plan
+            .iter()
+            .filter_map(|plan| validate_pubkey(&plan.swap_info.amm_key).ok())
+            .collect();
+        let recent_fees = rpc_client
+            .get_recent_prioritization_fees(&accounts)
+            .await
+            .map_err(|e| JupiterError::NetworkError(e.to_string()))?;
+        let unit_prices = percentiles(
+            recent_fees
+                .iter()
+                .map(|fee| fee.prioritization_fee)
+                .collect(),
+            PRIORITY_FEE_PERCENTILES,
+        );
+        Ok(FeeEstimate::new(
+            LAMPORTS_PER_SIGNATURE * signatures as u64,
+            simulated_units.unwrap_or_else(|| self.compute_unit_estimator.estimate(quote)),
+            simulated_units.is_some(),
+            unit_prices,
+        ))
     }
 
     /// Exchange transaction creation with retries
@@ -725,33 +3673,299 @@ impl JupiterClient {
         F: Fn() -> Fut,
         Fut: std::future::Future<Output = Result<T, JupiterError>>,
     {
-        let mut last_error = None;
+        self.run_with_retry(&ExponentialBackoff::from(config.clone()), operation)
+            .await
+    }
 
-        for attempt in 0..=config.max_retries {
-            match operation().await {
-                Ok(result) => return Ok(result),
-                Err(e) => {
-                    last_error = Some(e.clone());
-                    if attempt < config.max_retries && e.is_retriable() {
-                        let delay = Self::cal_delay(attempt, config);
-                        time::sleep(delay).await;
-                        continue;
-                    } else {
-                        break;
+    /// Runs an operation through the retry strategy, the client retry budget and,
+    /// when configured, request hedging
+    async fn run_with_retry<S, F, T, Fut>(
+        &self,
+        strategy: &S,
+        operation: F,
+    ) -> Result<T, JupiterError>
+    where
+        S: RetryStrategy + ?Sized,
+        F: Fn() -> Fut,
+        Fut: std::future::Future<Output = Result<T, JupiterError>>,
+    {
+        let budget = self.retry_budget.as_ref();
+        match self.config.hedge_after {
+            Some(hedge_after) => {
+                retry_with_budget(strategy, budget, || hedge(hedge_after, &operation)).await
+            }
+            None => retry_with_budget(strategy, budget, &operation).await,
+        }
+    }
+
+    /// Serves a response from the cache backend, fetching and storing it on a miss
+    /// A `None` TTL bypasses the cache.
+    async fn cached<T, F, Fut>(
+        &self,
+        key: &str,
+        ttl: Option<Duration>,
+        fetch: F,
+    ) -> Result<T, JupiterError>
+    where
+        T: Serialize + DeserializeOwned,
+        F: FnOnce() -> Fut,
+        Fut: std::future::Future<Output = Result<T, JupiterError>>,
+    {
+        let Some(ttl) = ttl else {
+            return fetch().await;
+        };
+        if let Some(bytes) = self.cache.get(key).await
+            && let Ok(value) = serde_json::from_slice(&bytes)
+        {
+            return Ok(value);
+        }
+        let value = fetch().await?;
+        if let Ok(bytes) = serde_json::to_vec(&value) {
+            self.cache.set(key, bytes, ttl).await;
+        }
+        Ok(value)
+    }
+
+    /// Sends a conditional GET, reusing the cached response on 304 Not Modified or
+    /// when the downloaded body is unchanged
+    async fn send_conditional<T: DeserializeOwned + Clone>(
+        &self,
+        endpoint: &str,
+        mut request_builder: reqwest::RequestBuilder,
+        cache: &ConditionalCache<T>,
+    ) -> Result<T, JupiterError> {
+        if let Some(etag) = cache.etag() {
+            request_builder = request_builder.header(IF_NONE_MATCH, etag);
+        }
+        let response = self.send(endpoint, request_builder).await?;
+        if response.status() == StatusCode::NOT_MODIFIED
+            && let Some(value) = cache.cached()
+        {
+            return Ok(value);
+        }
+        if !response.status().is_success() {
+            return Err(Self::response_error(response).await);
+        }
+        let etag = response
+            .headers()
+            .get(ETAG)
+            .and_then(|etag| etag.to_str().ok())
+            .map(|etag| etag.to_string());
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| JupiterError::NetworkError(e.to_string()))?;
+        let hash = content_hash(&body);
+        if let Some(value) = cache.cached_if_unchanged(etag.clone(), hash) {
+            return Ok(value);
+        }
+        let value: T =
+            serde_json::from_slice(&body).map_err(|e| JupiterError::ParseError(e.to_string()))?;
+        cache.store(etag, hash, value.clone());
+        Ok(value)
+    }
+
+    /// Sends a request and records its latency and outcome under `endpoint`
+    ///
+    /// Every call gets a fresh request ID, sent as `x-request-id` and recorded on the
+    /// `jupiter_request` span. If the server doesn't return an `x-request-id`, the
+    /// sent one is added to the response headers, so `response_error` can attach it
+    /// to the error.
+    async fn send(
+        &self,
+        endpoint: &str,
+        request_builder: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, JupiterError> {
+        let request_id = prefixed_id("req");
+        let span = tracing::debug_span!("jupiter_request", endpoint, request_id = %request_id);
+        self.send_with_id(endpoint, &request_id, request_builder)
+            .instrument(span)
+            .await
+    }
+
+    async fn send_with_id(
+        &self,
+        endpoint: &str,
+        request_id: &str,
+        request_builder: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, JupiterError> {
+        let request_builder = request_builder.header(REQUEST_ID_HEADER, request_id);
+        let request_builder = match &self.config.integrator_id {
+            Some(integrator_id) => request_builder.header(INTEGRATOR_ID_HEADER, integrator_id),
+            None => request_builder,
+        };
+        #[cfg(feature = "otel")]
+        let request_builder = telemetry::inject_trace_context(request_builder);
+        let request_builder = match &self.config.api_key {
+            Some(api_key) => request_builder.header("x-api-key", api_key.expose_secret()),
+            None => request_builder,
+        };
+        // Every Jupiter API request draws from the budget shared by the client's clones
+        self.throttle().await;
+        if let Some(throttle) = &self.adaptive_throttle {
+            throttle.acquire(endpoint).await;
+        }
+        let started = Instant::now();
+        let result = match self
+            .config
+            .request_log
+            .as_ref()
+            .filter(|log| log.logs(endpoint))
+        {
+            Some(log) => {
+                let request = request_builder
+                    .build()
+                    .map_err(|e| JupiterError::InvalidInput(e.to_string()))?;
+                let logged = request.try_clone();
+                let result = self.client.execute(request).await;
+                match logged {
+                    Some(logged) => {
+                        log.log_exchange(endpoint, &logged, result, started.elapsed())
+                            .await
                     }
+                    None => result,
+                }
+            }
+            None => request_builder.send().await,
+        };
+        let elapsed = started.elapsed();
+        let success = matches!(&result, Ok(response)
+            if response.status().is_success() || response.status() == StatusCode::NOT_MODIFIED);
+        self.metrics.record(endpoint, elapsed, success);
+        if let (Some(throttle), Ok(response)) = (&self.adaptive_throttle, &result)
+            && response.status() == StatusCode::TOO_MANY_REQUESTS
+        {
+            let retry_after = response
+                .headers()
+                .get(RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse::<u64>().ok())
+                .map(Duration::from_secs);
+            throttle.record_rate_limited(endpoint, retry_after);
+        }
+        let mut result = result;
+        if let Ok(response) = &mut result
+            && !response.headers().contains_key(REQUEST_ID_HEADER)
+            && let Ok(value) = HeaderValue::from_str(request_id)
+        {
+            response.headers_mut().insert(REQUEST_ID_HEADER, value);
+        }
+        let server_request_id = result.as_ref().ok().and_then(|response| {
+            response
+                .headers()
+                .get(REQUEST_ID_HEADER)
+                .and_then(|value| value.to_str().ok())
+                .filter(|value| *value != request_id)
+                .map(str::to_string)
+        });
+        match &result {
+            Ok(response) if success => tracing::debug!(
+                endpoint,
+                server_request_id = server_request_id.as_deref(),
+                status = response.status().as_u16(),
+                elapsed_ms = elapsed.as_millis() as u64,
+                "request completed"
+            ),
+            Ok(response) => tracing::warn!(
+                endpoint,
+                server_request_id = server_request_id.as_deref(),
+                status = response.status().as_u16(),
+                elapsed_ms = elapsed.as_millis() as u64,
+                "request returned error status"
+            ),
+            Err(e) => tracing::warn!(
+                endpoint,
+                error = %e,
+                elapsed_ms = elapsed.as_millis() as u64,
+                "request failed"
+            ),
+        }
+        result.map_err(|e| JupiterError::NetworkError(format!("{} (request id {})", e, request_id)))
+    }
+
+    /// Converts a non-success HTTP response into a JupiterError carrying its status code
+    /// and request ID
+    async fn response_error(response: reqwest::Response) -> JupiterError {
+        let status = response.status().as_u16();
+        let request_id = response
+            .headers()
+            .get(REQUEST_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        match response.text().await {
+            Ok(message) => {
+                let error = JupiterError::from_status(status, message);
+                match request_id {
+                    Some(request_id) => error.with_request_id(&request_id),
+                    None => error,
                 }
             }
+            Err(e) => JupiterError::NetworkError(e.to_string()),
+        }
+    }
+
+    fn deadline_exceeded() -> JupiterError {
+        JupiterError::NetworkError("Request deadline exceeded".to_string())
+    }
+
+    /// Value of a quote's output in lamports, quoting the output back to SOL unless
+    /// either side of the swap already is SOL
+    async fn notional_lamports(&self, quote: &QuoteResponse) -> Result<u64, JupiterError> {
+        let amount = |value: &str| {
+            value
+                .parse::<u64>()
+                .map_err(|_| JupiterError::ParseError(format!("invalid amount {}", value)))
+        };
+        if quote.output_mint == WSOL_MINT {
+            return amount(&quote.out_amount);
+        }
+        if quote.input_mint == WSOL_MINT {
+            return amount(&quote.in_amount);
+        }
+        let request = QuoteRequest {
+            input_mint: quote.output_mint.clone(),
+            output_mint: WSOL_MINT.to_string(),
+            amount: amount(&quote.out_amount)?,
+            slippage_bps: DEFAULT_SLIPPAGE_BPS,
+            fee_bps: None,
+            only_direct_routes: None,
+            as_legacy_transaction: None,
+            restrict_middle_tokens: None,
+        };
+        amount(&self.get_quote(&request).await?.out_amount)
+    }
+
+    /// Decimals of a mint, from the token list or else the on-chain mint account
+    async fn mint_decimals(&self, mint: &str) -> Result<u8, JupiterError> {
+        if let Ok(tokens) = self.get_token_map().await
+            && let Some(token) = tokens.get(mint)
+        {
+            return Ok(token.decimals);
         }
-        Err(last_error
-            .unwrap_or_else(|| JupiterError::Error("Unknown error after retries".to_string())))
+        let mint = validate_pubkey(mint).map_err(JupiterError::InvalidInput)?;
+        let rpc_client = self
+            .solana
+            .client
+            .clone()
+            .ok_or(JupiterError::Error("solana client error".to_string()))?;
+        let account = rpc_client
+            .get_account_with_commitment(&mint, self.config.commitment)
+            .await
+            .map_err(|e| JupiterError::NetworkError(e.to_string()))?
+            .value
+            .ok_or_else(|| JupiterError::InvalidInput(format!("mint {} not found", mint)))?;
+        account
+            .data
+            .get(MINT_DECIMALS_OFFSET)
+            .copied()
+            .ok_or_else(|| JupiterError::InvalidInput(format!("{} is not a token mint", mint)))
     }
 
-    /// Calculate backoff delay
-    fn cal_delay(attempt: u32, config: &RetryConfig) -> Duration {
-        let delay = config.initial_delay.as_millis() as f64
-            * config.backoff_multiplier.powi(attempt as i32);
-        let delay = delay.min(config.max_delay.as_millis() as f64);
-        Duration::from_millis(delay as u64)
+    /// Waits for the rate limiter, if one is configured
+    async fn throttle(&self) {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.acquire().await;
+        }
     }
 
     fn validate_quote_request(&self, request: &QuoteRequest) -> Result<(), JupiterError> {
@@ -769,6 +3983,39 @@ impl JupiterClient {
         Ok(())
     }
 
+    /// Runs the registered pre-trade checks, failing on the first veto
+    async fn run_pre_trade_checks(
+        &self,
+        quote: &QuoteResponse,
+        wallet: &Pubkey,
+    ) -> Result<(), JupiterError> {
+        for check in &self.pre_trade_checks {
+            if let PreTradeDecision::Veto(reason) = check.check(quote, wallet).await? {
+                tracing::warn!(check = %check.name(), reason = %reason, "pre-trade check vetoed trade");
+                return Err(JupiterError::PreTradeVeto {
+                    check: check.name().to_string(),
+                    reason,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks `mints` against the client's token policy, loading the token list when
+    /// the policy requires tags
+    async fn enforce_token_policy(&self, mints: &[&str]) -> Result<(), JupiterError> {
+        let Some(policy) = &self.token_policy else {
+            return Ok(());
+        };
+        let tokens = match policy.needs_token_map() {
+            true => Some(self.get_token_map().await?),
+            false => None,
+        };
+        policy
+            .check_mints(mints, tokens.as_deref())
+            .inspect_err(|e| tracing::warn!(error = %e, "token policy violation"))
+    }
+
     fn validate_swap_request(&self, request: &SwapRequest) -> Result<(), JupiterError> {
         self.validate_pubkey(&request.user_public_key)?;
         Ok(())
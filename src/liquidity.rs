@@ -0,0 +1,238 @@
+/// Liquidity analysis module.
+/// Turns quotes issued across an amount ladder into a depth curve for a token pair.
+use crate::{
+    dex::DexId,
+    types::{JupiterError, QuoteResponse},
+};
+
+/// A single point on a depth curve
+#[derive(Debug, Clone)]
+pub struct DepthPoint {
+    /// Input amount quoted (raw units)
+    pub amount: u64,
+    /// Output amount returned by the quote (raw units)
+    pub out_amount: u64,
+    /// Effective price as output units per input unit
+    pub price: f64,
+    /// Price impact percentage reported by the API
+    pub price_impact_pct: f64,
+    /// Price degradation percentage relative to the smallest quoted amount
+    pub slippage_pct: f64,
+    /// Number of hops in the route plan
+    pub hops: usize,
+    /// Quote the point was derived from
+    pub quote: QuoteResponse,
+}
+
+/// Depth curve built from an amount ladder, ordered by ascending amount
+#[derive(Debug, Clone)]
+pub struct DepthCurve {
+    pub input_mint: String,
+    pub output_mint: String,
+    pub points: Vec<DepthPoint>,
+    /// Amounts that could not be quoted, with the error returned
+    pub failed: Vec<(u64, JupiterError)>,
+}
+
+impl DepthCurve {
+    /// Builds a depth curve from the quotes returned for each ladder amount
+    ///
+    /// # Params
+    /// input_mint - Input token mint
+    /// output_mint - Output token mint
+    /// results - Quote result for each ladder amount
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::liquidity::DepthCurve;
+    ///
+    /// let curve = DepthCurve::from_quotes("So111...", "EPjF...", Vec::new());
+    /// assert!(curve.points.is_empty());
+    /// ```
+    pub fn from_quotes(
+        input_mint: &str,
+        output_mint: &str,
+        results: Vec<(u64, Result<QuoteResponse, JupiterError>)>,
+    ) -> Self {
+        let mut points = Vec::new();
+        let mut failed = Vec::new();
+        for (amount, result) in results {
+            match result {
+                Ok(quote) => {
+                    let out_amount: u64 = quote.out_amount.parse().unwrap_or(0);
+                    let price = if amount == 0 {
+                        0.0
+                    } else {
+                        out_amount as f64 / amount as f64
+                    };
+                    points.push(DepthPoint {
+                        amount,
+                        out_amount,
+                        price,
+                        price_impact_pct: quote.price_impact_pct.parse().unwrap_or(0.0),
+                        slippage_pct: 0.0,
+                        hops: quote.route_plan.len(),
+                        quote,
+                    });
+                }
+                Err(e) => failed.push((amount, e)),
+            }
+        }
+        let mut curve = Self {
+            input_mint: input_mint.to_string(),
+            output_mint: output_mint.to_string(),
+            points,
+            failed,
+        };
+        curve.normalize();
+        curve
+    }
+
+    /// Merges the points of another probe of the same pair into this curve
+    pub fn merge(&mut self, other: DepthCurve) {
+        self.points.extend(other.points);
+        self.failed.extend(other.failed);
+        self.normalize();
+    }
+
+    /// Sorts points by amount and recomputes slippage against the smallest amount
+    fn normalize(&mut self) {
+        self.points.sort_by_key(|point| point.amount);
+        self.points.dedup_by_key(|point| point.amount);
+        let reference_price = self.reference_price().unwrap_or(0.0);
+        if reference_price > 0.0 {
+            for point in self.points.iter_mut() {
+                point.slippage_pct = (reference_price - point.price) / reference_price * 100.0;
+            }
+        }
+    }
+
+    /// Price at the smallest quoted amount, used as the reference for slippage
+    pub fn reference_price(&self) -> Option<f64> {
+        self.points.first().map(|point| point.price)
+    }
+}
+
+/// Tradable liquidity estimate for a pair at a maximum price impact
+#[derive(Debug, Clone)]
+pub struct LiquidityEstimate {
+    pub input_mint: String,
+    pub output_mint: String,
+    /// Price impact budget in basis points
+    pub max_impact_bps: u16,
+    /// Largest input amount estimated to stay within the impact budget (raw units)
+    pub max_amount: u64,
+    /// Estimated output amount for `max_amount` (raw units)
+    pub out_amount: u64,
+    /// Whether a probed amount exceeded the budget; if false the estimate is a lower bound
+    pub bounded: bool,
+    /// Venues used by the largest route within budget
+    pub venues: Vec<DexId>,
+}
+
+impl DepthCurve {
+    /// Returns the last point within the impact budget and the first point beyond it
+    ///
+    /// # Params
+    /// max_impact_bps - Price impact budget in basis points
+    pub fn impact_bracket(
+        &self,
+        max_impact_bps: u16,
+    ) -> (Option<&DepthPoint>, Option<&DepthPoint>) {
+        let max_impact_pct = max_impact_bps as f64 / 100.0;
+        let within = self
+            .points
+            .iter()
+            .take_while(|point| point.price_impact_pct <= max_impact_pct)
+            .last();
+        let beyond = self
+            .points
+            .iter()
+            .find(|point| point.price_impact_pct > max_impact_pct);
+        (within, beyond)
+    }
+
+    /// Estimates tradable liquidity within the impact budget
+    ///
+    /// Interpolates linearly on price impact between the bracketing points.
+    ///
+    /// # Params
+    /// max_impact_bps - Price impact budget in basis points
+    pub fn estimate_liquidity(&self, max_impact_bps: u16) -> LiquidityEstimate {
+        let max_impact_pct = max_impact_bps as f64 / 100.0;
+        let (within, beyond) = self.impact_bracket(max_impact_bps);
+        let (max_amount, out_amount) = match (within, beyond) {
+            (Some(lo), Some(hi)) if hi.price_impact_pct > lo.price_impact_pct => {
+                let ratio = (max_impact_pct - lo.price_impact_pct)
+                    / (hi.price_impact_pct - lo.price_impact_pct);
+                let amount = lo.amount as f64 + (hi.amount - lo.amount) as f64 * ratio;
+                let out =
+                    lo.out_amount as f64 + (hi.out_amount as f64 - lo.out_amount as f64) * ratio;
+                (amount as u64, out.max(0.0) as u64)
+            }
+            (Some(lo), _) => (lo.amount, lo.out_amount),
+            (None, _) => (0, 0),
+        };
+        LiquidityEstimate {
+            input_mint: self.input_mint.clone(),
+            output_mint: self.output_mint.clone(),
+            max_impact_bps,
+            max_amount,
+            out_amount,
+            bounded: beyond.is_some(),
+            venues: within
+                .map(|point| {
+                    point
+                        .quote
+                        .route_plan
+                        .iter()
+                        .map(|plan| plan.swap_info.label.clone())
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Builds a geometric amount ladder
+///
+/// # Params
+/// start - First amount of the ladder (raw units)
+/// factor - Multiplier between consecutive amounts
+/// steps - Number of amounts to generate
+///
+/// # Example
+/// ```rust
+/// use jup_sdk::liquidity::geometric_ladder;
+///
+/// assert_eq!(geometric_ladder(1_000, 10, 3), vec![1_000, 10_000, 100_000]);
+/// ```
+pub fn geometric_ladder(start: u64, factor: u64, steps: usize) -> Vec<u64> {
+    let mut ladder = Vec::with_capacity(steps);
+    let mut amount = start.max(1);
+    for _ in 0..steps {
+        ladder.push(amount);
+        match amount.checked_mul(factor.max(2)) {
+            Some(next) => amount = next,
+            None => break,
+        }
+    }
+    ladder
+}
+
+/// Builds an evenly spaced ladder strictly between two amounts
+///
+/// # Params
+/// lo - Lower bound (exclusive)
+/// hi - Upper bound (exclusive)
+/// steps - Number of amounts to generate
+pub fn linear_ladder(lo: u64, hi: u64, steps: usize) -> Vec<u64> {
+    if hi <= lo.saturating_add(1) {
+        return Vec::new();
+    }
+    let step = (hi - lo) / (steps as u64 + 1);
+    (1..=steps as u64)
+        .map(|i| lo + step * i)
+        .filter(|amount| *amount > lo && *amount < hi)
+        .collect()
+}
@@ -0,0 +1,227 @@
+/// Request logger module.
+/// Opt-in logging of Jupiter API exchanges with redaction of API keys and wallet addresses.
+use std::{collections::HashSet, time::Duration};
+
+use reqwest::{Request, Response};
+use serde_json::Value;
+
+/// Placeholder logged in place of redacted values
+const REDACTED: &str = "[REDACTED]";
+
+/// Parameter, header and JSON field names redacted by default
+/// Names are compared case-insensitively with `-` and `_` removed.
+const SENSITIVE_FIELDS: [&str; 9] = [
+    "apikey",
+    "xapikey",
+    "authorization",
+    "userpublickey",
+    "feeaccount",
+    "destinationtokenaccount",
+    "trackingaccount",
+    "payer",
+    "wallet",
+];
+
+/// Configuration of the request/response logger
+#[derive(Debug, Clone)]
+pub struct RequestLogConfig {
+    /// Endpoints to log, e.g. `quote` or `swap`; `None` logs every endpoint
+    pub endpoints: Option<HashSet<String>>,
+    /// Log request and response bodies
+    pub log_bodies: bool,
+    /// Bodies longer than this are truncated, in bytes
+    pub max_body_len: usize,
+    /// Additional parameter or field names whose values are redacted
+    pub redact_fields: Vec<String>,
+}
+
+impl Default for RequestLogConfig {
+    fn default() -> Self {
+        Self {
+            endpoints: None,
+            log_bodies: true,
+            max_body_len: 1024,
+            redact_fields: Vec::new(),
+        }
+    }
+}
+
+impl RequestLogConfig {
+    /// Logs only the given endpoints
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::logger::RequestLogConfig;
+    ///
+    /// let config = RequestLogConfig::for_endpoints(&["swap"]);
+    /// assert!(config.logs("swap"));
+    /// assert!(!config.logs("quote"));
+    /// ```
+    pub fn for_endpoints(endpoints: &[&str]) -> Self {
+        Self {
+            endpoints: Some(endpoints.iter().map(|e| e.to_string()).collect()),
+            ..Self::default()
+        }
+    }
+
+    /// Whether exchanges with `endpoint` are logged
+    pub fn logs(&self, endpoint: &str) -> bool {
+        self.endpoints
+            .as_ref()
+            .is_none_or(|endpoints| endpoints.contains(endpoint))
+    }
+
+    fn is_sensitive(&self, name: &str) -> bool {
+        let normalize = |name: &str| name.to_lowercase().replace(['-', '_'], "");
+        let name = normalize(name);
+        SENSITIVE_FIELDS.contains(&name.as_str())
+            || self
+                .redact_fields
+                .iter()
+                .any(|field| normalize(field) == name)
+    }
+
+    /// Returns `url` with the values of sensitive query parameters redacted
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::logger::RequestLogConfig;
+    ///
+    /// let url = url::Url::parse("https://api.jup.ag/swap?api-key=secret&amount=1").unwrap();
+    /// assert_eq!(
+    ///     RequestLogConfig::default().redact_url(&url),
+    ///     "https://api.jup.ag/swap?api-key=%5BREDACTED%5D&amount=1"
+    /// );
+    /// ```
+    pub fn redact_url(&self, url: &url::Url) -> String {
+        if url.query().is_none() {
+            return url.to_string();
+        }
+        let mut redacted = url.clone();
+        let pairs: Vec<(String, String)> = url
+            .query_pairs()
+            .map(|(key, value)| {
+                let value = if self.is_sensitive(&key) {
+                    REDACTED.to_string()
+                } else {
+                    value.into_owned()
+                };
+                (key.into_owned(), value)
+            })
+            .collect();
+        redacted.query_pairs_mut().clear().extend_pairs(pairs);
+        redacted.to_string()
+    }
+
+    /// Returns a body with sensitive JSON fields redacted, truncated to `max_body_len`
+    pub fn redact_body(&self, body: &[u8]) -> String {
+        let text = match serde_json::from_slice::<Value>(body) {
+            Ok(mut value) => {
+                self.redact_value(&mut value);
+                value.to_string()
+            }
+            Err(_) => String::from_utf8_lossy(body).into_owned(),
+        };
+        truncate(text, self.max_body_len)
+    }
+
+    fn redact_value(&self, value: &mut Value) {
+        match value {
+            Value::Object(map) => {
+                for (key, value) in map.iter_mut() {
+                    if self.is_sensitive(key) && !value.is_object() && !value.is_array() {
+                        *value = Value::String(REDACTED.to_string());
+                    } else {
+                        self.redact_value(value);
+                    }
+                }
+            }
+            Value::Array(values) => values.iter_mut().for_each(|value| self.redact_value(value)),
+            _ => {}
+        }
+    }
+
+    /// Logs a completed exchange, buffering the response body when bodies are logged
+    pub(crate) async fn log_exchange(
+        &self,
+        endpoint: &str,
+        request: &Request,
+        result: Result<Response, reqwest::Error>,
+        elapsed: Duration,
+    ) -> Result<Response, reqwest::Error> {
+        let method = request.method().as_str();
+        let url = self.redact_url(request.url());
+        let elapsed_ms = elapsed.as_millis() as u64;
+        let request_body = if self.log_bodies {
+            request
+                .body()
+                .and_then(|body| body.as_bytes())
+                .map(|body| self.redact_body(body))
+        } else {
+            None
+        };
+        let response = match result {
+            Ok(response) => response,
+            Err(e) => {
+                tracing::info!(
+                    endpoint,
+                    method,
+                    url = %url,
+                    elapsed_ms,
+                    request_body = request_body.as_deref(),
+                    error = %e,
+                    "jupiter request failed"
+                );
+                return Err(e);
+            }
+        };
+        let status = response.status();
+        if !self.log_bodies {
+            tracing::info!(
+                endpoint,
+                method,
+                url = %url,
+                status = status.as_u16(),
+                elapsed_ms,
+                "jupiter request"
+            );
+            return Ok(response);
+        }
+        let version = response.version();
+        let headers = response.headers().clone();
+        let body = response.bytes().await?;
+        tracing::info!(
+            endpoint,
+            method,
+            url = %url,
+            status = status.as_u16(),
+            elapsed_ms,
+            request_body = request_body.as_deref(),
+            response_body = %self.redact_body(&body),
+            "jupiter request"
+        );
+        let mut builder = http::Response::builder().status(status).version(version);
+        if let Some(builder_headers) = builder.headers_mut() {
+            *builder_headers = headers;
+        }
+        let response = builder
+            .body(body)
+            .expect("status and headers were taken from a valid response");
+        Ok(Response::from(response))
+    }
+}
+
+/// Truncates `text` to at most `max_len` bytes on a character boundary
+fn truncate(mut text: String, max_len: usize) -> String {
+    if text.len() <= max_len {
+        return text;
+    }
+    let mut end = max_len;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    let omitted = text.len() - end;
+    text.truncate(end);
+    text.push_str(&format!("...({} bytes truncated)", omitted));
+    text
+}
@@ -0,0 +1,82 @@
+/// Market-making module.
+/// Two-sided quotes with derived mid price and spread for pricing services.
+use std::time::SystemTime;
+
+use crate::types::{JupiterError, QuoteResponse};
+
+/// Simultaneous bid and ask quotes for a fixed base size
+#[derive(Debug, Clone)]
+pub struct TwoSidedQuote {
+    pub base_mint: String,
+    pub quote_mint: String,
+    /// Base size quoted on both sides (raw units)
+    pub size: u64,
+    /// Quote selling `size` base for the quote token (ExactIn)
+    pub bid: QuoteResponse,
+    /// Quote buying `size` base with the quote token (ExactOut)
+    pub ask: QuoteResponse,
+    /// Quote tokens received per base token when selling
+    pub bid_price: f64,
+    /// Quote tokens paid per base token when buying
+    pub ask_price: f64,
+    pub mid_price: f64,
+    /// Ask minus bid relative to the mid price, in basis points
+    pub spread_bps: f64,
+    /// Time both quotes were received
+    pub timestamp: SystemTime,
+}
+
+impl TwoSidedQuote {
+    /// Derives prices from a bid and an ask quote
+    ///
+    /// Prices are in display units, i.e. quote tokens per base token.
+    ///
+    /// # Params
+    /// size - Base size quoted (raw units)
+    /// bid - ExactIn quote selling `size` base
+    /// ask - ExactOut quote buying `size` base
+    /// base_decimals - Decimals of the base mint
+    /// quote_decimals - Decimals of the quote mint
+    pub fn from_quotes(
+        size: u64,
+        bid: QuoteResponse,
+        ask: QuoteResponse,
+        base_decimals: u8,
+        quote_decimals: u8,
+    ) -> Result<Self, JupiterError> {
+        let base_size = bid.in_token_amount(base_decimals)?.to_f64();
+        let bid_proceeds = bid.out_token_amount(quote_decimals)?.to_f64();
+        let ask_base = ask.out_token_amount(base_decimals)?.to_f64();
+        let ask_cost = ask.in_token_amount(quote_decimals)?.to_f64();
+        if base_size == 0.0 || ask_base == 0.0 {
+            return Err(JupiterError::InvalidInput(
+                "two-sided quote size is zero".to_string(),
+            ));
+        }
+        let bid_price = bid_proceeds / base_size;
+        let ask_price = ask_cost / ask_base;
+        let mid_price = (bid_price + ask_price) / 2.0;
+        let spread_bps = if mid_price > 0.0 {
+            (ask_price - bid_price) / mid_price * 10_000.0
+        } else {
+            0.0
+        };
+        Ok(Self {
+            base_mint: bid.input_mint.clone(),
+            quote_mint: bid.output_mint.clone(),
+            size,
+            bid,
+            ask,
+            bid_price,
+            ask_price,
+            mid_price,
+            spread_bps,
+            timestamp: SystemTime::now(),
+        })
+    }
+
+    /// Older of the two quotes' context slots
+    pub fn context_slot(&self) -> u64 {
+        self.bid.context_slot.min(self.ask.context_slot)
+    }
+}
@@ -0,0 +1,429 @@
+/// Metrics module.
+/// Per-endpoint request counts, error rates and latency histograms recorded by the client.
+use std::{
+    collections::{HashMap, VecDeque},
+    future::Future,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    fees::percentiles,
+    global::{LATENCY_BUCKETS_MS, QUOTE_TELEMETRY_WINDOW, SLOT_DURATION_MS},
+    types::{JupiterError, QuoteResponse},
+};
+
+/// Latency histogram with fixed millisecond buckets
+#[derive(Debug, Clone, PartialEq)]
+pub struct LatencyHistogram {
+    /// Upper bound of each bucket in milliseconds; the final bucket is unbounded
+    pub bounds_ms: Vec<u64>,
+    /// Number of observations per bucket, one longer than `bounds_ms`
+    pub counts: Vec<u64>,
+    /// Sum of all observed latencies
+    pub sum: Duration,
+    /// Largest observed latency
+    pub max: Duration,
+}
+
+impl Default for LatencyHistogram {
+    fn default() -> Self {
+        Self {
+            bounds_ms: LATENCY_BUCKETS_MS.to_vec(),
+            counts: vec![0; LATENCY_BUCKETS_MS.len() + 1],
+            sum: Duration::ZERO,
+            max: Duration::ZERO,
+        }
+    }
+}
+
+impl LatencyHistogram {
+    /// Records a single latency observation
+    pub fn observe(&mut self, latency: Duration) {
+        let ms = latency.as_millis() as u64;
+        let bucket = self
+            .bounds_ms
+            .iter()
+            .position(|bound| ms <= *bound)
+            .unwrap_or(self.bounds_ms.len());
+        self.counts[bucket] += 1;
+        self.sum += latency;
+        self.max = self.max.max(latency);
+    }
+
+    /// Total number of observations
+    pub fn count(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+
+    /// Mean latency, if any observations exist
+    pub fn mean(&self) -> Option<Duration> {
+        let count = self.count();
+        (count > 0).then(|| self.sum / count as u32)
+    }
+
+    /// Upper bound of the bucket containing the `q` quantile (0.0 - 1.0)
+    ///
+    /// Observations in the unbounded bucket report the maximum latency.
+    pub fn quantile(&self, q: f64) -> Option<Duration> {
+        let count = self.count();
+        if count == 0 {
+            return None;
+        }
+        let rank = ((q.clamp(0.0, 1.0) * count as f64).ceil() as u64).max(1);
+        let mut seen = 0;
+        for (i, bucket_count) in self.counts.iter().enumerate() {
+            seen += bucket_count;
+            if seen >= rank {
+                return Some(match self.bounds_ms.get(i) {
+                    Some(bound) => Duration::from_millis(*bound).min(self.max),
+                    None => self.max,
+                });
+            }
+        }
+        Some(self.max)
+    }
+}
+
+/// Metrics recorded for a single endpoint
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EndpointMetrics {
+    /// Number of requests sent
+    pub requests: u64,
+    /// Number of requests that failed (transport error or non-success status)
+    pub errors: u64,
+    pub latency: LatencyHistogram,
+}
+
+impl EndpointMetrics {
+    /// Fraction of requests that failed (0.0 - 1.0)
+    pub fn error_rate(&self) -> f64 {
+        if self.requests == 0 {
+            0.0
+        } else {
+            self.errors as f64 / self.requests as f64
+        }
+    }
+}
+
+/// Point-in-time copy of the client metrics
+#[derive(Debug, Clone)]
+pub struct MetricsSnapshot {
+    /// When recording of this snapshot's window started
+    pub since: Instant,
+    /// Metrics keyed by endpoint name, e.g. `quote`, `swap`, `rpc:send_transaction`
+    pub endpoints: HashMap<String, EndpointMetrics>,
+}
+
+impl MetricsSnapshot {
+    /// Metrics of a single endpoint
+    pub fn endpoint(&self, name: &str) -> Option<&EndpointMetrics> {
+        self.endpoints.get(name)
+    }
+
+    /// Total requests across all endpoints
+    pub fn total_requests(&self) -> u64 {
+        self.endpoints
+            .values()
+            .map(|metrics| metrics.requests)
+            .sum()
+    }
+
+    /// Total errors across all endpoints
+    pub fn total_errors(&self) -> u64 {
+        self.endpoints.values().map(|metrics| metrics.errors).sum()
+    }
+}
+
+#[derive(Debug)]
+struct MetricsState {
+    since: Instant,
+    endpoints: HashMap<String, EndpointMetrics>,
+}
+
+/// Metrics registry shared by a client and its clones
+#[derive(Debug, Clone)]
+pub struct Metrics {
+    state: Arc<Mutex<MetricsState>>,
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(MetricsState {
+                since: Instant::now(),
+                endpoints: HashMap::new(),
+            })),
+        }
+    }
+}
+
+impl Metrics {
+    /// Records the outcome of a single request
+    ///
+    /// # Params
+    /// endpoint - Endpoint name
+    /// latency - Time taken by the request
+    /// success - Whether the request succeeded
+    pub fn record(&self, endpoint: &str, latency: Duration, success: bool) {
+        let mut state = self.state.lock().unwrap();
+        let metrics = state.endpoints.entry(endpoint.to_string()).or_default();
+        metrics.requests += 1;
+        if !success {
+            metrics.errors += 1;
+        }
+        metrics.latency.observe(latency);
+    }
+
+    /// Runs a fallible operation and records its latency and outcome under `endpoint`
+    pub async fn observe<T, Fut>(&self, endpoint: &str, operation: Fut) -> Result<T, JupiterError>
+    where
+        Fut: Future<Output = Result<T, JupiterError>>,
+    {
+        let started = Instant::now();
+        let result = operation.await;
+        self.record(endpoint, started.elapsed(), result.is_ok());
+        result
+    }
+
+    /// Copies the current metrics
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        let state = self.state.lock().unwrap();
+        MetricsSnapshot {
+            since: state.since,
+            endpoints: state.endpoints.clone(),
+        }
+    }
+
+    /// Clears all metrics and starts a new window
+    pub fn reset(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.since = Instant::now();
+        state.endpoints.clear();
+    }
+
+    /// Returns the current metrics and starts a new window
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::metrics::Metrics;
+    /// use std::time::Duration;
+    ///
+    /// let metrics = Metrics::default();
+    /// metrics.record("quote", Duration::from_millis(120), true);
+    /// metrics.record("quote", Duration::from_millis(80), false);
+    /// let snapshot = metrics.take_snapshot();
+    /// assert_eq!(snapshot.endpoint("quote").unwrap().error_rate(), 0.5);
+    /// assert_eq!(metrics.snapshot().total_requests(), 0);
+    /// ```
+    pub fn take_snapshot(&self) -> MetricsSnapshot {
+        let mut state = self.state.lock().unwrap();
+        let snapshot = MetricsSnapshot {
+            since: state.since,
+            endpoints: std::mem::take(&mut state.endpoints),
+        };
+        state.since = Instant::now();
+        snapshot
+    }
+}
+
+#[cfg(feature = "metrics")]
+impl MetricsSnapshot {
+    /// Renders the metrics in the Prometheus text exposition format
+    ///
+    /// Use with `Metrics::snapshot` so counters stay monotonic between scrapes.
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::metrics::Metrics;
+    /// use std::time::Duration;
+    ///
+    /// let metrics = Metrics::default();
+    /// metrics.record("quote", Duration::from_millis(42), true);
+    /// let body = metrics.snapshot().to_prometheus();
+    /// assert!(body.contains("jupiter_sdk_requests_total{endpoint=\"quote\"} 1"));
+    /// ```
+    pub fn to_prometheus(&self) -> String {
+        use std::fmt::Write;
+
+        let mut endpoints: Vec<(&String, &EndpointMetrics)> = self.endpoints.iter().collect();
+        endpoints.sort_by(|a, b| a.0.cmp(b.0));
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "# HELP jupiter_sdk_requests_total Requests sent per endpoint"
+        );
+        let _ = writeln!(out, "# TYPE jupiter_sdk_requests_total counter");
+        for (endpoint, metrics) in &endpoints {
+            let _ = writeln!(
+                out,
+                "jupiter_sdk_requests_total{{endpoint=\"{}\"}} {}",
+                escape_label(endpoint),
+                metrics.requests
+            );
+        }
+        let _ = writeln!(
+            out,
+            "# HELP jupiter_sdk_errors_total Failed requests per endpoint"
+        );
+        let _ = writeln!(out, "# TYPE jupiter_sdk_errors_total counter");
+        for (endpoint, metrics) in &endpoints {
+            let _ = writeln!(
+                out,
+                "jupiter_sdk_errors_total{{endpoint=\"{}\"}} {}",
+                escape_label(endpoint),
+                metrics.errors
+            );
+        }
+        let _ = writeln!(
+            out,
+            "# HELP jupiter_sdk_request_duration_seconds Request latency per endpoint"
+        );
+        let _ = writeln!(out, "# TYPE jupiter_sdk_request_duration_seconds histogram");
+        for (endpoint, metrics) in &endpoints {
+            let endpoint = escape_label(endpoint);
+            let histogram = &metrics.latency;
+            let mut cumulative = 0;
+            for (i, count) in histogram.counts.iter().enumerate() {
+                cumulative += count;
+                let le = match histogram.bounds_ms.get(i) {
+                    Some(bound) => (*bound as f64 / 1000.0).to_string(),
+                    None => "+Inf".to_string(),
+                };
+                let _ = writeln!(
+                    out,
+                    "jupiter_sdk_request_duration_seconds_bucket{{endpoint=\"{}\",le=\"{}\"}} {}",
+                    endpoint, le, cumulative
+                );
+            }
+            let _ = writeln!(
+                out,
+                "jupiter_sdk_request_duration_seconds_sum{{endpoint=\"{}\"}} {}",
+                endpoint,
+                histogram.sum.as_secs_f64()
+            );
+            let _ = writeln!(
+                out,
+                "jupiter_sdk_request_duration_seconds_count{{endpoint=\"{}\"}} {}",
+                endpoint,
+                histogram.count()
+            );
+        }
+        out
+    }
+}
+
+/// Rolling statistics of recent quotes' router metadata
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QuoteTelemetryStats {
+    /// Number of quotes in the window
+    pub samples: usize,
+    /// Highest `context_slot` seen
+    pub latest_context_slot: u64,
+    /// Median and 90th percentile of slots a quote's `context_slot` trailed the
+    /// projected chain tip when it was received
+    pub slot_lag_p50: u64,
+    pub slot_lag_p90: u64,
+    pub slot_lag_max: u64,
+    /// Median and 90th percentile of the router's `time_taken`
+    pub time_taken_p50: Duration,
+    pub time_taken_p90: Duration,
+    pub time_taken_max: Duration,
+}
+
+/// Rolling tracker of quote staleness and router latency
+///
+/// The chain tip is projected from the newest `context_slot` seen and the time since,
+/// so no RPC calls are needed; a growing slot lag or `time_taken` signals a degraded
+/// upstream router. Clones share the same samples.
+#[derive(Debug, Clone)]
+pub struct QuoteTelemetry {
+    window: usize,
+    state: Arc<Mutex<QuoteTelemetryState>>,
+}
+
+#[derive(Debug, Default)]
+struct QuoteTelemetryState {
+    /// Newest context slot and when it was received
+    tip: Option<(u64, Instant)>,
+    /// Slot lag and time taken in microseconds per quote
+    samples: VecDeque<(u64, u64)>,
+}
+
+impl Default for QuoteTelemetry {
+    fn default() -> Self {
+        Self::new(QUOTE_TELEMETRY_WINDOW)
+    }
+}
+
+impl QuoteTelemetry {
+    /// Creates a tracker keeping the last `window` quotes
+    pub fn new(window: usize) -> Self {
+        Self {
+            window: window.max(1),
+            state: Arc::new(Mutex::new(QuoteTelemetryState::default())),
+        }
+    }
+
+    /// Records a received quote
+    pub fn record(&self, quote: &QuoteResponse) {
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap();
+        let projected_tip = state
+            .tip
+            .map(|(slot, at)| slot + (now.duration_since(at).as_millis() as u64 / SLOT_DURATION_MS))
+            .unwrap_or(quote.context_slot);
+        if projected_tip <= quote.context_slot {
+            state.tip = Some((quote.context_slot, now));
+        }
+        let lag = projected_tip.saturating_sub(quote.context_slot);
+        let time_taken = (quote.time_taken.max(0.0) * 1_000_000.0) as u64;
+        if state.samples.len() == self.window {
+            state.samples.pop_front();
+        }
+        state.samples.push_back((lag, time_taken));
+    }
+
+    /// Slots `quote` trails the projected chain tip now
+    pub fn slot_lag(&self, quote: &QuoteResponse) -> u64 {
+        let state = self.state.lock().unwrap();
+        state
+            .tip
+            .map(|(slot, at)| slot + (at.elapsed().as_millis() as u64 / SLOT_DURATION_MS))
+            .unwrap_or(quote.context_slot)
+            .saturating_sub(quote.context_slot)
+    }
+
+    /// Current rolling statistics
+    pub fn stats(&self) -> QuoteTelemetryStats {
+        let state = self.state.lock().unwrap();
+        let lags: Vec<u64> = state.samples.iter().map(|(lag, _)| *lag).collect();
+        let times: Vec<u64> = state.samples.iter().map(|(_, time)| *time).collect();
+        let [slot_lag_p50, slot_lag_p90, slot_lag_max] = percentiles(lags, [0.5, 0.9, 1.0]);
+        let [time_p50, time_p90, time_max] = percentiles(times, [0.5, 0.9, 1.0]);
+        QuoteTelemetryStats {
+            samples: state.samples.len(),
+            latest_context_slot: state.tip.map(|(slot, _)| slot).unwrap_or(0),
+            slot_lag_p50,
+            slot_lag_p90,
+            slot_lag_max,
+            time_taken_p50: Duration::from_micros(time_p50),
+            time_taken_p90: Duration::from_micros(time_p90),
+            time_taken_max: Duration::from_micros(time_max),
+        }
+    }
+
+    /// Drops all samples
+    pub fn reset(&self) {
+        *self.state.lock().unwrap() = QuoteTelemetryState::default();
+    }
+}
+
+/// Escapes a Prometheus label value
+#[cfg(feature = "metrics")]
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
@@ -0,0 +1,140 @@
+/// MEV protection module.
+/// Sends swap transactions only through private relays (Jito block engines or private
+/// RPC endpoints), keeping them off public RPC nodes until they land.
+use serde_json::json;
+
+use crate::types::{AdvancedSwapConfig, JupiterError};
+
+/// Hosts of public RPC endpoints that must not be used as private relays
+const PUBLIC_RPC_HOSTS: [&str; 3] = [
+    "api.mainnet-beta.solana.com",
+    "api.devnet.solana.com",
+    "api.testnet.solana.com",
+];
+
+/// Private transaction relay
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PrivateRelay {
+    /// Jito block engine, e.g. `https://mainnet.block-engine.jito.wtf`
+    ///
+    /// With `bundle_only`, the engine only forwards the transaction as a bundle, never
+    /// to validators directly. The transaction must tip a Jito tip account to land.
+    Jito { url: String, bundle_only: bool },
+    /// Private or protected RPC endpoint accepting `sendTransaction`
+    Rpc { url: String },
+}
+
+impl PrivateRelay {
+    /// Jito block engine in bundle-only mode
+    pub fn jito(url: &str) -> Self {
+        PrivateRelay::Jito {
+            url: url.trim_end_matches('/').to_string(),
+            bundle_only: true,
+        }
+    }
+
+    pub fn rpc(url: &str) -> Self {
+        PrivateRelay::Rpc {
+            url: url.to_string(),
+        }
+    }
+
+    pub fn url(&self) -> &str {
+        match self {
+            PrivateRelay::Jito { url, .. } | PrivateRelay::Rpc { url } => url,
+        }
+    }
+
+    /// Endpoint receiving `sendTransaction`
+    pub(crate) fn send_url(&self) -> String {
+        match self {
+            PrivateRelay::Jito { url, bundle_only } => format!(
+                "{}/api/v1/transactions{}",
+                url,
+                if *bundle_only { "?bundleOnly=true" } else { "" }
+            ),
+            PrivateRelay::Rpc { url } => url.clone(),
+        }
+    }
+
+    /// JSON-RPC `sendTransaction` body of a base64 transaction
+    pub(crate) fn send_body(&self, transaction: &str) -> serde_json::Value {
+        json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "sendTransaction",
+            "params": [transaction, {"encoding": "base64", "skipPreflight": true}],
+        })
+    }
+}
+
+/// Execution mode avoiding public RPC nodes
+///
+/// Transactions, including rebroadcasts, go to every relay and never to the client's
+/// Solana RPC, which is still used to monitor them. A send succeeds if any relay
+/// accepts the transaction.
+///
+/// # Example
+/// ```rust
+/// use jup_sdk::{
+///     mev::{MevProtection, PrivateRelay},
+///     types::AdvancedSwapConfig,
+/// };
+///
+/// let config = AdvancedSwapConfig {
+///     mev_protection: Some(MevProtection::new(vec![PrivateRelay::jito(
+///         "https://mainnet.block-engine.jito.wtf",
+///     )])),
+///     ..AdvancedSwapConfig::default()
+/// };
+/// assert!(config.mev_protection.as_ref().unwrap().warnings(&config).is_empty());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MevProtection {
+    pub relays: Vec<PrivateRelay>,
+}
+
+impl MevProtection {
+    pub fn new(relays: Vec<PrivateRelay>) -> Self {
+        Self { relays }
+    }
+
+    /// Fails when no relay is configured, since sending would be impossible
+    pub fn validate(&self) -> Result<(), JupiterError> {
+        if self.relays.is_empty() {
+            return Err(JupiterError::ValidationError(
+                "MEV protection needs at least one private relay".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Ways `config` would expose the swap publicly despite the protection
+    pub fn warnings(&self, config: &AdvancedSwapConfig) -> Vec<String> {
+        let mut warnings = Vec::new();
+        for relay in &self.relays {
+            let url = relay.url();
+            if !url.starts_with("https://") {
+                warnings.push(format!("relay {} is not reached over https", url));
+            }
+            if PUBLIC_RPC_HOSTS.iter().any(|host| url.contains(host)) {
+                warnings.push(format!("relay {} is a public RPC endpoint", url));
+            }
+            if let PrivateRelay::Jito {
+                bundle_only: false, ..
+            } = relay
+            {
+                warnings.push(format!(
+                    "Jito relay {} forwards the transaction outside bundles",
+                    url
+                ));
+            }
+        }
+        if config.ensure_destination_ata || config.recipient.is_some() {
+            warnings.push(
+                "destination token account creation is sent through the public RPC".to_string(),
+            );
+        }
+        warnings
+    }
+}
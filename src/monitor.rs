@@ -1,19 +1,48 @@
-use crate::types::JupiterError;
+use crate::dex::DexId;
+use crate::failure::FailureReason;
+use crate::global::{DEFAULT_CONFIRMATION_SECONDS, LATENCY_WINDOW, ROUTE_HOP_OVERHEAD_SECONDS};
+#[cfg(feature = "grpc")]
+use crate::grpc::{GrpcConfig, wait_for_signature};
+use crate::notify::Notifier;
+use crate::tool::{SignatureOrHash, run_cancellable, validate_signature_or_hash};
+use crate::types::{JupiterError, QuoteResponse};
+use serde::{Deserialize, Serialize};
 use solana_client::rpc_config::RpcTransactionConfig;
-use solana_commitment_config::CommitmentConfig;
+use solana_commitment_config::{CommitmentConfig, CommitmentLevel};
 use solana_network_sdk::Solana;
 use solana_sdk::signature::Signature;
-use std::str::FromStr;
+use solana_transaction_status::{
+    TransactionConfirmationStatus, TransactionStatus as SignatureStatus, UiTransactionStatusMeta,
+    option_serializer::OptionSerializer,
+};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use tokio::time;
+use tokio_util::sync::CancellationToken;
 
 /// Configuration for transaction monitoring
 #[derive(Debug, Clone)]
 pub struct TransactionMonitorConfig {
     pub timeout: Duration,
     pub poll_interval: Duration,
+    /// Commitment the transaction must reach, also used for transaction reads;
+    /// `JupiterClient` fills it from `ClientConfig::commitment` when no config is given
     pub commitment: CommitmentConfig,
     pub confirmations_required: u8,
+    /// Searches the ledger history for signature statuses, needed for signatures
+    /// older than the RPC node's recent status cache (about 150 blocks), at the cost
+    /// of a slower lookup
+    pub search_transaction_history: bool,
+    /// Stops monitoring with JupiterError::Cancelled when cancelled
+    pub cancellation_token: Option<CancellationToken>,
+    /// Notified when the transaction reaches each commitment level, is confirmed,
+    /// fails or times out
+    pub notifier: Option<Arc<dyn Notifier>>,
+    /// Yellowstone gRPC endpoint waking the poller as soon as the transaction lands;
+    /// polling continues alone when the stream fails
+    #[cfg(feature = "grpc")]
+    pub grpc: Option<GrpcConfig>,
 }
 
 impl Default for TransactionMonitorConfig {
@@ -23,6 +52,11 @@ impl Default for TransactionMonitorConfig {
             poll_interval: Duration::from_secs(2),
             commitment: CommitmentConfig::confirmed(),
             confirmations_required: 1,
+            search_transaction_history: false,
+            cancellation_token: None,
+            notifier: None,
+            #[cfg(feature = "grpc")]
+            grpc: None,
         }
     }
 }
@@ -37,6 +71,47 @@ pub enum TransactionStatus {
     Timeout,
 }
 
+/// Commitment level a transaction has reached, in ladder order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CommitmentStage {
+    Processed,
+    Confirmed,
+    Finalized,
+}
+
+impl CommitmentStage {
+    /// Stages up to and including `self`
+    pub fn ladder(&self) -> &'static [CommitmentStage] {
+        const LADDER: [CommitmentStage; 3] = [
+            CommitmentStage::Processed,
+            CommitmentStage::Confirmed,
+            CommitmentStage::Finalized,
+        ];
+        &LADDER[..=*self as usize]
+    }
+}
+
+impl From<CommitmentLevel> for CommitmentStage {
+    fn from(level: CommitmentLevel) -> Self {
+        match level {
+            CommitmentLevel::Processed => CommitmentStage::Processed,
+            CommitmentLevel::Confirmed => CommitmentStage::Confirmed,
+            CommitmentLevel::Finalized => CommitmentStage::Finalized,
+        }
+    }
+}
+
+impl From<&TransactionConfirmationStatus> for CommitmentStage {
+    fn from(status: &TransactionConfirmationStatus) -> Self {
+        match status {
+            TransactionConfirmationStatus::Processed => CommitmentStage::Processed,
+            TransactionConfirmationStatus::Confirmed => CommitmentStage::Confirmed,
+            TransactionConfirmationStatus::Finalized => CommitmentStage::Finalized,
+        }
+    }
+}
+
 /// Transaction monitoring result
 #[derive(Debug, Clone)]
 pub struct TransactionMonitorResult {
@@ -47,6 +122,40 @@ pub struct TransactionMonitorResult {
     pub confirmations: Option<u8>,
     pub logs: Vec<String>,
     pub error: Option<String>,
+    /// Time from the start of monitoring until the transaction was confirmed
+    pub confirmation_time: Option<Duration>,
+    /// Highest commitment level observed
+    pub commitment_stage: Option<CommitmentStage>,
+    /// Compute units consumed, from the transaction meta
+    pub compute_units_consumed: Option<u64>,
+    /// Transaction fee paid in lamports, from the transaction meta
+    pub fee: Option<u64>,
+    /// Classified cause of an on-chain failure, from `error` and `logs`
+    pub failure_reason: Option<FailureReason>,
+}
+
+/// Fields of a transaction's status meta the monitor reports
+#[derive(Debug, Clone, Default)]
+struct TransactionMeta {
+    logs: Vec<String>,
+    compute_units_consumed: Option<u64>,
+    fee: Option<u64>,
+}
+
+impl From<UiTransactionStatusMeta> for TransactionMeta {
+    fn from(meta: UiTransactionStatusMeta) -> Self {
+        Self {
+            logs: match meta.log_messages {
+                OptionSerializer::Some(logs) => logs,
+                _ => Vec::new(),
+            },
+            compute_units_consumed: match meta.compute_units_consumed {
+                OptionSerializer::Some(units) => Some(units),
+                _ => None,
+            },
+            fee: Some(meta.fee),
+        }
+    }
 }
 
 /// Transaction monitor for tracking Solana transaction status
@@ -76,6 +185,7 @@ impl Monitor {
     /// Ok(())
     /// }
     /// ```
+    #[tracing::instrument(skip(self, solana, config))]
     pub async fn monitor_transaction_status(
         &self,
         signature: &str,
@@ -83,20 +193,48 @@ impl Monitor {
         config: Option<TransactionMonitorConfig>,
     ) -> Result<TransactionMonitorResult, JupiterError> {
         let config = config.unwrap_or_default();
-        let signature = Signature::from_str(signature)
-            .map_err(|e| JupiterError::InvalidInput(e.to_string()))?;
+        let signature = match validate_signature_or_hash(signature) {
+            Ok(SignatureOrHash::Signature(signature)) => signature,
+            Ok(SignatureOrHash::Hash(_)) => {
+                return Err(JupiterError::InvalidInput(format!(
+                    "{} is a hash, not a transaction signature",
+                    signature
+                )));
+            }
+            Err(e) => return Err(JupiterError::InvalidInput(e)),
+        };
         let start = std::time::Instant::now();
+        let token = config.cancellation_token.as_ref();
+        let wait_for_finalized = config.commitment.is_finalized();
+        let mut reported_stage: Option<CommitmentStage> = None;
+        #[cfg(feature = "grpc")]
+        let mut grpc_landed = std::pin::pin!(futures::FutureExt::fuse(Self::grpc_landed(
+            &config, &signature
+        )));
         while start.elapsed() < config.timeout {
-            match self
-                .check_transaction_status(&signature, solana, &config)
-                .await
+            match run_cancellable(
+                token,
+                self.check_transaction_status(&signature, solana, &config),
+            )
+            .await?
             {
-                Ok(Some(result)) => {
-                    if result.status == TransactionStatus::Confirmed
-                        || result.status == TransactionStatus::Finalized
-                    {
+                Ok(Some(mut result)) => {
+                    tracing::debug!(status = ?result.status, slot = result.slot, "transaction status");
+                    if result.status != TransactionStatus::Failed {
+                        Self::report_stages(&config, &result, &mut reported_stage).await;
+                    }
+                    let done = if wait_for_finalized {
+                        result.status == TransactionStatus::Finalized
+                    } else {
+                        result.status == TransactionStatus::Confirmed
+                            || result.status == TransactionStatus::Finalized
+                    };
+                    if done {
+                        result.confirmation_time = Some(start.elapsed());
+                        Self::notify_result(&config, &result).await;
                         return Ok(result);
                     } else if result.status == TransactionStatus::Failed {
+                        Self::notify_result(&config, &result).await;
                         return Ok(result);
                     }
                     // Continue to wait for confirmation
@@ -106,13 +244,23 @@ impl Monitor {
                 }
                 Err(e) => {
                     // Log the error but continue to retry.
-                    eprintln!("Error checking transaction status: {}", e);
+                    tracing::warn!(error = %e, "error checking transaction status");
                 }
             }
-            time::sleep(config.poll_interval).await;
+            #[cfg(feature = "grpc")]
+            run_cancellable(token, async {
+                tokio::select! {
+                    _ = time::sleep(config.poll_interval) => {}
+                    _ = &mut grpc_landed => {}
+                }
+            })
+            .await?;
+            #[cfg(not(feature = "grpc"))]
+            run_cancellable(token, time::sleep(config.poll_interval)).await?;
         }
         // timeout
-        Ok(TransactionMonitorResult {
+        tracing::warn!("transaction monitoring timed out");
+        let result = TransactionMonitorResult {
             signature: signature.to_string(),
             status: TransactionStatus::Timeout,
             slot: 0,
@@ -120,7 +268,80 @@ impl Monitor {
             confirmations: None,
             logs: Vec::new(),
             error: Some("Transaction monitoring timeout".to_string()),
-        })
+            confirmation_time: None,
+            commitment_stage: reported_stage,
+            compute_units_consumed: None,
+            fee: None,
+            failure_reason: None,
+        };
+        Self::notify_result(&config, &result).await;
+        Ok(result)
+    }
+
+    /// Resolves once the gRPC stream reports the transaction; never resolves without
+    /// a gRPC endpoint or after a stream failure
+    #[cfg(feature = "grpc")]
+    async fn grpc_landed(config: &TransactionMonitorConfig, signature: &Signature) {
+        if let Some(grpc) = &config.grpc {
+            match wait_for_signature(grpc, signature, config.commitment).await {
+                Ok(update) => {
+                    tracing::debug!(slot = update.slot, "transaction reported by grpc");
+                    return;
+                }
+                Err(e) => tracing::warn!(error = %e, "grpc stream failed, polling only"),
+            }
+        }
+        std::future::pending().await
+    }
+
+    /// Sends the final monitoring outcome to the configured notifier
+    ///
+    /// Delivery failures are logged and do not affect the monitoring result.
+    async fn notify_result(config: &TransactionMonitorConfig, result: &TransactionMonitorResult) {
+        let Some(notifier) = &config.notifier else {
+            return;
+        };
+        let delivery = match result.status {
+            TransactionStatus::Confirmed | TransactionStatus::Finalized => {
+                notifier.on_fill(&result.signature, result.slot).await
+            }
+            TransactionStatus::Failed | TransactionStatus::Timeout => {
+                let error = result.error.as_deref().unwrap_or("transaction failed");
+                notifier.on_failure(Some(&result.signature), error).await
+            }
+            TransactionStatus::Pending => Ok(()),
+        };
+        if let Err(e) = delivery {
+            tracing::warn!(error = %e, "failed to deliver notification");
+        }
+    }
+
+    /// Reports each commitment level reached since the last check, in ladder order
+    ///
+    /// Levels skipped between two polls are reported too, so consumers always see
+    /// processed, confirmed and finalized in sequence.
+    async fn report_stages(
+        config: &TransactionMonitorConfig,
+        result: &TransactionMonitorResult,
+        reported: &mut Option<CommitmentStage>,
+    ) {
+        let Some(stage) = result.commitment_stage else {
+            return;
+        };
+        for &level in stage.ladder() {
+            if reported.is_some_and(|reported| level <= reported) {
+                continue;
+            }
+            *reported = Some(level);
+            tracing::debug!(stage = ?level, slot = result.slot, "commitment level reached");
+            if let Some(notifier) = &config.notifier
+                && let Err(e) = notifier
+                    .on_commitment(&result.signature, level, result.slot)
+                    .await
+            {
+                tracing::warn!(error = %e, "failed to deliver notification");
+            }
+        }
     }
 
     /// Check the status of a single transaction
@@ -130,59 +351,60 @@ impl Monitor {
         solana: &Solana,
         config: &TransactionMonitorConfig,
     ) -> Result<Option<TransactionMonitorResult>, JupiterError> {
-        let statuses = solana
+        let rpc_client = solana
             .client
             .clone()
-            .ok_or(JupiterError::Error("solana client error".to_string()))?
-            .get_signature_statuses(&[*signature])
-            .await
-            .map_err(|e| JupiterError::NetworkError(e.to_string()))?;
+            .ok_or(JupiterError::Error("solana client error".to_string()))?;
+        let statuses = if config.search_transaction_history {
+            rpc_client
+                .get_signature_statuses_with_history(&[*signature])
+                .await
+        } else {
+            rpc_client.get_signature_statuses(&[*signature]).await
+        }
+        .map_err(|e| JupiterError::NetworkError(e.to_string()))?;
         if let Some(status) = statuses.value.get(0).and_then(|s| s.as_ref()) {
-            let slot = status.slot;
-            // get transcation lgos
-            let logs = self
-                .get_transaction_logs(signature, solana)
+            // Below confirmed, transaction reads at the monitor's commitment and the
+            // block time of the slot fail, so the status alone reports the stage
+            let landed = status
+                .confirmation_status
+                .as_ref()
+                .is_none_or(|stage| CommitmentStage::from(stage) >= CommitmentStage::Confirmed);
+            if !landed {
+                return Ok(Some(Self::status_result(
+                    signature,
+                    status,
+                    config,
+                    TransactionMeta::default(),
+                    None,
+                )));
+            }
+            // get transcation logs, compute units and fee
+            let meta = self
+                .get_transaction_meta(signature, solana, config)
                 .await
                 .map_err(|e| JupiterError::Error(format!("get transcation logs error:{:?}", e)))?
-                .unwrap();
-            // Determine transaction status
-            let transaction_status = if status.err.is_some() {
-                TransactionStatus::Failed
-            } else if status.confirmations.is_none() {
-                // No confirmation number indicates final confirmation.
-                TransactionStatus::Finalized
-            } else if status
-                .confirmations
-                .map(|c| c >= config.confirmations_required.into())
-                .unwrap_or(false)
-            {
-                TransactionStatus::Confirmed
-            } else {
-                TransactionStatus::Pending
-            };
-            // get block time
-            let block_time = if slot > 0 {
+                .unwrap_or_default();
+            // get block time; getBlockTime takes no commitment, the slot is only read
+            // once the signature status is known
+            let block_time = if status.slot > 0 {
                 solana
                     .client
                     .clone()
                     .ok_or(JupiterError::Error("get block time error".to_string()))?
-                    .get_block_time(slot)
+                    .get_block_time(status.slot)
                     .await
                     .map_err(|e| JupiterError::Error(format!("get block time error:{:?}", e)))?
             } else {
                 0
             };
-            let result = TransactionMonitorResult {
-                signature: signature.to_string(),
-                status: transaction_status,
-                slot,
-                block_time: Some(block_time),
-                confirmations: status.confirmations.map(|c| c as u8),
-                logs: logs,
-                error: status.err.clone().map(|e| e.to_string()),
-            };
-
-            return Ok(Some(result));
+            return Ok(Some(Self::status_result(
+                signature,
+                status,
+                config,
+                meta,
+                Some(block_time),
+            )));
         }
         if let Ok(Some(result)) = self.check_via_transaction(signature, solana, config).await {
             return Ok(Some(result));
@@ -190,6 +412,54 @@ impl Monitor {
         Ok(None)
     }
 
+    /// Builds a monitoring result from a signature status
+    fn status_result(
+        signature: &Signature,
+        status: &SignatureStatus,
+        config: &TransactionMonitorConfig,
+        meta: TransactionMeta,
+        block_time: Option<i64>,
+    ) -> TransactionMonitorResult {
+        // Determine transaction status
+        let stage = status
+            .confirmation_status
+            .as_ref()
+            .map(CommitmentStage::from);
+        let required = CommitmentStage::from(config.commitment.commitment);
+        let transaction_status = if status.err.is_some() {
+            TransactionStatus::Failed
+        } else if stage.is_some_and(|stage| stage < required) {
+            // Below the configured commitment the transaction may still be dropped
+            TransactionStatus::Pending
+        } else if status.confirmations.is_none() {
+            // No confirmation number indicates final confirmation.
+            TransactionStatus::Finalized
+        } else if status
+            .confirmations
+            .map(|c| c >= config.confirmations_required.into())
+            .unwrap_or(false)
+        {
+            TransactionStatus::Confirmed
+        } else {
+            TransactionStatus::Pending
+        };
+        let error = status.err.clone().map(|e| e.to_string());
+        TransactionMonitorResult {
+            signature: signature.to_string(),
+            status: transaction_status,
+            slot: status.slot,
+            block_time,
+            confirmations: status.confirmations.map(|c| c as u8),
+            failure_reason: FailureReason::classify(&meta.logs, error.as_deref()),
+            logs: meta.logs,
+            error,
+            confirmation_time: None,
+            commitment_stage: stage,
+            compute_units_consumed: meta.compute_units_consumed,
+            fee: meta.fee,
+        }
+    }
+
     /// Check the transaction status using get_transaction
     async fn check_via_transaction(
         &self,
@@ -199,7 +469,7 @@ impl Monitor {
     ) -> Result<Option<TransactionMonitorResult>, JupiterError> {
         let transaction_config = RpcTransactionConfig {
             encoding: None,
-            commitment: Some(config.commitment),
+            commitment: Some(transaction_commitment(config.commitment)),
             max_supported_transaction_version: Some(0),
         };
         match solana
@@ -212,15 +482,10 @@ impl Monitor {
             Ok(transaction) => {
                 let slot = transaction.slot;
                 let block_time = transaction.block_time;
-                let logs = transaction
+                let meta: TransactionMeta = transaction
                     .transaction
                     .meta
-                    .and_then(|meta| match meta.log_messages {
-                        solana_transaction_status::option_serializer::OptionSerializer::Some(
-                            logs,
-                        ) => Some(logs),
-                        _ => None,
-                    })
+                    .map(TransactionMeta::from)
                     .unwrap_or_default();
                 let result = TransactionMonitorResult {
                     signature: signature.to_string(),
@@ -228,8 +493,18 @@ impl Monitor {
                     slot,
                     block_time,
                     confirmations: Some(config.confirmations_required),
-                    logs,
+                    logs: meta.logs,
                     error: None,
+                    confirmation_time: None,
+                    // getTransaction only returns transactions at the requested commitment
+                    commitment_stage: Some(if config.commitment.is_finalized() {
+                        CommitmentStage::Finalized
+                    } else {
+                        CommitmentStage::Confirmed
+                    }),
+                    compute_units_consumed: meta.compute_units_consumed,
+                    fee: meta.fee,
+                    failure_reason: None,
                 };
                 Ok(Some(result))
             }
@@ -237,15 +512,16 @@ impl Monitor {
         }
     }
 
-    /// get transca
-    async fn get_transaction_logs(
+    /// Fetches the transaction's logs, compute units consumed and fee
+    async fn get_transaction_meta(
         &self,
         signature: &Signature,
         solana: &Solana,
-    ) -> Result<Option<Vec<String>>, JupiterError> {
+        config: &TransactionMonitorConfig,
+    ) -> Result<Option<TransactionMeta>, JupiterError> {
         let transaction_config = RpcTransactionConfig {
             encoding: None,
-            commitment: None,
+            commitment: Some(transaction_commitment(config.commitment)),
             max_supported_transaction_version: Some(0),
         };
         match solana
@@ -255,17 +531,7 @@ impl Monitor {
             .get_transaction_with_config(signature, transaction_config)
             .await
         {
-            Ok(transaction) => {
-                Ok(transaction
-                    .transaction
-                    .meta
-                    .and_then(|meta| match meta.log_messages {
-                        solana_transaction_status::option_serializer::OptionSerializer::Some(
-                            logs,
-                        ) => Some(logs),
-                        _ => None,
-                    }))
-            }
+            Ok(transaction) => Ok(transaction.transaction.meta.map(TransactionMeta::from)),
             Err(_) => Err(JupiterError::Error(
                 "transaction does not exist".to_string(),
             )),
@@ -313,6 +579,7 @@ impl Monitor {
                 .await
             {
                 Ok(result) => results.push(result),
+                Err(JupiterError::Cancelled(msg)) => return Err(JupiterError::Cancelled(msg)),
                 Err(e) => {
                     results.push(TransactionMonitorResult {
                         signature: signature.clone(),
@@ -322,6 +589,11 @@ impl Monitor {
                         confirmations: None,
                         logs: Vec::new(),
                         error: Some(e.to_string()),
+                        confirmation_time: None,
+                        commitment_stage: None,
+                        compute_units_consumed: None,
+                        fee: None,
+                        failure_reason: None,
                     });
                 }
             }
@@ -329,3 +601,147 @@ impl Monitor {
         Ok(results)
     }
 }
+
+/// Commitment for getTransaction reads, which reject `processed`
+fn transaction_commitment(commitment: CommitmentConfig) -> CommitmentConfig {
+    if commitment.commitment == CommitmentLevel::Processed {
+        CommitmentConfig::confirmed()
+    } else {
+        commitment
+    }
+}
+
+/// Rolling per-DEX confirmation latency tracker
+///
+/// Fed with confirmation times observed by the monitor and used to estimate
+/// route execution time. Clones share the same samples.
+#[derive(Debug, Clone)]
+pub struct LatencyTracker {
+    window: usize,
+    samples: Arc<Mutex<HashMap<DexId, VecDeque<Duration>>>>,
+}
+
+impl Default for LatencyTracker {
+    fn default() -> Self {
+        Self::new(LATENCY_WINDOW)
+    }
+}
+
+impl LatencyTracker {
+    /// Creates a tracker keeping the last `window` samples per DEX
+    pub fn new(window: usize) -> Self {
+        Self {
+            window: window.max(1),
+            samples: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Records a confirmation latency observed for a transaction routed through `dex`
+    pub fn record(&self, dex: &DexId, latency: Duration) {
+        let mut samples = self.samples.lock().unwrap();
+        let entry = samples.entry(dex.clone()).or_default();
+        entry.push_back(latency);
+        while entry.len() > self.window {
+            entry.pop_front();
+        }
+    }
+
+    /// Records a confirmation latency for every DEX used by a route
+    pub fn record_route(&self, route: &QuoteResponse, latency: Duration) {
+        let mut seen: Vec<&DexId> = Vec::new();
+        for plan in &route.route_plan {
+            if !seen.contains(&&plan.swap_info.label) {
+                seen.push(&plan.swap_info.label);
+                self.record(&plan.swap_info.label, latency);
+            }
+        }
+    }
+
+    /// Median confirmation latency observed for `dex`, if any samples exist
+    pub fn median(&self, dex: &DexId) -> Option<Duration> {
+        let samples = self.samples.lock().unwrap();
+        let mut latencies: Vec<Duration> = samples.get(dex)?.iter().copied().collect();
+        if latencies.is_empty() {
+            return None;
+        }
+        latencies.sort();
+        Some(latencies[latencies.len() / 2])
+    }
+
+    /// Estimates the execution time of a route in seconds
+    ///
+    /// Combines the API routing time (`time_taken`), the slowest observed confirmation
+    /// latency among the route's DEXes (or a default when no samples exist), and a
+    /// per-hop overhead.
+    pub fn estimate_route_time(&self, route: &QuoteResponse) -> f64 {
+        let confirmation = route
+            .route_plan
+            .iter()
+            .filter_map(|plan| self.median(&plan.swap_info.label))
+            .map(|latency| latency.as_secs_f64())
+            .reduce(f64::max)
+            .unwrap_or(DEFAULT_CONFIRMATION_SECONDS);
+        route.time_taken.max(0.0)
+            + confirmation
+            + route.route_plan.len() as f64 * ROUTE_HOP_OVERHEAD_SECONDS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notify::NotificationEvent;
+    use async_trait::async_trait;
+
+    #[derive(Debug, Default)]
+    struct RecordingNotifier {
+        events: Mutex<Vec<NotificationEvent>>,
+    }
+
+    #[async_trait]
+    impl Notifier for RecordingNotifier {
+        async fn notify(&self, event: &NotificationEvent) -> Result<(), JupiterError> {
+            self.events.lock().unwrap().push(event.clone());
+            Ok(())
+        }
+    }
+
+    #[allow(deprecated)]
+    #[tokio::test]
+    async fn processed_status_reports_processed_stage() {
+        let notifier = Arc::new(RecordingNotifier::default());
+        let config = TransactionMonitorConfig {
+            notifier: Some(notifier.clone()),
+            ..TransactionMonitorConfig::default()
+        };
+        let status = SignatureStatus {
+            slot: 42,
+            confirmations: Some(0),
+            status: Ok(()),
+            err: None,
+            confirmation_status: Some(TransactionConfirmationStatus::Processed),
+        };
+        let signature = Signature::default();
+        let result = Monitor::status_result(
+            &signature,
+            &status,
+            &config,
+            TransactionMeta::default(),
+            None,
+        );
+        assert_eq!(result.status, TransactionStatus::Pending);
+        assert_eq!(result.commitment_stage, Some(CommitmentStage::Processed));
+
+        let mut reported = None;
+        Monitor::report_stages(&config, &result, &mut reported).await;
+        assert_eq!(reported, Some(CommitmentStage::Processed));
+        assert_eq!(
+            *notifier.events.lock().unwrap(),
+            vec![NotificationEvent::Commitment {
+                signature: signature.to_string(),
+                stage: CommitmentStage::Processed,
+                slot: 42,
+            }]
+        );
+    }
+}
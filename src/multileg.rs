@@ -0,0 +1,102 @@
+/// Multi-leg swap module.
+/// Chains quotes through intermediate mints chosen by the user, e.g. SOL → USDC → BONK,
+/// instead of letting the router pick the path.
+use crate::types::{JupiterError, QuoteResponse, SwapRequest};
+
+/// Quoted legs of a user-defined multi-leg swap
+///
+/// Each leg after the first spends the previous leg's minimum output, so the legs can
+/// run atomically in one transaction whatever the fill within slippage. Any output
+/// above the minimum stays in the intermediate token.
+#[derive(Debug, Clone)]
+pub struct MultiLegPlan {
+    /// Quotes in execution order
+    pub legs: Vec<QuoteResponse>,
+    /// Input amount of the first leg (raw units)
+    pub in_amount: u64,
+    /// Expected output of the last leg (raw units)
+    pub out_amount: u64,
+    /// Minimum output of the last leg after slippage (raw units)
+    pub min_out_amount: u64,
+}
+
+impl MultiLegPlan {
+    /// Builds a plan from quoted legs
+    pub fn new(legs: Vec<QuoteResponse>) -> Result<Self, JupiterError> {
+        let (Some(first), Some(last)) = (legs.first(), legs.last()) else {
+            return Err(JupiterError::InvalidInput(
+                "multi-leg swap needs at least one leg".to_string(),
+            ));
+        };
+        let parse = |amount: &str| {
+            amount
+                .parse::<u64>()
+                .map_err(|e| JupiterError::ParseError(format!("{}: {}", amount, e)))
+        };
+        Ok(Self {
+            in_amount: parse(&first.in_amount)?,
+            out_amount: parse(&last.out_amount)?,
+            min_out_amount: parse(&last.other_amount_threshold)?,
+            legs,
+        })
+    }
+
+    /// Mints traversed by the plan, from input to output
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::{multileg::MultiLegPlan, types::QuoteResponse};
+    ///
+    /// let plan = MultiLegPlan::new(vec![
+    ///     QuoteResponse::new("SOL", "USDC", 1000, 150),
+    ///     QuoteResponse::new("USDC", "BONK", 150, 7000),
+    /// ])?;
+    /// assert_eq!(plan.path(), vec!["SOL", "USDC", "BONK"]);
+    /// # Ok::<(), jup_sdk::types::JupiterError>(())
+    /// ```
+    pub fn path(&self) -> Vec<&str> {
+        let mut path: Vec<&str> = self
+            .legs
+            .iter()
+            .map(|leg| leg.input_mint.as_str())
+            .collect();
+        if let Some(last) = self.legs.last() {
+            path.push(&last.output_mint);
+        }
+        path
+    }
+
+    /// Swap requests of the legs, in order
+    pub fn swap_requests(&self, user_public_key: &str) -> Vec<SwapRequest> {
+        self.legs
+            .iter()
+            .map(|leg| SwapRequest {
+                quote_response: leg.clone(),
+                user_public_key: user_public_key.to_string(),
+                wrap_and_unwrap_sol: Some(true),
+                compute_unit_price: None,
+                prioritization_fee_lamports: None,
+                as_legacy_transaction: None,
+                destination_token_account: None,
+            })
+            .collect()
+    }
+}
+
+/// Checks that every leg starts from the previous leg's output mint
+pub(crate) fn validate_legs(legs: &[(&str, &str)]) -> Result<(), JupiterError> {
+    if legs.is_empty() {
+        return Err(JupiterError::InvalidInput(
+            "multi-leg swap needs at least one leg".to_string(),
+        ));
+    }
+    for pair in legs.windows(2) {
+        if pair[0].1 != pair[1].0 {
+            return Err(JupiterError::InvalidInput(format!(
+                "leg {} → {} doesn't continue from {}",
+                pair[1].0, pair[1].1, pair[0].1
+            )));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,171 @@
+/// Notification module.
+/// Delivers fill, failure and price-alert events to operators, e.g. via HTTP webhooks.
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use crate::{monitor::CommitmentStage, secret::SecretString, types::JupiterError};
+
+/// Event delivered to a `Notifier`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum NotificationEvent {
+    /// A swap transaction was confirmed
+    Filled {
+        signature: String,
+        slot: u64,
+        input_mint: Option<String>,
+        output_mint: Option<String>,
+        /// Output amount received (raw units), if known
+        out_amount: Option<u64>,
+    },
+    /// A swap transaction reached a commitment level
+    Commitment {
+        signature: String,
+        stage: CommitmentStage,
+        slot: u64,
+    },
+    /// A swap failed on chain, timed out or could not be sent
+    Failed {
+        signature: Option<String>,
+        error: String,
+    },
+    /// A token price crossed a threshold
+    PriceAlert {
+        mint: String,
+        price: f64,
+        threshold: f64,
+        /// Whether the price rose above the threshold (false: fell below)
+        above: bool,
+    },
+}
+
+/// Receiver of operational notifications
+///
+/// Only `notify` is required; the other methods are conveniences building events.
+#[async_trait]
+pub trait Notifier: Send + Sync + std::fmt::Debug {
+    /// Delivers an event
+    async fn notify(&self, event: &NotificationEvent) -> Result<(), JupiterError>;
+
+    /// Notifies that a swap transaction was confirmed
+    async fn on_fill(&self, signature: &str, slot: u64) -> Result<(), JupiterError> {
+        self.notify(&NotificationEvent::Filled {
+            signature: signature.to_string(),
+            slot,
+            input_mint: None,
+            output_mint: None,
+            out_amount: None,
+        })
+        .await
+    }
+
+    /// Notifies that a swap transaction reached a commitment level
+    ///
+    /// Called once per level, so UIs can show an optimistic fill at `Processed`
+    /// while risk systems wait for `Finalized`.
+    async fn on_commitment(
+        &self,
+        signature: &str,
+        stage: CommitmentStage,
+        slot: u64,
+    ) -> Result<(), JupiterError> {
+        self.notify(&NotificationEvent::Commitment {
+            signature: signature.to_string(),
+            stage,
+            slot,
+        })
+        .await
+    }
+
+    /// Notifies that a swap failed
+    async fn on_failure(&self, signature: Option<&str>, error: &str) -> Result<(), JupiterError> {
+        self.notify(&NotificationEvent::Failed {
+            signature: signature.map(str::to_string),
+            error: error.to_string(),
+        })
+        .await
+    }
+
+    /// Notifies that a price crossed a threshold
+    async fn on_price_alert(
+        &self,
+        mint: &str,
+        price: f64,
+        threshold: f64,
+    ) -> Result<(), JupiterError> {
+        self.notify(&NotificationEvent::PriceAlert {
+            mint: mint.to_string(),
+            price,
+            threshold,
+            above: price >= threshold,
+        })
+        .await
+    }
+}
+
+/// Notifier posting each event as JSON to an HTTP endpoint
+///
+/// # Example
+/// ```rust
+/// use jup_sdk::notify::WebhookNotifier;
+///
+/// let notifier = WebhookNotifier::new("https://hooks.example.com/jupiter")
+///     .with_header("Authorization", "Bearer secret");
+/// ```
+#[derive(Debug, Clone)]
+pub struct WebhookNotifier {
+    client: Client,
+    url: String,
+    /// Header values may carry credentials, so they are kept as secrets
+    headers: Vec<(String, SecretString)>,
+    timeout: Duration,
+}
+
+impl WebhookNotifier {
+    pub fn new(url: &str) -> Self {
+        Self {
+            client: Client::new(),
+            url: url.to_string(),
+            headers: Vec::new(),
+            timeout: Duration::from_secs(10),
+        }
+    }
+
+    /// Adds a header sent with every request, e.g. for authentication
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers
+            .push((name.to_string(), SecretString::from(value)));
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+#[async_trait]
+impl Notifier for WebhookNotifier {
+    async fn notify(&self, event: &NotificationEvent) -> Result<(), JupiterError> {
+        let mut request = self
+            .client
+            .post(&self.url)
+            .timeout(self.timeout)
+            .json(event);
+        for (name, value) in &self.headers {
+            request = request.header(name, value.expose_secret());
+        }
+        let response = request
+            .send()
+            .await
+            .map_err(|e| JupiterError::NetworkError(e.to_string()))?;
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let message = response.text().await.unwrap_or_default();
+            return Err(JupiterError::from_status(status, message));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,304 @@
+/// Price oracle module.
+/// Cross-checks quoted swap prices against an external oracle such as Pyth.
+use async_trait::async_trait;
+use std::{
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    amount::TokenAmount,
+    types::{JupiterError, QuoteResponse},
+};
+
+/// Oracle price of a token in USD
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OraclePrice {
+    pub price: f64,
+    /// Confidence interval around `price`
+    pub confidence: f64,
+    /// Unix timestamp (seconds) the price was published at
+    pub publish_time: i64,
+}
+
+impl OraclePrice {
+    /// Age of the price relative to the local clock
+    pub fn age(&self) -> Duration {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or(0);
+        Duration::from_secs(now.saturating_sub(self.publish_time).max(0) as u64)
+    }
+}
+
+/// Source of USD token prices
+#[async_trait]
+pub trait PriceOracle: Send + Sync + std::fmt::Debug {
+    /// USD price of a mint
+    async fn price(&self, mint: &str) -> Result<OraclePrice, JupiterError>;
+}
+
+/// Outcome of an oracle cross-check
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OracleCheck {
+    /// Output per input unit implied by the quote
+    pub implied_price: f64,
+    /// Output per input unit implied by the oracle
+    pub oracle_price: f64,
+    /// Absolute deviation of the quote from the oracle in basis points
+    pub deviation_bps: f64,
+}
+
+/// Rejects swaps whose quoted price deviates too far from an oracle
+///
+/// # Example
+/// ```rust
+/// use jup_sdk::oracle::{OracleGuard, PriceOracle};
+/// use std::{sync::Arc, time::Duration};
+///
+/// fn guard(oracle: Arc<dyn PriceOracle>) -> OracleGuard {
+///     OracleGuard::new(oracle, 100).with_max_age(Duration::from_secs(30))
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct OracleGuard {
+    oracle: Arc<dyn PriceOracle>,
+    /// Maximum tolerated deviation in basis points
+    pub max_deviation_bps: u16,
+    /// Oracle prices older than this are rejected
+    pub max_age: Duration,
+}
+
+impl OracleGuard {
+    pub fn new(oracle: Arc<dyn PriceOracle>, max_deviation_bps: u16) -> Self {
+        Self {
+            oracle,
+            max_deviation_bps,
+            max_age: Duration::from_secs(60),
+        }
+    }
+
+    pub fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = max_age;
+        self
+    }
+
+    /// Compares the quote's implied price with the oracle
+    ///
+    /// # Params
+    /// quote - Quote to check
+    /// input_decimals - Decimals of the input mint
+    /// output_decimals - Decimals of the output mint
+    ///
+    /// # Returns
+    /// The check outcome, or JupiterError::ValidationError when the oracle price is
+    /// stale or the deviation exceeds `max_deviation_bps`
+    pub async fn check(
+        &self,
+        quote: &QuoteResponse,
+        input_decimals: u8,
+        output_decimals: u8,
+    ) -> Result<OracleCheck, JupiterError> {
+        let in_amount = quote.in_token_amount(input_decimals)?;
+        let out_amount = quote.out_token_amount(output_decimals)?;
+        if in_amount.is_zero() {
+            return Err(JupiterError::InvalidInput(
+                "quote in_amount is zero".to_string(),
+            ));
+        }
+        let (input_price, output_price) = tokio::try_join!(
+            self.oracle.price(&quote.input_mint),
+            self.oracle.price(&quote.output_mint)
+        )?;
+        for (mint, price) in [
+            (&quote.input_mint, input_price),
+            (&quote.output_mint, output_price),
+        ] {
+            if price.age() > self.max_age {
+                return Err(JupiterError::ValidationError(format!(
+                    "oracle price of {} is {}s old",
+                    mint,
+                    price.age().as_secs()
+                )));
+            }
+            if price.price <= 0.0 {
+                return Err(JupiterError::ValidationError(format!(
+                    "oracle price of {} is not positive",
+                    mint
+                )));
+            }
+        }
+        let check = Self::compare(in_amount, out_amount, input_price.price, output_price.price);
+        if check.deviation_bps > self.max_deviation_bps as f64 {
+            return Err(JupiterError::ValidationError(format!(
+                "quoted price {:.6} deviates {:.0} bps from oracle price {:.6} (max {} bps)",
+                check.implied_price,
+                check.deviation_bps,
+                check.oracle_price,
+                self.max_deviation_bps
+            )));
+        }
+        Ok(check)
+    }
+
+    fn compare(
+        in_amount: TokenAmount,
+        out_amount: TokenAmount,
+        input_usd: f64,
+        output_usd: f64,
+    ) -> OracleCheck {
+        let implied_price = out_amount.to_f64() / in_amount.to_f64();
+        let oracle_price = input_usd / output_usd;
+        OracleCheck {
+            implied_price,
+            oracle_price,
+            deviation_bps: ((implied_price - oracle_price) / oracle_price).abs() * 10_000.0,
+        }
+    }
+}
+
+#[cfg(feature = "pyth")]
+pub use pyth::PythOracle;
+
+#[cfg(feature = "pyth")]
+mod pyth {
+    use async_trait::async_trait;
+    use reqwest::Client;
+    use serde::Deserialize;
+    use std::collections::HashMap;
+
+    use super::{OraclePrice, PriceOracle};
+    use crate::{global::WSOL_MINT, types::JupiterError};
+
+    /// Public Pyth Hermes endpoint
+    const HERMES_URL: &str = "https://hermes.pyth.network";
+
+    /// Default Pyth USD price feeds by mint
+    const DEFAULT_FEEDS: [(&str, &str); 3] = [
+        (
+            WSOL_MINT,
+            "ef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d",
+        ),
+        (
+            "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
+            "eaa020c61cc479712813461ce153894a96a6c00b21ed0cfc2798d1f9a9e9c94a",
+        ),
+        (
+            "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB",
+            "2b89b9dc8fdf9f34709a5b106b472f0f39bb6ca9ce04b0fd7f2e971688e2e53b",
+        ),
+    ];
+
+    #[derive(Deserialize)]
+    struct HermesResponse {
+        parsed: Vec<HermesPriceUpdate>,
+    }
+
+    #[derive(Deserialize)]
+    struct HermesPriceUpdate {
+        price: HermesPrice,
+    }
+
+    #[derive(Deserialize)]
+    struct HermesPrice {
+        price: String,
+        conf: String,
+        expo: i32,
+        publish_time: i64,
+    }
+
+    /// Pyth oracle reading the latest prices from a Hermes endpoint
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::oracle::PythOracle;
+    ///
+    /// let oracle = PythOracle::new().with_feed(
+    ///     "JUPyiwrYJFskUPiHa7hkeR8VUtAeFoSYbKedZNsDvCN",
+    ///     "0a0408d619e9380abad35060f9192039ed5042fa6f82301d0e48bb52be830996",
+    /// );
+    /// ```
+    #[derive(Debug, Clone)]
+    pub struct PythOracle {
+        client: Client,
+        base_url: String,
+        /// Mint to hex price feed ID
+        feeds: HashMap<String, String>,
+    }
+
+    impl Default for PythOracle {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl PythOracle {
+        /// Creates an oracle for the public Hermes endpoint with SOL, USDC and USDT feeds
+        pub fn new() -> Self {
+            Self::with_url(HERMES_URL)
+        }
+
+        /// Creates an oracle for a custom Hermes endpoint
+        pub fn with_url(base_url: &str) -> Self {
+            Self {
+                client: Client::new(),
+                base_url: base_url.trim_end_matches('/').to_string(),
+                feeds: DEFAULT_FEEDS
+                    .iter()
+                    .map(|(mint, feed)| (mint.to_string(), feed.to_string()))
+                    .collect(),
+            }
+        }
+
+        /// Registers the USD price feed of a mint
+        pub fn with_feed(mut self, mint: &str, feed_id: &str) -> Self {
+            self.feeds.insert(
+                mint.to_string(),
+                feed_id.trim_start_matches("0x").to_string(),
+            );
+            self
+        }
+    }
+
+    #[async_trait]
+    impl PriceOracle for PythOracle {
+        async fn price(&self, mint: &str) -> Result<OraclePrice, JupiterError> {
+            let feed_id = self.feeds.get(mint).ok_or_else(|| {
+                JupiterError::InvalidInput(format!("no Pyth price feed for {}", mint))
+            })?;
+            let url = format!("{}/v2/updates/price/latest", self.base_url);
+            let response = self
+                .client
+                .get(&url)
+                .query(&[("ids[]", feed_id.as_str()), ("parsed", "true")])
+                .send()
+                .await
+                .map_err(|e| JupiterError::NetworkError(e.to_string()))?;
+            if !response.status().is_success() {
+                let status = response.status().as_u16();
+                let message = response.text().await.unwrap_or_default();
+                return Err(JupiterError::from_status(status, message));
+            }
+            let body: HermesResponse = response
+                .json()
+                .await
+                .map_err(|e| JupiterError::ParseError(e.to_string()))?;
+            let update = body.parsed.into_iter().next().ok_or_else(|| {
+                JupiterError::ParseError(format!("no Pyth price returned for {}", mint))
+            })?;
+            let scale = 10f64.powi(update.price.expo);
+            let parse = |value: &str| -> Result<f64, JupiterError> {
+                value
+                    .parse::<i64>()
+                    .map(|value| value as f64 * scale)
+                    .map_err(|e| JupiterError::ParseError(e.to_string()))
+            };
+            Ok(OraclePrice {
+                price: parse(&update.price.price)?,
+                confidence: parse(&update.price.conf)?,
+                publish_time: update.price.publish_time,
+            })
+        }
+    }
+}
@@ -0,0 +1,299 @@
+/// Order management module.
+/// Tracks orders through a lifecycle state machine and persists them via `Storage`.
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tokio::sync::RwLock;
+
+use crate::{
+    id::prefixed_id,
+    storage::Storage,
+    types::{JupiterError, QuoteRequest, QuoteResponse},
+};
+
+/// Storage key prefix of persisted orders
+const ORDER_KEY_PREFIX: &str = "order:";
+
+/// Kind of strategy an order belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderKind {
+    /// One-off market swap
+    Swap,
+    Limit,
+    Dca,
+    Twap,
+}
+
+/// Lifecycle state of an order
+///
+/// `Created → Quoted → Submitted → Confirmed | Failed`; orders not yet submitted can
+/// also be re-quoted, cancelled or fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderState {
+    Created,
+    Quoted,
+    Submitted,
+    Confirmed,
+    Failed,
+    Cancelled,
+}
+
+impl OrderState {
+    /// Whether the order can no longer change
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            OrderState::Confirmed | OrderState::Failed | OrderState::Cancelled
+        )
+    }
+
+    /// Whether the state machine allows moving from `self` to `next`
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::order::OrderState;
+    ///
+    /// assert!(OrderState::Quoted.can_transition_to(OrderState::Submitted));
+    /// assert!(!OrderState::Submitted.can_transition_to(OrderState::Cancelled));
+    /// ```
+    pub fn can_transition_to(&self, next: OrderState) -> bool {
+        use OrderState::*;
+        matches!(
+            (self, next),
+            (Created, Quoted | Cancelled | Failed)
+                | (Quoted, Quoted | Submitted | Cancelled | Failed)
+                | (Submitted, Confirmed | Failed)
+        )
+    }
+}
+
+/// State change of an order
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OrderTransition {
+    pub from: OrderState,
+    pub to: OrderState,
+    /// Unix time in milliseconds
+    pub at: u64,
+}
+
+/// Order tracked by an `OrderManager`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Order {
+    pub id: String,
+    pub kind: OrderKind,
+    pub state: OrderState,
+    pub request: QuoteRequest,
+    /// Latest quote the order was priced with
+    pub quote: Option<QuoteResponse>,
+    /// Signature of the submitted transaction
+    pub signature: Option<String>,
+    pub error: Option<String>,
+    /// Unix time in milliseconds
+    pub created_at: u64,
+    /// Unix time in milliseconds
+    pub updated_at: u64,
+    pub history: Vec<OrderTransition>,
+}
+
+impl Order {
+    fn transition(&mut self, next: OrderState) -> Result<(), JupiterError> {
+        if !self.state.can_transition_to(next) {
+            return Err(JupiterError::ValidationError(format!(
+                "order {} cannot move from {:?} to {:?}",
+                self.id, self.state, next
+            )));
+        }
+        let at = now_millis();
+        self.history.push(OrderTransition {
+            from: self.state,
+            to: next,
+            at,
+        });
+        self.state = next;
+        self.updated_at = at;
+        Ok(())
+    }
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// Owns orders and their lifecycle, persisting every change
+///
+/// # Example
+/// ```rust
+/// use jup_sdk::{order::{OrderKind, OrderManager, OrderState}, storage::MemoryStorage, types::QuoteRequest};
+/// use std::sync::Arc;
+///
+/// async fn example(request: QuoteRequest) -> Result<(), jup_sdk::types::JupiterError> {
+/// let manager = OrderManager::load(Arc::new(MemoryStorage::new())).await?;
+/// let order = manager.create(OrderKind::Swap, request).await?;
+/// manager.cancel(&order.id).await?;
+/// assert_eq!(manager.get(&order.id).await.unwrap().state, OrderState::Cancelled);
+/// Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct OrderManager {
+    storage: Arc<dyn Storage>,
+    orders: RwLock<HashMap<String, Order>>,
+}
+
+impl OrderManager {
+    /// Creates a manager, restoring the orders persisted in `storage`
+    pub async fn load(storage: Arc<dyn Storage>) -> Result<Self, JupiterError> {
+        let mut orders = HashMap::new();
+        for (key, value) in storage.list(ORDER_KEY_PREFIX).await? {
+            match serde_json::from_slice::<Order>(&value) {
+                Ok(order) => {
+                    orders.insert(order.id.clone(), order);
+                }
+                Err(e) => tracing::warn!(key = %key, error = %e, "skipping unreadable order"),
+            }
+        }
+        Ok(Self {
+            storage,
+            orders: RwLock::new(orders),
+        })
+    }
+
+    /// Creates an order in the `Created` state
+    pub async fn create(
+        &self,
+        kind: OrderKind,
+        request: QuoteRequest,
+    ) -> Result<Order, JupiterError> {
+        let now = now_millis();
+        let order = Order {
+            id: prefixed_id("ord"),
+            kind,
+            state: OrderState::Created,
+            request,
+            quote: None,
+            signature: None,
+            error: None,
+            created_at: now,
+            updated_at: now,
+            history: Vec::new(),
+        };
+        self.persist(&order).await?;
+        self.orders
+            .write()
+            .await
+            .insert(order.id.clone(), order.clone());
+        Ok(order)
+    }
+
+    /// Records the quote an order will be executed with
+    pub async fn mark_quoted(&self, id: &str, quote: QuoteResponse) -> Result<Order, JupiterError> {
+        self.update(id, OrderState::Quoted, |order| order.quote = Some(quote))
+            .await
+    }
+
+    /// Records the signature of the submitted transaction
+    pub async fn mark_submitted(&self, id: &str, signature: &str) -> Result<Order, JupiterError> {
+        self.update(id, OrderState::Submitted, |order| {
+            order.signature = Some(signature.to_string())
+        })
+        .await
+    }
+
+    pub async fn mark_confirmed(&self, id: &str) -> Result<Order, JupiterError> {
+        self.update(id, OrderState::Confirmed, |_| {}).await
+    }
+
+    pub async fn mark_failed(&self, id: &str, error: &str) -> Result<Order, JupiterError> {
+        self.update(id, OrderState::Failed, |order| {
+            order.error = Some(error.to_string())
+        })
+        .await
+    }
+
+    /// Cancels an order that has not been submitted
+    pub async fn cancel(&self, id: &str) -> Result<Order, JupiterError> {
+        self.update(id, OrderState::Cancelled, |_| {}).await
+    }
+
+    pub async fn get(&self, id: &str) -> Option<Order> {
+        self.orders.read().await.get(id).cloned()
+    }
+
+    /// Orders matching a predicate, oldest first
+    pub async fn list(&self, filter: impl Fn(&Order) -> bool) -> Vec<Order> {
+        let mut orders: Vec<Order> = self
+            .orders
+            .read()
+            .await
+            .values()
+            .filter(|order| filter(order))
+            .cloned()
+            .collect();
+        orders.sort_by(|a, b| a.created_at.cmp(&b.created_at).then(a.id.cmp(&b.id)));
+        orders
+    }
+
+    pub async fn by_state(&self, state: OrderState) -> Vec<Order> {
+        self.list(|order| order.state == state).await
+    }
+
+    pub async fn by_kind(&self, kind: OrderKind) -> Vec<Order> {
+        self.list(|order| order.kind == kind).await
+    }
+
+    /// Orders not yet in a terminal state
+    pub async fn open_orders(&self) -> Vec<Order> {
+        self.list(|order| !order.state.is_terminal()).await
+    }
+
+    /// Removes terminal orders from memory and storage, returning how many were removed
+    pub async fn prune_terminal(&self) -> Result<usize, JupiterError> {
+        let mut orders = self.orders.write().await;
+        let terminal: Vec<String> = orders
+            .values()
+            .filter(|order| order.state.is_terminal())
+            .map(|order| order.id.clone())
+            .collect();
+        for id in &terminal {
+            self.storage
+                .delete(&format!("{}{}", ORDER_KEY_PREFIX, id))
+                .await?;
+            orders.remove(id);
+        }
+        Ok(terminal.len())
+    }
+
+    /// Applies a transition, persisting before the in-memory order changes
+    async fn update(
+        &self,
+        id: &str,
+        next: OrderState,
+        apply: impl FnOnce(&mut Order),
+    ) -> Result<Order, JupiterError> {
+        let mut orders = self.orders.write().await;
+        let mut order = orders
+            .get(id)
+            .cloned()
+            .ok_or_else(|| JupiterError::InvalidInput(format!("unknown order {}", id)))?;
+        order.transition(next)?;
+        apply(&mut order);
+        self.persist(&order).await?;
+        orders.insert(order.id.clone(), order.clone());
+        Ok(order)
+    }
+
+    async fn persist(&self, order: &Order) -> Result<(), JupiterError> {
+        let value = serde_json::to_vec(order).map_err(|e| JupiterError::Error(e.to_string()))?;
+        self.storage
+            .put(&format!("{}{}", ORDER_KEY_PREFIX, order.id), value)
+            .await
+    }
+}
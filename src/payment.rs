@@ -0,0 +1,74 @@
+/// Payment module.
+/// Exact-output payments funded by whichever accepted input token is cheapest, e.g.
+/// paying 25 USDC with any token the payer holds.
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{
+    monitor::{TransactionMonitorResult, TransactionStatus},
+    types::QuoteResponse,
+};
+
+/// ExactOut quote of one accepted input token
+#[derive(Debug, Clone)]
+pub struct PaymentOption {
+    pub input_mint: String,
+    pub quote: QuoteResponse,
+    /// USD value of the quoted input, `None` without a price for the input mint
+    pub cost_usd: Option<f64>,
+}
+
+impl PaymentOption {
+    /// Quoted input amount (raw units)
+    pub fn in_amount(&self) -> u64 {
+        self.quote.in_amount.parse().unwrap_or(0)
+    }
+
+    /// Maximum input after slippage (raw units)
+    pub fn max_in_amount(&self) -> u64 {
+        self.quote.other_amount_threshold.parse().unwrap_or(0)
+    }
+}
+
+/// Outcome of `JupiterClient::pay_exact`
+#[derive(Debug, Clone)]
+pub struct PaymentReceipt {
+    /// Input token chosen to fund the payment
+    pub input_mint: String,
+    pub output_mint: String,
+    /// Owner credited with the payment
+    pub recipient: Pubkey,
+    /// Exact output amount delivered (raw units)
+    pub amount_delivered: u64,
+    /// Quoted input amount (raw units)
+    pub amount_paid: u64,
+    /// Maximum input the transaction could spend after slippage (raw units)
+    pub max_amount_paid: u64,
+    /// USD value of `amount_paid`, when priced
+    pub cost_usd: Option<f64>,
+    pub signature: Option<String>,
+    pub monitor_result: Option<TransactionMonitorResult>,
+    /// Every accepted input token that could be quoted, cheapest first
+    pub options: Vec<PaymentOption>,
+}
+
+impl PaymentReceipt {
+    /// Whether the payment transaction confirmed
+    pub fn is_confirmed(&self) -> bool {
+        self.monitor_result.as_ref().is_some_and(|result| {
+            matches!(
+                result.status,
+                TransactionStatus::Confirmed | TransactionStatus::Finalized
+            )
+        })
+    }
+}
+
+/// Sorts payment options cheapest first; unpriced options sort last
+pub(crate) fn sort_options(options: &mut [PaymentOption]) {
+    options.sort_by(|a, b| match (a.cost_usd, b.cost_usd) {
+        (Some(a), Some(b)) => a.total_cmp(&b),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+    });
+}
@@ -0,0 +1,153 @@
+/// Execution policy module.
+/// Re-quotes and retries failed swaps with adjusted slippage or priority, within caps,
+/// keeping an audit of every attempt.
+use serde::{Deserialize, Serialize};
+
+use crate::{failure::FailureReason, monitor::TransactionStatus, priority::ExecutionPriority};
+
+/// Retry policy of `JupiterClient::execute_swap` for classified failures
+///
+/// A transaction failing with `SlippageToleranceExceeded` is re-quoted with
+/// `slippage_step_bps` more tolerance, up to `max_slippage_bps`; one that times out
+/// without landing is re-quoted at the next `ExecutionPriority`, up to
+/// `max_priority`. Other failures, and failures whose cap is reached, end execution.
+///
+/// # Example
+/// ```rust
+/// use jup_sdk::{policy::ExecutionPolicy, priority::ExecutionPriority, types::AdvancedSwapConfig};
+///
+/// let config = AdvancedSwapConfig {
+///     execution_policy: Some(
+///         ExecutionPolicy::default()
+///             .with_max_slippage_bps(150)
+///             .with_max_priority(ExecutionPriority::High),
+///     ),
+///     ..AdvancedSwapConfig::default()
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExecutionPolicy {
+    /// Maximum number of submitted transactions, including the first
+    pub max_attempts: u32,
+    /// Slippage added per retry after a slippage failure
+    pub slippage_step_bps: u16,
+    /// Slippage cap of retries
+    pub max_slippage_bps: u16,
+    /// Priority cap of retries after timeouts
+    pub max_priority: ExecutionPriority,
+}
+
+impl Default for ExecutionPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            slippage_step_bps: 50,
+            max_slippage_bps: 200,
+            max_priority: ExecutionPriority::High,
+        }
+    }
+}
+
+impl ExecutionPolicy {
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    pub fn with_slippage_step_bps(mut self, slippage_step_bps: u16) -> Self {
+        self.slippage_step_bps = slippage_step_bps;
+        self
+    }
+
+    pub fn with_max_slippage_bps(mut self, max_slippage_bps: u16) -> Self {
+        self.max_slippage_bps = max_slippage_bps;
+        self
+    }
+
+    pub fn with_max_priority(mut self, max_priority: ExecutionPriority) -> Self {
+        self.max_priority = max_priority;
+        self
+    }
+
+    /// Adjustment for the attempt after `attempt`, `None` to stop
+    ///
+    /// # Params
+    /// attempt - Settings and outcome of the attempt that just ended
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::{
+    ///     failure::FailureReason,
+    ///     monitor::TransactionStatus,
+    ///     policy::{ExecutionAttempt, ExecutionPolicy, RetryAdjustment},
+    /// };
+    ///
+    /// let attempt = ExecutionAttempt {
+    ///     attempt: 1,
+    ///     slippage_bps: 50,
+    ///     priority: None,
+    ///     signature: None,
+    ///     status: Some(TransactionStatus::Failed),
+    ///     failure_reason: Some(FailureReason::SlippageToleranceExceeded),
+    ///     adjustment: None,
+    /// };
+    /// assert_eq!(
+    ///     ExecutionPolicy::default().next_adjustment(&attempt),
+    ///     Some(RetryAdjustment::Slippage { from_bps: 50, to_bps: 100 })
+    /// );
+    /// ```
+    pub fn next_adjustment(&self, attempt: &ExecutionAttempt) -> Option<RetryAdjustment> {
+        if attempt.attempt >= self.max_attempts {
+            return None;
+        }
+        match (&attempt.status, &attempt.failure_reason) {
+            (Some(TransactionStatus::Failed), Some(reason)) if reason.is_slippage() => {
+                let to_bps = attempt
+                    .slippage_bps
+                    .saturating_add(self.slippage_step_bps)
+                    .min(self.max_slippage_bps);
+                (to_bps > attempt.slippage_bps).then_some(RetryAdjustment::Slippage {
+                    from_bps: attempt.slippage_bps,
+                    to_bps,
+                })
+            }
+            (Some(TransactionStatus::Timeout), _) => {
+                let from = attempt.priority.unwrap_or_default();
+                from.next()
+                    .filter(|to| *to <= self.max_priority)
+                    .map(|to| RetryAdjustment::Priority { from, to })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Parameter change applied before a retry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RetryAdjustment {
+    Slippage {
+        from_bps: u16,
+        to_bps: u16,
+    },
+    Priority {
+        from: ExecutionPriority,
+        to: ExecutionPriority,
+    },
+}
+
+/// Audit record of one submitted swap transaction
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExecutionAttempt {
+    /// 1-based attempt number
+    pub attempt: u32,
+    /// Slippage of the attempt's quote
+    pub slippage_bps: u16,
+    pub priority: Option<ExecutionPriority>,
+    pub signature: Option<String>,
+    /// Final monitoring status
+    pub status: Option<TransactionStatus>,
+    pub failure_reason: Option<FailureReason>,
+    /// Change applied before this attempt, `None` for the first
+    pub adjustment: Option<RetryAdjustment>,
+}
@@ -0,0 +1,280 @@
+/// Client pool module.
+/// Load-balances requests across several Jupiter clients (hosts or API keys), tracking
+/// per-endpoint health and latency and taking failing endpoints out of rotation.
+use std::{
+    future::Future,
+    sync::{
+        Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+use crate::{
+    JupiterClient,
+    global::{POOL_COOLDOWN_SECONDS, POOL_LATENCY_SMOOTHING, POOL_MAX_CONSECUTIVE_FAILURES},
+    types::{JupiterError, QuoteRequest, QuoteResponse, SwapRequest, SwapResponse},
+};
+
+/// How the pool picks an endpoint for each request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LoadBalanceStrategy {
+    /// Cycle through healthy endpoints in order
+    #[default]
+    RoundRobin,
+    /// Prefer the healthy endpoint with the lowest smoothed latency
+    LeastLatency,
+}
+
+/// Configuration of a `ClientPool`
+#[derive(Debug, Clone)]
+pub struct ClientPoolConfig {
+    pub strategy: LoadBalanceStrategy,
+    /// Consecutive retriable failures after which an endpoint leaves rotation
+    pub max_consecutive_failures: u32,
+    /// Time an unhealthy endpoint stays out of rotation before it is tried again
+    pub cooldown: Duration,
+}
+
+impl Default for ClientPoolConfig {
+    fn default() -> Self {
+        Self {
+            strategy: LoadBalanceStrategy::default(),
+            max_consecutive_failures: POOL_MAX_CONSECUTIVE_FAILURES,
+            cooldown: Duration::from_secs(POOL_COOLDOWN_SECONDS),
+        }
+    }
+}
+
+/// Health and latency of a pool endpoint
+#[derive(Debug, Clone, PartialEq)]
+pub struct EndpointStats {
+    pub name: String,
+    /// Whether the endpoint is in rotation
+    pub healthy: bool,
+    pub consecutive_failures: u32,
+    pub requests: u64,
+    pub errors: u64,
+    /// Exponentially smoothed request latency, if any request succeeded
+    pub latency: Option<Duration>,
+}
+
+#[derive(Debug)]
+struct EndpointState {
+    consecutive_failures: u32,
+    requests: u64,
+    errors: u64,
+    latency: Option<Duration>,
+    /// Set while the endpoint is out of rotation
+    unhealthy_until: Option<Instant>,
+}
+
+struct Endpoint {
+    name: String,
+    client: JupiterClient,
+    state: Mutex<EndpointState>,
+}
+
+/// Pool of Jupiter clients load-balanced by health and latency
+///
+/// Requests failing with a retriable error are retried on the next endpoint; an
+/// endpoint failing `max_consecutive_failures` times in a row is skipped for
+/// `cooldown`. When every endpoint is out of rotation, the one recovering first is used.
+///
+/// # Example
+/// ```rust
+/// use jup_sdk::{ClientConfig, JupiterClient, pool::{ClientPool, ClientPoolConfig}};
+///
+/// async fn example(request: jup_sdk::types::QuoteRequest) -> Result<(), Box<dyn std::error::Error>> {
+/// let pool = ClientPool::new(
+///     vec![
+///         ("lite".to_string(), JupiterClient::new()?),
+///         ("pro".to_string(), JupiterClient::from_config(ClientConfig {
+///             base_url: "https://api.jup.ag/swap/v1".to_string(),
+///             ..ClientConfig::default()
+///         })?),
+///     ],
+///     ClientPoolConfig::default(),
+/// )?;
+/// let quote = pool.get_quote(&request).await?;
+/// Ok(())
+/// }
+/// ```
+pub struct ClientPool {
+    endpoints: Vec<Endpoint>,
+    config: ClientPoolConfig,
+    next: AtomicUsize,
+}
+
+impl ClientPool {
+    /// Creates a pool from named clients
+    pub fn new(
+        clients: Vec<(String, JupiterClient)>,
+        config: ClientPoolConfig,
+    ) -> Result<Self, JupiterError> {
+        if clients.is_empty() {
+            return Err(JupiterError::InvalidInput(
+                "client pool needs at least one client".to_string(),
+            ));
+        }
+        let endpoints = clients
+            .into_iter()
+            .map(|(name, client)| Endpoint {
+                name,
+                client,
+                state: Mutex::new(EndpointState {
+                    consecutive_failures: 0,
+                    requests: 0,
+                    errors: 0,
+                    latency: None,
+                    unhealthy_until: None,
+                }),
+            })
+            .collect();
+        Ok(Self {
+            endpoints,
+            config,
+            next: AtomicUsize::new(0),
+        })
+    }
+
+    /// Runs a request on a selected endpoint, failing over to the others on
+    /// retriable errors
+    ///
+    /// # Params
+    /// request - Request to run, given the selected client
+    pub async fn run<T, F, Fut>(&self, request: F) -> Result<T, JupiterError>
+    where
+        F: Fn(JupiterClient) -> Fut,
+        Fut: Future<Output = Result<T, JupiterError>>,
+    {
+        let mut tried: Vec<usize> = Vec::new();
+        loop {
+            let index = self.select(&tried);
+            tried.push(index);
+            let endpoint = &self.endpoints[index];
+            let started = Instant::now();
+            let result = request(endpoint.client.clone()).await;
+            self.record(index, &result, started.elapsed());
+            match result {
+                Err(e) if e.is_retriable() && tried.len() < self.endpoints.len() => {
+                    tracing::debug!(endpoint = %endpoint.name, error = %e, "failing over to next endpoint");
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Gets a quote from the pool
+    pub async fn get_quote(&self, request: &QuoteRequest) -> Result<QuoteResponse, JupiterError> {
+        self.run(|client| async move { client.get_quote(request).await })
+            .await
+    }
+
+    /// Builds a swap transaction using the pool
+    pub async fn get_swap_transaction_data(
+        &self,
+        request: &SwapRequest,
+    ) -> Result<SwapResponse, JupiterError> {
+        self.run(|client| async move { client.get_swap_transaction_data(request).await })
+            .await
+    }
+
+    /// Health and latency of every endpoint, in pool order
+    pub fn stats(&self) -> Vec<EndpointStats> {
+        let now = Instant::now();
+        self.endpoints
+            .iter()
+            .map(|endpoint| {
+                let state = endpoint.state.lock().unwrap();
+                EndpointStats {
+                    name: endpoint.name.clone(),
+                    healthy: state.unhealthy_until.is_none_or(|until| until <= now),
+                    consecutive_failures: state.consecutive_failures,
+                    requests: state.requests,
+                    errors: state.errors,
+                    latency: state.latency,
+                }
+            })
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.endpoints.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.endpoints.is_empty()
+    }
+
+    /// Picks an endpoint not in `exclude`, preferring those in rotation
+    fn select(&self, exclude: &[usize]) -> usize {
+        let now = Instant::now();
+        let candidates: Vec<(usize, Option<Instant>, Option<Duration>)> = self
+            .endpoints
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !exclude.contains(index))
+            .map(|(index, endpoint)| {
+                let state = endpoint.state.lock().unwrap();
+                (
+                    index,
+                    state.unhealthy_until.filter(|until| *until > now),
+                    state.latency,
+                )
+            })
+            .collect();
+        let healthy: Vec<&(usize, Option<Instant>, Option<Duration>)> = candidates
+            .iter()
+            .filter(|(_, unhealthy_until, _)| unhealthy_until.is_none())
+            .collect();
+        if healthy.is_empty() {
+            // Everything is out of rotation: use the endpoint recovering first
+            return candidates
+                .iter()
+                .min_by_key(|(_, unhealthy_until, _)| *unhealthy_until)
+                .map_or(0, |(index, _, _)| *index);
+        }
+        match self.config.strategy {
+            LoadBalanceStrategy::RoundRobin => {
+                let turn = self.next.fetch_add(1, Ordering::Relaxed);
+                healthy[turn % healthy.len()].0
+            }
+            LoadBalanceStrategy::LeastLatency => {
+                // Endpoints without samples go first so they get measured
+                healthy
+                    .iter()
+                    .min_by_key(|(_, _, latency)| latency.unwrap_or(Duration::ZERO))
+                    .map_or(0, |(index, _, _)| *index)
+            }
+        }
+    }
+
+    fn record<T>(&self, index: usize, result: &Result<T, JupiterError>, elapsed: Duration) {
+        let mut state = self.endpoints[index].state.lock().unwrap();
+        state.requests += 1;
+        match result {
+            Ok(_) => {
+                state.consecutive_failures = 0;
+                state.unhealthy_until = None;
+                state.latency = Some(match state.latency {
+                    Some(latency) => {
+                        latency.mul_f64(1.0 - POOL_LATENCY_SMOOTHING)
+                            + elapsed.mul_f64(POOL_LATENCY_SMOOTHING)
+                    }
+                    None => elapsed,
+                });
+            }
+            // Caller mistakes say nothing about the endpoint's health
+            Err(e) if !e.is_retriable() => state.errors += 1,
+            Err(_) => {
+                state.errors += 1;
+                state.consecutive_failures += 1;
+                if state.consecutive_failures >= self.config.max_consecutive_failures {
+                    tracing::warn!(endpoint = %self.endpoints[index].name, "endpoint removed from rotation");
+                    state.unhealthy_until = Some(Instant::now() + self.config.cooldown);
+                }
+            }
+        }
+    }
+}
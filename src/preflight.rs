@@ -0,0 +1,87 @@
+/// Preflight module.
+/// Pre-trade balance and rent checks, reporting deficiencies before a swap is sent.
+use solana_sdk::pubkey::Pubkey;
+
+/// Problem that would make a swap fail on-chain
+#[derive(Debug, Clone, PartialEq)]
+pub enum Deficiency {
+    /// The wallet has no token account for the input mint
+    MissingInputTokenAccount { mint: Pubkey },
+    /// The wallet holds fewer input tokens than the swap spends (raw units)
+    InsufficientInputToken {
+        mint: Pubkey,
+        required: u64,
+        available: u64,
+    },
+    /// The wallet holds too little SOL for fees, rent and wrapping (lamports)
+    InsufficientSol { required: u64, available: u64 },
+}
+
+impl std::fmt::Display for Deficiency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Deficiency::MissingInputTokenAccount { mint } => {
+                write!(f, "no token account for input mint {}", mint)
+            }
+            Deficiency::InsufficientInputToken {
+                mint,
+                required,
+                available,
+            } => write!(
+                f,
+                "insufficient {} balance: {} required, {} available",
+                mint, required, available
+            ),
+            Deficiency::InsufficientSol {
+                required,
+                available,
+            } => write!(
+                f,
+                "insufficient SOL: {} lamports required, {} available",
+                required, available
+            ),
+        }
+    }
+}
+
+/// SOL required by a swap, in lamports
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SolRequirement {
+    /// Signature and priority fees
+    pub fees: u64,
+    /// Rent for token accounts created by the swap
+    pub rent: u64,
+    /// SOL wrapped into the input token account when selling SOL
+    pub wrap: u64,
+}
+
+impl SolRequirement {
+    pub fn total(&self) -> u64 {
+        self.fees
+            .saturating_add(self.rent)
+            .saturating_add(self.wrap)
+    }
+}
+
+/// Result of `JupiterClient::preflight_checks`
+#[derive(Debug, Clone)]
+pub struct PreflightReport {
+    pub wallet: Pubkey,
+    /// SOL balance of the wallet, in lamports
+    pub sol_balance: u64,
+    /// Input token balance of the wallet (raw units); equals `sol_balance` when selling SOL
+    pub input_balance: u64,
+    pub sol_required: SolRequirement,
+    /// Token-2022 fee withheld from the input transfer (raw units)
+    pub input_transfer_fee: u64,
+    /// Output expected to arrive after Token-2022 transfer fees (raw units)
+    pub expected_output: u64,
+    pub deficiencies: Vec<Deficiency>,
+}
+
+impl PreflightReport {
+    /// Whether the swap is expected to be affordable
+    pub fn is_ok(&self) -> bool {
+        self.deficiencies.is_empty()
+    }
+}
@@ -0,0 +1,90 @@
+/// Pre-trade check module.
+/// Insertion point for KYC, limit and other compliance systems that must approve a
+/// trade before the SDK executes it.
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::types::{JupiterError, QuoteResponse};
+
+/// Why a pre-trade check vetoed a trade
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VetoReason {
+    /// The wallet hasn't passed KYC
+    KycRequired,
+    /// The trade exceeds a position, volume or notional limit
+    LimitExceeded(String),
+    /// The wallet may not trade, e.g. it is sanctioned or frozen
+    RestrictedWallet,
+    /// Any other reason given by the check
+    Other(String),
+}
+
+impl std::fmt::Display for VetoReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VetoReason::KycRequired => write!(f, "KYC required"),
+            VetoReason::LimitExceeded(limit) => write!(f, "limit exceeded: {}", limit),
+            VetoReason::RestrictedWallet => write!(f, "wallet is restricted"),
+            VetoReason::Other(reason) => write!(f, "{}", reason),
+        }
+    }
+}
+
+/// Decision of a pre-trade check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PreTradeDecision {
+    Approve,
+    Veto(VetoReason),
+}
+
+/// Check run with the quote and wallet before every execution
+///
+/// Checks run in registration order and the first veto aborts the trade with
+/// `JupiterError::PreTradeVeto`. An error returned by a check, e.g. an unreachable
+/// KYC service, aborts the trade as well, so checks fail closed.
+///
+/// # Example
+/// ```rust
+/// use async_trait::async_trait;
+/// use jup_sdk::{
+///     pretrade::{PreTradeCheck, PreTradeDecision, VetoReason},
+///     types::{JupiterError, QuoteResponse},
+/// };
+/// use solana_sdk::pubkey::Pubkey;
+///
+/// #[derive(Debug)]
+/// struct MaxInput(u64);
+///
+/// #[async_trait]
+/// impl PreTradeCheck for MaxInput {
+///     fn name(&self) -> &str {
+///         "max-input"
+///     }
+///
+///     async fn check(
+///         &self,
+///         quote: &QuoteResponse,
+///         _wallet: &Pubkey,
+///     ) -> Result<PreTradeDecision, JupiterError> {
+///         let amount: u64 = quote.in_amount.parse().unwrap_or(u64::MAX);
+///         Ok(match amount > self.0 {
+///             true => PreTradeDecision::Veto(VetoReason::LimitExceeded(format!("input above {}", self.0))),
+///             false => PreTradeDecision::Approve,
+///         })
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait PreTradeCheck: Send + Sync + std::fmt::Debug {
+    /// Name reported when the check vetoes
+    fn name(&self) -> &str;
+
+    /// Approves or vetoes trading `quote` from `wallet`
+    async fn check(
+        &self,
+        quote: &QuoteResponse,
+        wallet: &Pubkey,
+    ) -> Result<PreTradeDecision, JupiterError>;
+}
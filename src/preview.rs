@@ -0,0 +1,354 @@
+/// Transaction preview module.
+/// Decodes swap transactions into a structured, human-readable preview of what is signed.
+use std::collections::{BTreeMap, HashMap};
+
+use solana_sdk::{message::VersionedMessage, pubkey::Pubkey};
+
+use crate::{
+    dex::{COMPUTE_BUDGET_PROGRAM_ID, ProgramIdList, SYSTEM_PROGRAM_ID},
+    global::LAMPORTS_PER_SIGNATURE,
+    tx::decode_swap_transaction,
+    types::{JupiterError, QuoteResponse},
+};
+
+/// Address of an account referenced by a transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum AccountKey {
+    /// Address stored in the message itself
+    Static(Pubkey),
+    /// Address loaded from an address lookup table, not yet resolved
+    Lookup { table: Pubkey, index: u8 },
+}
+
+impl std::fmt::Display for AccountKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AccountKey::Static(pubkey) => write!(f, "{}", pubkey),
+            AccountKey::Lookup { table, index } => write!(f, "{}[{}]", table, index),
+        }
+    }
+}
+
+/// Account touched by a transaction
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccountPreview {
+    pub key: AccountKey,
+    pub signer: bool,
+    pub writable: bool,
+}
+
+/// Instruction of a transaction
+#[derive(Debug, Clone, PartialEq)]
+pub struct InstructionPreview {
+    pub program_id: Pubkey,
+    /// Program label from the known program list, if available
+    pub program_name: Option<String>,
+    pub accounts: Vec<AccountPreview>,
+    /// Length of the instruction data in bytes
+    pub data_len: usize,
+}
+
+/// Compute budget settings of a transaction
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ComputeBudgetPreview {
+    pub unit_limit: Option<u32>,
+    pub unit_price_micro_lamports: Option<u64>,
+    pub heap_frame_bytes: Option<u32>,
+    pub loaded_accounts_data_size_limit: Option<u32>,
+}
+
+impl ComputeBudgetPreview {
+    /// Priority fee in lamports implied by the unit limit and price
+    pub fn priority_fee_lamports(&self) -> u64 {
+        let units = self.unit_limit.unwrap_or(0) as u128;
+        let price = self.unit_price_micro_lamports.unwrap_or(0) as u128;
+        (units * price).div_ceil(1_000_000) as u64
+    }
+}
+
+/// Approximate balance change of an account
+#[derive(Debug, Clone, PartialEq)]
+pub struct BalanceChange {
+    pub account: AccountKey,
+    /// Token mint, `None` for native SOL (lamports)
+    pub mint: Option<String>,
+    /// Signed change in raw units
+    pub delta: i128,
+}
+
+/// Structured preview of a transaction, suitable for showing users what they sign
+#[derive(Debug, Clone)]
+pub struct TransactionPreview {
+    /// Whether the transaction uses the v0 message format
+    pub versioned: bool,
+    pub fee_payer: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub recent_blockhash: String,
+    pub instructions: Vec<InstructionPreview>,
+    /// Every account referenced by the message, in message order
+    pub accounts: Vec<AccountPreview>,
+    /// Address lookup tables referenced by the message
+    pub lookup_tables: Vec<Pubkey>,
+    pub compute_budget: ComputeBudgetPreview,
+    /// Signature fee plus priority fee, in lamports
+    pub estimated_fee_lamports: u64,
+    /// Balance changes derived from the fee and top-level SOL transfers, plus the
+    /// quoted amounts when built with `with_quote`
+    pub balance_changes: Vec<BalanceChange>,
+}
+
+impl TransactionPreview {
+    /// Decodes a base64 encoded transaction into a preview
+    ///
+    /// # Params
+    /// encoded - Base64 encoded versioned transaction
+    /// programs - Known program list used to name programs, if available
+    pub fn decode(encoded: &str, programs: Option<&ProgramIdList>) -> Result<Self, JupiterError> {
+        let transaction = decode_swap_transaction(encoded)?;
+        let message = &transaction.message;
+        let keys = message.static_account_keys();
+        let fee_payer = *keys
+            .first()
+            .ok_or_else(|| JupiterError::ParseError("transaction has no accounts".to_string()))?;
+        let accounts = message_accounts(message);
+        let num_signers = message.header().num_required_signatures as usize;
+        let compute_budget_program = program_id(COMPUTE_BUDGET_PROGRAM_ID);
+        let system_program = program_id(SYSTEM_PROGRAM_ID);
+
+        let mut instructions = Vec::new();
+        let mut compute_budget = ComputeBudgetPreview::default();
+        let mut lamport_changes: BTreeMap<AccountKey, i128> = BTreeMap::new();
+        for instruction in message.instructions() {
+            let program = *keys
+                .get(instruction.program_id_index as usize)
+                .ok_or_else(|| {
+                    JupiterError::ParseError("instruction program is not a static account".into())
+                })?;
+            let instruction_accounts: Vec<AccountPreview> = instruction
+                .accounts
+                .iter()
+                .filter_map(|index| accounts.get(*index as usize).cloned())
+                .collect();
+            if program == compute_budget_program {
+                apply_compute_budget(&mut compute_budget, &instruction.data);
+            } else if program == system_program
+                && let Some(lamports) = system_transfer_lamports(&instruction.data)
+                && let [from, to, ..] = instruction_accounts.as_slice()
+            {
+                *lamport_changes.entry(from.key).or_default() -= lamports as i128;
+                *lamport_changes.entry(to.key).or_default() += lamports as i128;
+            }
+            instructions.push(InstructionPreview {
+                program_id: program,
+                program_name: programs.and_then(|p| p.label(&program)).map(str::to_string),
+                accounts: instruction_accounts,
+                data_len: instruction.data.len(),
+            });
+        }
+
+        let estimated_fee_lamports =
+            LAMPORTS_PER_SIGNATURE * num_signers as u64 + compute_budget.priority_fee_lamports();
+        *lamport_changes
+            .entry(AccountKey::Static(fee_payer))
+            .or_default() -= estimated_fee_lamports as i128;
+        let balance_changes = lamport_changes
+            .into_iter()
+            .filter(|(_, delta)| *delta != 0)
+            .map(|(account, delta)| BalanceChange {
+                account,
+                mint: None,
+                delta,
+            })
+            .collect();
+
+        Ok(Self {
+            versioned: matches!(message, VersionedMessage::V0(_)),
+            fee_payer,
+            signers: keys.iter().take(num_signers).copied().collect(),
+            recent_blockhash: message.recent_blockhash().to_string(),
+            instructions,
+            accounts,
+            lookup_tables: message
+                .address_table_lookups()
+                .map(|lookups| lookups.iter().map(|l| l.account_key).collect())
+                .unwrap_or_default(),
+            compute_budget,
+            estimated_fee_lamports,
+            balance_changes,
+        })
+    }
+
+    /// Replaces lookup table references with the resolved addresses
+    ///
+    /// # Params
+    /// account_keys - Every account key of the message, see `LookupTableCache::resolve`
+    pub fn with_resolved_accounts(mut self, account_keys: &[Pubkey]) -> Self {
+        if account_keys.len() != self.accounts.len() {
+            return self;
+        }
+        let resolved: HashMap<AccountKey, AccountKey> = self
+            .accounts
+            .iter()
+            .zip(account_keys)
+            .map(|(account, key)| (account.key, AccountKey::Static(*key)))
+            .collect();
+        let resolve = |key: &mut AccountKey| {
+            if let Some(resolved) = resolved.get(key) {
+                *key = *resolved;
+            }
+        };
+        self.accounts
+            .iter_mut()
+            .chain(
+                self.instructions
+                    .iter_mut()
+                    .flat_map(|i| i.accounts.iter_mut()),
+            )
+            .for_each(|account| resolve(&mut account.key));
+        self.balance_changes
+            .iter_mut()
+            .for_each(|change| resolve(&mut change.account));
+        self
+    }
+
+    /// Adds the quoted input and output amounts as balance changes of the fee payer
+    pub fn with_quote(mut self, quote: &QuoteResponse) -> Self {
+        let fee_payer = AccountKey::Static(self.fee_payer);
+        let in_amount: i128 = quote.in_amount.parse().unwrap_or(0);
+        let out_amount: i128 = quote.out_amount.parse().unwrap_or(0);
+        self.balance_changes.push(BalanceChange {
+            account: fee_payer,
+            mint: Some(quote.input_mint.clone()),
+            delta: -in_amount,
+        });
+        self.balance_changes.push(BalanceChange {
+            account: fee_payer,
+            mint: Some(quote.output_mint.clone()),
+            delta: out_amount,
+        });
+        self
+    }
+}
+
+impl std::fmt::Display for TransactionPreview {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{} transaction, fee payer {}",
+            if self.versioned { "v0" } else { "legacy" },
+            self.fee_payer
+        )?;
+        writeln!(
+            f,
+            "Estimated fee: {} lamports (compute unit limit {}, price {} micro-lamports)",
+            self.estimated_fee_lamports,
+            self.compute_budget
+                .unit_limit
+                .map_or("default".to_string(), |limit| limit.to_string()),
+            self.compute_budget.unit_price_micro_lamports.unwrap_or(0)
+        )?;
+        writeln!(f, "Instructions:")?;
+        for (i, instruction) in self.instructions.iter().enumerate() {
+            writeln!(
+                f,
+                "  {}. {} ({} accounts, {} bytes)",
+                i + 1,
+                instruction
+                    .program_name
+                    .clone()
+                    .unwrap_or_else(|| instruction.program_id.to_string()),
+                instruction.accounts.len(),
+                instruction.data_len
+            )?;
+        }
+        writeln!(f, "Balance changes:")?;
+        for change in &self.balance_changes {
+            writeln!(
+                f,
+                "  {} {:+} {}",
+                change.account,
+                change.delta,
+                change.mint.as_deref().unwrap_or("lamports")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Every account referenced by a message, static keys first followed by writable
+/// and then readonly lookup table entries
+pub(crate) fn message_accounts(message: &VersionedMessage) -> Vec<AccountPreview> {
+    let header = message.header();
+    let keys = message.static_account_keys();
+    let num_signers = header.num_required_signatures as usize;
+    let num_writable_signers =
+        num_signers.saturating_sub(header.num_readonly_signed_accounts as usize);
+    let num_writable_unsigned = keys
+        .len()
+        .saturating_sub(num_signers)
+        .saturating_sub(header.num_readonly_unsigned_accounts as usize);
+    let mut accounts: Vec<AccountPreview> = keys
+        .iter()
+        .enumerate()
+        .map(|(i, key)| AccountPreview {
+            key: AccountKey::Static(*key),
+            signer: i < num_signers,
+            writable: if i < num_signers {
+                i < num_writable_signers
+            } else {
+                i - num_signers < num_writable_unsigned
+            },
+        })
+        .collect();
+    if let Some(lookups) = message.address_table_lookups() {
+        for writable in [true, false] {
+            for lookup in lookups {
+                let indexes = if writable {
+                    &lookup.writable_indexes
+                } else {
+                    &lookup.readonly_indexes
+                };
+                accounts.extend(indexes.iter().map(|index| AccountPreview {
+                    key: AccountKey::Lookup {
+                        table: lookup.account_key,
+                        index: *index,
+                    },
+                    signer: false,
+                    writable,
+                }));
+            }
+        }
+    }
+    accounts
+}
+
+fn apply_compute_budget(budget: &mut ComputeBudgetPreview, data: &[u8]) {
+    let u32_at = |offset: usize| {
+        data.get(offset..offset + 4)
+            .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+    };
+    match data.first() {
+        Some(1) => budget.heap_frame_bytes = u32_at(1),
+        Some(2) => budget.unit_limit = u32_at(1),
+        Some(3) => {
+            budget.unit_price_micro_lamports = data
+                .get(1..9)
+                .and_then(|b| b.try_into().ok())
+                .map(u64::from_le_bytes)
+        }
+        Some(4) => budget.loaded_accounts_data_size_limit = u32_at(1),
+        _ => {}
+    }
+}
+
+/// Lamports moved by a system `Transfer` instruction
+fn system_transfer_lamports(data: &[u8]) -> Option<u64> {
+    if data.get(..4)? != 2u32.to_le_bytes() {
+        return None;
+    }
+    data.get(4..12)?.try_into().ok().map(u64::from_le_bytes)
+}
+
+fn program_id(address: &str) -> Pubkey {
+    address.parse().expect("built-in address is a valid pubkey")
+}
@@ -0,0 +1,113 @@
+/// Execution priority module.
+/// Presets mapping "how fast" to compute-unit prices, priority fees and broadcast strategies.
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use crate::types::SwapRequest;
+
+/// How a swap transaction pays for priority
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PriorityFeeMode {
+    /// Fixed compute-unit price in micro-lamports (`computeUnitPrice`)
+    ComputeUnitPrice(u64),
+    /// Total priority fee budget in lamports, priced by Jupiter (`prioritizationFeeLamports`)
+    MaxLamports(u64),
+}
+
+/// How a signed transaction is broadcast until it lands
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BroadcastStrategy {
+    /// Send once and rely on the RPC node's retries
+    Single,
+    /// Re-send the same signed transaction every `interval` until confirmed,
+    /// at most `max_attempts` sends in total
+    Rebroadcast {
+        interval: Duration,
+        max_attempts: u32,
+    },
+}
+
+/// Concrete settings of an `ExecutionPriority`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PriorityPreset {
+    pub fee: PriorityFeeMode,
+    pub broadcast: BroadcastStrategy,
+}
+
+/// Execution priority levels
+///
+/// # Example
+/// ```rust
+/// use jup_sdk::priority::{ExecutionPriority, PriorityFeeMode};
+///
+/// let preset = ExecutionPriority::High.preset();
+/// assert!(matches!(preset.fee, PriorityFeeMode::MaxLamports(_)));
+/// ```
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize,
+)]
+pub enum ExecutionPriority {
+    /// Cheapest; fine when the market is calm and timing does not matter
+    Low,
+    /// Default; a modest fixed compute-unit price
+    #[default]
+    Medium,
+    /// Priced by Jupiter up to a moderate budget, re-broadcast while pending
+    High,
+    /// Largest budget and aggressive re-broadcast, for time-critical swaps
+    Turbo,
+}
+
+impl ExecutionPriority {
+    /// Fee and broadcast settings of the level
+    pub fn preset(&self) -> PriorityPreset {
+        match self {
+            ExecutionPriority::Low => PriorityPreset {
+                fee: PriorityFeeMode::ComputeUnitPrice(1_000),
+                broadcast: BroadcastStrategy::Single,
+            },
+            ExecutionPriority::Medium => PriorityPreset {
+                fee: PriorityFeeMode::ComputeUnitPrice(10_000),
+                broadcast: BroadcastStrategy::Single,
+            },
+            ExecutionPriority::High => PriorityPreset {
+                fee: PriorityFeeMode::MaxLamports(1_000_000),
+                broadcast: BroadcastStrategy::Rebroadcast {
+                    interval: Duration::from_secs(2),
+                    max_attempts: 5,
+                },
+            },
+            ExecutionPriority::Turbo => PriorityPreset {
+                fee: PriorityFeeMode::MaxLamports(5_000_000),
+                broadcast: BroadcastStrategy::Rebroadcast {
+                    interval: Duration::from_millis(500),
+                    max_attempts: 20,
+                },
+            },
+        }
+    }
+
+    /// Next faster level, `None` for `Turbo`
+    pub fn next(&self) -> Option<Self> {
+        match self {
+            ExecutionPriority::Low => Some(ExecutionPriority::Medium),
+            ExecutionPriority::Medium => Some(ExecutionPriority::High),
+            ExecutionPriority::High => Some(ExecutionPriority::Turbo),
+            ExecutionPriority::Turbo => None,
+        }
+    }
+
+    /// Sets the priority fee fields of a swap request, replacing any set before
+    pub fn apply(&self, request: &mut SwapRequest) {
+        match self.preset().fee {
+            PriorityFeeMode::ComputeUnitPrice(price) => {
+                request.compute_unit_price = Some(price);
+                request.prioritization_fee_lamports = None;
+            }
+            PriorityFeeMode::MaxLamports(lamports) => {
+                request.compute_unit_price = None;
+                request.prioritization_fee_lamports = Some(lamports);
+            }
+        }
+    }
+}
@@ -0,0 +1,243 @@
+/// Venue reliability module.
+/// Tracks success rates and confirmation times of executed swaps per DEX, persisted
+/// through `Storage` and fed back into route scoring and confidence estimates.
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use crate::{
+    dex::DexId,
+    global::LATENCY_WINDOW,
+    monitor::{TransactionMonitorResult, TransactionStatus},
+    router::RouteScorer,
+    storage::Storage,
+    types::{JupiterError, QuoteResponse},
+};
+
+/// Storage key prefix of per-venue statistics
+pub const VENUE_STATS_KEY_PREFIX: &str = "venue_stats:";
+
+/// Execution statistics of one venue
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VenueStats {
+    pub venue: DexId,
+    /// Swaps through the venue that confirmed
+    pub successes: u64,
+    /// Swaps through the venue that failed on chain
+    pub failures: u64,
+    /// Recent confirmation times in milliseconds, oldest first
+    pub confirmation_ms: VecDeque<u64>,
+}
+
+impl VenueStats {
+    pub fn new(venue: DexId) -> Self {
+        Self {
+            venue,
+            successes: 0,
+            failures: 0,
+            confirmation_ms: VecDeque::new(),
+        }
+    }
+
+    pub fn total(&self) -> u64 {
+        self.successes + self.failures
+    }
+
+    /// Laplace-smoothed success rate, 0.5 without observations, so a single failure
+    /// doesn't mark a venue as broken
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::{dex::DexId, reliability::VenueStats};
+    ///
+    /// let mut stats = VenueStats::new(DexId::Whirlpool);
+    /// assert_eq!(stats.success_rate(), 0.5);
+    /// stats.successes = 8;
+    /// assert_eq!(stats.success_rate(), 0.9);
+    /// ```
+    pub fn success_rate(&self) -> f64 {
+        (self.successes as f64 + 1.0) / (self.total() as f64 + 2.0)
+    }
+
+    /// Median of the recent confirmation times
+    pub fn median_confirmation_time(&self) -> Option<Duration> {
+        let mut times: Vec<u64> = self.confirmation_ms.iter().copied().collect();
+        if times.is_empty() {
+            return None;
+        }
+        times.sort_unstable();
+        Some(Duration::from_millis(times[times.len() / 2]))
+    }
+}
+
+/// Per-venue success rates and confirmation times of executed swaps
+///
+/// Every venue of a monitored swap's route is credited with its outcome. Timeouts
+/// aren't counted, since an unlanded transaction says more about fees than about the
+/// venue. With a storage backend, touched venues are written after each record and
+/// `load` restores them on start. Clones share the same statistics.
+#[derive(Debug, Clone)]
+pub struct VenueReliability {
+    window: usize,
+    stats: Arc<Mutex<HashMap<DexId, VenueStats>>>,
+    storage: Option<Arc<dyn Storage>>,
+}
+
+impl Default for VenueReliability {
+    fn default() -> Self {
+        Self::new(LATENCY_WINDOW)
+    }
+}
+
+impl VenueReliability {
+    /// Creates an in-memory tracker keeping the last `window` confirmation times per venue
+    pub fn new(window: usize) -> Self {
+        Self {
+            window: window.max(1),
+            stats: Arc::new(Mutex::new(HashMap::new())),
+            storage: None,
+        }
+    }
+
+    /// Persists statistics to `storage`
+    pub fn with_storage(mut self, storage: Arc<dyn Storage>) -> Self {
+        self.storage = Some(storage);
+        self
+    }
+
+    /// Restores persisted statistics, replacing the in-memory ones of the same venues
+    pub async fn load(&self) -> Result<usize, JupiterError> {
+        let Some(storage) = &self.storage else {
+            return Ok(0);
+        };
+        let entries = storage.list(VENUE_STATS_KEY_PREFIX).await?;
+        let mut stats = self.stats.lock().unwrap();
+        let mut loaded = 0;
+        for (key, value) in entries {
+            match serde_json::from_slice::<VenueStats>(&value) {
+                Ok(venue) => {
+                    stats.insert(venue.venue.clone(), venue);
+                    loaded += 1;
+                }
+                Err(e) => tracing::warn!(key = %key, error = %e, "invalid venue stats"),
+            }
+        }
+        Ok(loaded)
+    }
+
+    /// Records the outcome of a swap along `route`
+    pub async fn record(
+        &self,
+        route: &QuoteResponse,
+        result: &TransactionMonitorResult,
+    ) -> Result<(), JupiterError> {
+        let success = match result.status {
+            TransactionStatus::Confirmed | TransactionStatus::Finalized => true,
+            TransactionStatus::Failed => false,
+            TransactionStatus::Pending | TransactionStatus::Timeout => return Ok(()),
+        };
+        let touched: Vec<VenueStats> = {
+            let mut stats = self.stats.lock().unwrap();
+            route
+                .venues()
+                .into_iter()
+                .map(|venue| {
+                    let entry = stats
+                        .entry(venue.clone())
+                        .or_insert_with(|| VenueStats::new(venue.clone()));
+                    if success {
+                        entry.successes += 1;
+                    } else {
+                        entry.failures += 1;
+                    }
+                    if let (true, Some(time)) = (success, result.confirmation_time) {
+                        entry.confirmation_ms.push_back(time.as_millis() as u64);
+                        while entry.confirmation_ms.len() > self.window {
+                            entry.confirmation_ms.pop_front();
+                        }
+                    }
+                    entry.clone()
+                })
+                .collect()
+        };
+        let Some(storage) = &self.storage else {
+            return Ok(());
+        };
+        for venue in touched {
+            let value =
+                serde_json::to_vec(&venue).map_err(|e| JupiterError::ParseError(e.to_string()))?;
+            storage
+                .put(&format!("{}{}", VENUE_STATS_KEY_PREFIX, venue.venue), value)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Statistics of one venue
+    pub fn stats(&self, venue: &DexId) -> Option<VenueStats> {
+        self.stats.lock().unwrap().get(venue).cloned()
+    }
+
+    /// Statistics of all venues, most used first
+    pub fn snapshot(&self) -> Vec<VenueStats> {
+        let mut stats: Vec<VenueStats> = self.stats.lock().unwrap().values().cloned().collect();
+        stats.sort_by(|a, b| b.total().cmp(&a.total()));
+        stats
+    }
+
+    /// Success rate of every observed venue, e.g. for `ConfidenceInputs::venue_reliability`
+    pub fn success_rates(&self) -> HashMap<DexId, f64> {
+        self.stats
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(venue, stats)| (venue.clone(), stats.success_rate()))
+            .collect()
+    }
+
+    /// Forgets all statistics in memory; persisted entries are kept
+    pub fn reset(&self) {
+        self.stats.lock().unwrap().clear();
+    }
+}
+
+/// Prefers routes whose least reliable venue has the highest success rate
+///
+/// Venues without observations score 1.0, so new venues aren't penalized.
+///
+/// # Example
+/// ```rust
+/// use jup_sdk::{
+///     reliability::{ReliabilityScorer, VenueReliability},
+///     router::{CompositeScorer, MaxOutputScorer},
+/// };
+///
+/// let scorer = CompositeScorer::new()
+///     .with(MaxOutputScorer, 0.7)
+///     .with(ReliabilityScorer::new(VenueReliability::default()), 0.3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ReliabilityScorer {
+    reliability: VenueReliability,
+}
+
+impl ReliabilityScorer {
+    pub fn new(reliability: VenueReliability) -> Self {
+        Self { reliability }
+    }
+}
+
+impl RouteScorer for ReliabilityScorer {
+    fn score(&self, route: &QuoteResponse, _candidates: &[QuoteResponse]) -> f64 {
+        route
+            .venues()
+            .into_iter()
+            .filter_map(|venue| self.reliability.stats(venue))
+            .map(|stats| stats.success_rate())
+            .reduce(f64::min)
+            .unwrap_or(1.0)
+    }
+}
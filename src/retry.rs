@@ -1,8 +1,13 @@
 /// Client-side retry module.
 /// Provides intelligent retry, error classification, and recovery strategies.
 use crate::types::JupiterError;
-use std::time::Duration;
+use rand::Rng;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tokio::time;
+use tracing::Instrument;
 
 /// Configuration for retry behavior.
 #[derive(Debug, Clone)]
@@ -11,6 +16,7 @@ pub struct RetryConfig {
     pub initial_delay: Duration,
     pub max_delay: Duration,
     pub backoff_multiplier: f64,
+    pub jitter: Jitter,
 }
 
 impl Default for RetryConfig {
@@ -20,10 +26,35 @@ impl Default for RetryConfig {
             initial_delay: Duration::from_millis(500),
             max_delay: Duration::from_secs(5),
             backoff_multiplier: 2.0,
+            jitter: Jitter::Full,
         }
     }
 }
 
+/// Randomization applied to retry delays so clients don't retry in lockstep.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Jitter {
+    /// Use the computed delay as is
+    None,
+    /// Random delay between zero and the computed delay
+    Full,
+    /// Half the computed delay plus a random amount up to the other half
+    Equal,
+}
+
+impl Jitter {
+    /// Applies the jitter to a computed delay
+    pub fn apply(&self, delay: Duration) -> Duration {
+        let millis = delay.as_millis() as u64;
+        let jittered = match self {
+            Jitter::None => millis,
+            Jitter::Full => rand::thread_rng().gen_range(0..=millis),
+            Jitter::Equal => millis / 2 + rand::thread_rng().gen_range(0..=millis - millis / 2),
+        };
+        Duration::from_millis(jittered)
+    }
+}
+
 /// Trait defining retry strategy behavior.
 pub trait RetryStrategy {
     /// Determines if a retry should be attempted based on the error and attempt count.
@@ -85,6 +116,316 @@ pub enum ErrorCategory {
     Client,
     /// Transaction-related errors
     Transaction,
+    /// Invalid input or failed validation
+    Validation,
     /// Unknown or unclassified errors
     Unknown,
 }
+
+impl ErrorCategory {
+    /// Classifies an HTTP status code
+    pub fn from_status(status: u16) -> Self {
+        match status {
+            429 => ErrorCategory::RateLimit,
+            500..=599 => ErrorCategory::Server,
+            400..=499 => ErrorCategory::Client,
+            _ => ErrorCategory::Unknown,
+        }
+    }
+
+    /// Whether errors of this category are worth retrying
+    ///
+    /// Network, server and rate-limit errors are transient; client and validation
+    /// errors will fail the same way again.
+    pub fn is_retriable(&self) -> bool {
+        matches!(
+            self,
+            ErrorCategory::Network | ErrorCategory::Server | ErrorCategory::RateLimit
+        )
+    }
+}
+
+/// Exponential backoff: the delay grows by `multiplier` after every failed attempt.
+#[derive(Debug, Clone)]
+pub struct ExponentialBackoff {
+    pub max_retries: u32,
+    pub initial_delay: Duration,
+    pub max_delay: Duration,
+    pub multiplier: f64,
+    pub jitter: Jitter,
+}
+
+impl From<RetryConfig> for ExponentialBackoff {
+    fn from(config: RetryConfig) -> Self {
+        Self {
+            max_retries: config.max_retries,
+            initial_delay: config.initial_delay,
+            max_delay: config.max_delay,
+            multiplier: config.backoff_multiplier,
+            jitter: config.jitter,
+        }
+    }
+}
+
+impl RetryStrategy for ExponentialBackoff {
+    fn should_retry(&self, error: &JupiterError, attempt: u32) -> bool {
+        attempt <= self.max_retries && error.category().is_retriable()
+    }
+
+    fn get_delay(&self, attempt: u32) -> Duration {
+        let delay = self.initial_delay.as_millis() as f64
+            * self.multiplier.powi(attempt.saturating_sub(1) as i32);
+        let delay = delay.min(self.max_delay.as_millis() as f64);
+        self.jitter.apply(Duration::from_millis(delay as u64))
+    }
+}
+
+/// Fixed delay between attempts.
+#[derive(Debug, Clone)]
+pub struct FixedDelay {
+    pub max_retries: u32,
+    pub delay: Duration,
+    pub jitter: Jitter,
+}
+
+impl FixedDelay {
+    /// Creates a fixed delay strategy without jitter
+    pub fn new(max_retries: u32, delay: Duration) -> Self {
+        Self {
+            max_retries,
+            delay,
+            jitter: Jitter::None,
+        }
+    }
+
+    /// Sets the jitter applied to the delay
+    pub fn with_jitter(mut self, jitter: Jitter) -> Self {
+        self.jitter = jitter;
+        self
+    }
+}
+
+impl RetryStrategy for FixedDelay {
+    fn should_retry(&self, error: &JupiterError, attempt: u32) -> bool {
+        attempt <= self.max_retries && error.category().is_retriable()
+    }
+
+    fn get_delay(&self, _attempt: u32) -> Duration {
+        self.jitter.apply(self.delay)
+    }
+}
+
+/// Decorrelated jitter: each delay is drawn between `base_delay` and three times the
+/// previous delay, capped at `max_delay`, which spreads out retries from many clients.
+#[derive(Debug)]
+pub struct DecorrelatedJitter {
+    pub max_retries: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    previous_delay: Mutex<Duration>,
+}
+
+impl DecorrelatedJitter {
+    /// Creates a decorrelated jitter strategy
+    pub fn new(max_retries: u32, base_delay: Duration, max_delay: Duration) -> Self {
+        Self {
+            max_retries,
+            base_delay,
+            max_delay,
+            previous_delay: Mutex::new(base_delay),
+        }
+    }
+}
+
+impl RetryStrategy for DecorrelatedJitter {
+    fn should_retry(&self, error: &JupiterError, attempt: u32) -> bool {
+        attempt <= self.max_retries && error.category().is_retriable()
+    }
+
+    fn get_delay(&self, attempt: u32) -> Duration {
+        let mut previous_delay = self.previous_delay.lock().unwrap();
+        if attempt <= 1 {
+            *previous_delay = self.base_delay;
+        }
+        let base = self.base_delay.as_millis() as u64;
+        let upper = (previous_delay.as_millis() as u64)
+            .saturating_mul(3)
+            .max(base);
+        let delay =
+            Duration::from_millis(rand::thread_rng().gen_range(base..=upper)).min(self.max_delay);
+        *previous_delay = delay;
+        delay
+    }
+}
+
+/// Runs `operation` until it succeeds or `strategy` decides to stop retrying.
+///
+/// # Params
+/// strategy - Retry strategy deciding whether and when to retry
+/// operation - Closure producing a new attempt future
+///
+/// # Example
+/// ```rust
+/// use jup_sdk::retry::{FixedDelay, retry};
+/// use std::time::Duration;
+///
+/// # async fn example(client: jup_sdk::JupiterClient) -> Result<(), jup_sdk::types::JupiterError> {
+/// let strategy = FixedDelay::new(3, Duration::from_millis(200));
+/// let tokens = retry(&strategy, || client.get_tokens()).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn retry<S, F, Fut, T>(strategy: &S, operation: F) -> Result<T, JupiterError>
+where
+    S: RetryStrategy + ?Sized,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, JupiterError>>,
+{
+    retry_with_budget(strategy, None, operation).await
+}
+
+/// Runs `operation` with retries, additionally drawing every retry from a shared budget.
+///
+/// When the budget is exhausted the last error is returned immediately, so retries
+/// cannot amplify load during an outage.
+///
+/// # Params
+/// strategy - Retry strategy deciding whether and when to retry
+/// budget - Optional retry budget shared across operations
+/// operation - Closure producing a new attempt future
+pub async fn retry_with_budget<S, F, Fut, T>(
+    strategy: &S,
+    budget: Option<&RetryBudget>,
+    mut operation: F,
+) -> Result<T, JupiterError>
+where
+    S: RetryStrategy + ?Sized,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, JupiterError>>,
+{
+    let mut attempt = 0;
+    loop {
+        let span = tracing::info_span!("attempt", attempt = attempt + 1);
+        match operation().instrument(span).await {
+            Ok(result) => return Ok(result),
+            Err(e) => {
+                attempt += 1;
+                if !strategy.should_retry(&e, attempt) {
+                    return Err(e);
+                }
+                if budget.is_some_and(|budget| !budget.try_acquire()) {
+                    tracing::warn!(attempt, error = %e, "retry budget exhausted");
+                    return Err(e);
+                }
+                let delay = strategy.get_delay(attempt);
+                tracing::warn!(
+                    attempt,
+                    error = %e,
+                    delay_ms = delay.as_millis() as u64,
+                    "attempt failed, retrying"
+                );
+                time::sleep(delay).await;
+            }
+        }
+    }
+}
+
+/// Configuration of a client-wide retry budget
+#[derive(Debug, Clone)]
+pub struct RetryBudgetConfig {
+    /// Maximum number of retries allowed within `window`
+    pub max_retries: u32,
+    /// Sliding time window
+    pub window: Duration,
+}
+
+impl Default for RetryBudgetConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 20,
+            window: Duration::from_secs(10),
+        }
+    }
+}
+
+/// Sliding-window retry budget shared by all clones
+#[derive(Debug, Clone)]
+pub struct RetryBudget {
+    config: RetryBudgetConfig,
+    retries: Arc<Mutex<VecDeque<Instant>>>,
+}
+
+impl RetryBudget {
+    /// Creates a retry budget
+    pub fn new(config: RetryBudgetConfig) -> Self {
+        Self {
+            config,
+            retries: Arc::new(Mutex::new(VecDeque::new())),
+        }
+    }
+
+    /// Takes one retry from the budget, returning false if it is exhausted
+    pub fn try_acquire(&self) -> bool {
+        let mut retries = self.retries.lock().unwrap();
+        let now = Instant::now();
+        while retries
+            .front()
+            .is_some_and(|at| now.duration_since(*at) > self.config.window)
+        {
+            retries.pop_front();
+        }
+        if retries.len() as u32 >= self.config.max_retries {
+            return false;
+        }
+        retries.push_back(now);
+        true
+    }
+
+    /// Number of retries still available in the current window
+    pub fn remaining(&self) -> u32 {
+        let retries = self.retries.lock().unwrap();
+        let now = Instant::now();
+        let used = retries
+            .iter()
+            .filter(|at| now.duration_since(**at) <= self.config.window)
+            .count() as u32;
+        self.config.max_retries.saturating_sub(used)
+    }
+}
+
+/// Runs `operation`, firing a second identical attempt if the first has not completed
+/// within `hedge_after`, and returns whichever succeeds first.
+///
+/// If one attempt fails, the other is awaited before giving up.
+///
+/// # Params
+/// hedge_after - Latency threshold after which the hedged attempt is started
+/// operation - Closure producing a new attempt future
+pub async fn hedge<F, Fut, T>(hedge_after: Duration, operation: F) -> Result<T, JupiterError>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<T, JupiterError>>,
+{
+    let first = operation();
+    tokio::pin!(first);
+    tokio::select! {
+        result = &mut first => return result,
+        _ = time::sleep(hedge_after) => {}
+    }
+    tracing::debug!(
+        hedge_after_ms = hedge_after.as_millis() as u64,
+        "sending hedged request"
+    );
+    let second = operation();
+    tokio::pin!(second);
+    tokio::select! {
+        result = &mut first => match result {
+            Ok(result) => Ok(result),
+            Err(_) => second.await,
+        },
+        result = &mut second => match result {
+            Ok(result) => Ok(result),
+            Err(_) => first.await,
+        },
+    }
+}
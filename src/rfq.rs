@@ -0,0 +1,170 @@
+/// RFQ module.
+/// Orders from the Jupiter Ultra API, which fills either through AMM routes or
+/// through RFQ market makers (Jupiter Z).
+use serde::{Deserialize, Serialize};
+
+use crate::types::QuoteResponse;
+
+/// Request for an Ultra order
+///
+/// # Example
+/// ```rust
+/// use jup_sdk::rfq::RfqOrderRequest;
+///
+/// let request = RfqOrderRequest {
+///     input_mint: "A".to_string(),
+///     output_mint: "B".to_string(),
+///     amount: 1_000,
+///     taker: None,
+/// };
+/// assert_eq!(
+///     serde_json::to_string(&request).unwrap(),
+///     r#"{"inputMint":"A","outputMint":"B","amount":1000}"#
+/// );
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RfqOrderRequest {
+    pub input_mint: String,
+    pub output_mint: String,
+    pub amount: u64,
+    /// Wallet that will sign the order; without it no transaction is returned
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub taker: Option<String>,
+}
+
+/// How an order is filled
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OrderFill {
+    /// Routed through AMMs, like a regular `/quote` + `/swap`
+    Amm,
+    /// Filled by an RFQ market maker, who co-signs the transaction on execution
+    Rfq {
+        /// Router that sourced the quote, e.g. `jupiterz`
+        router: Option<String>,
+    },
+}
+
+/// Order returned by the Ultra `/order` endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict-serde", serde(deny_unknown_fields))]
+pub struct RfqOrder {
+    /// Identifier to pass back when executing the order
+    pub request_id: String,
+    pub input_mint: String,
+    pub output_mint: String,
+    pub in_amount: String,
+    pub out_amount: String,
+    pub other_amount_threshold: String,
+    pub slippage_bps: u16,
+    #[serde(default)]
+    pub price_impact_pct: Option<String>,
+    /// `aggregator` or `rfq`
+    #[serde(default)]
+    pub swap_type: String,
+    /// Quote source, e.g. `iris` (AMM) or `jupiterz` (RFQ)
+    #[serde(default)]
+    pub router: Option<String>,
+    /// Base64 unsigned transaction, present when `taker` was set
+    #[serde(default)]
+    pub transaction: Option<String>,
+    /// Whether fees are paid by the market maker instead of the taker
+    #[serde(default)]
+    pub gasless: bool,
+    #[serde(default)]
+    pub prioritization_fee_lamports: Option<u64>,
+    /// Reason no transaction was returned, e.g. insufficient balance
+    #[serde(default)]
+    pub error_message: Option<String>,
+}
+
+impl RfqOrder {
+    /// Quote view of the order, e.g. for `PreTradeCheck`s
+    pub fn as_quote(&self) -> QuoteResponse {
+        QuoteResponse {
+            input_mint: self.input_mint.clone(),
+            output_mint: self.output_mint.clone(),
+            in_amount: self.in_amount.clone(),
+            out_amount: self.out_amount.clone(),
+            other_amount_threshold: self.other_amount_threshold.clone(),
+            swap_mode: "ExactIn".to_string(),
+            slippage_bps: self.slippage_bps,
+            price_impact_pct: self
+                .price_impact_pct
+                .clone()
+                .unwrap_or_else(|| "0".to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Whether the order is filled by AMMs or an RFQ market maker
+    pub fn fill(&self) -> OrderFill {
+        let is_rfq = self.swap_type.eq_ignore_ascii_case("rfq")
+            || self
+                .router
+                .as_deref()
+                .is_some_and(|router| router.eq_ignore_ascii_case("jupiterz"));
+        if is_rfq {
+            OrderFill::Rfq {
+                router: self.router.clone(),
+            }
+        } else {
+            OrderFill::Amm
+        }
+    }
+
+    pub fn is_rfq(&self) -> bool {
+        matches!(self.fill(), OrderFill::Rfq { .. })
+    }
+}
+
+/// Body of the Ultra `/execute` endpoint
+///
+/// # Example
+/// ```rust
+/// use jup_sdk::rfq::RfqExecuteRequest;
+///
+/// let request = RfqExecuteRequest {
+///     signed_transaction: "AQ==".to_string(),
+///     request_id: "r-1".to_string(),
+/// };
+/// assert_eq!(
+///     serde_json::to_string(&request).unwrap(),
+///     r#"{"signedTransaction":"AQ==","requestId":"r-1"}"#
+/// );
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RfqExecuteRequest {
+    /// Base64 transaction signed by the taker
+    pub signed_transaction: String,
+    pub request_id: String,
+}
+
+/// Result of the Ultra `/execute` endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict-serde", serde(deny_unknown_fields))]
+pub struct RfqExecuteResponse {
+    /// `Success` or `Failed`
+    pub status: String,
+    #[serde(default)]
+    pub signature: Option<String>,
+    #[serde(default)]
+    pub slot: Option<String>,
+    #[serde(default)]
+    pub code: Option<i64>,
+    #[serde(default)]
+    pub error: Option<String>,
+    #[serde(default)]
+    pub input_amount_result: Option<String>,
+    #[serde(default)]
+    pub output_amount_result: Option<String>,
+}
+
+impl RfqExecuteResponse {
+    pub fn is_success(&self) -> bool {
+        self.status.eq_ignore_ascii_case("success")
+    }
+}
@@ -1,5 +1,7 @@
 /// An abstract module for Jupiter routing.
-use crate::types::QuoteResponse;
+use crate::dex::DexId;
+use crate::types::{IndexedRouteMapResponse, QuoteResponse};
+use std::collections::HashMap;
 
 /// Route analysis result for comparison and selection of optimal routes
 #[derive(Debug, Clone)]
@@ -7,7 +9,75 @@ pub struct RouteAnalysis {
     pub best_route: QuoteResponse,
     pub alternative_routes: Vec<QuoteResponse>,
     pub estimated_time: f64,
+    /// Composite confidence in the best route, `confidence.score`
     pub confidence_score: f64,
+    /// Per-factor breakdown of `confidence_score`
+    pub confidence: ConfidenceBreakdown,
+    /// Fees paid to each venue of the best route
+    pub venue_fees: Vec<VenueFee>,
+    /// Estimated total cost of the best route (venue fees plus price impact) in basis points
+    pub effective_spread_bps: f64,
+}
+
+/// Fees paid to a venue across the hops of a route, per fee mint
+#[derive(Debug, Clone, PartialEq)]
+pub struct VenueFee {
+    pub dex: DexId,
+    pub fee_mint: String,
+    /// Total fee charged in `fee_mint` (raw units)
+    pub fee_amount: u64,
+    /// Fee relative to the traded amount, weighted by route share, in basis points
+    pub fee_bps: f64,
+    /// Number of hops through this venue
+    pub hops: usize,
+}
+
+/// Aggregates `fee_amount`/`fee_mint` across the route plan into per-venue fees
+///
+/// Each hop's fee is related to the hop amount in the fee mint (its input or output)
+/// and weighted by the hop's share of the route, so `fee_bps` of all venues sums to
+/// the estimated fee cost of the whole route.
+pub fn venue_fees(quote: &QuoteResponse) -> Vec<VenueFee> {
+    let mut fees: Vec<VenueFee> = Vec::new();
+    for plan in &quote.route_plan {
+        let info = &plan.swap_info;
+        let fee_amount: u64 = info.fee_amount.parse().unwrap_or(0);
+        let hop_amount: u64 = if info.fee_mint == info.input_mint {
+            info.in_amount.parse().unwrap_or(0)
+        } else {
+            info.out_amount.parse().unwrap_or(0)
+        };
+        let fee_bps = if hop_amount == 0 {
+            0.0
+        } else {
+            fee_amount as f64 / hop_amount as f64 * 10_000.0 * plan.percent as f64 / 100.0
+        };
+        match fees
+            .iter_mut()
+            .find(|fee| fee.dex == info.label && fee.fee_mint == info.fee_mint)
+        {
+            Some(fee) => {
+                fee.fee_amount = fee.fee_amount.saturating_add(fee_amount);
+                fee.fee_bps += fee_bps;
+                fee.hops += 1;
+            }
+            None => fees.push(VenueFee {
+                dex: info.label.clone(),
+                fee_mint: info.fee_mint.clone(),
+                fee_amount,
+                fee_bps,
+                hops: 1,
+            }),
+        }
+    }
+    fees
+}
+
+/// Estimated total cost of a route in basis points: venue fees plus price impact
+pub fn effective_spread_bps(quote: &QuoteResponse) -> f64 {
+    let fee_bps: f64 = venue_fees(quote).iter().map(|fee| fee.fee_bps).sum();
+    let impact_pct: f64 = quote.price_impact_pct.parse().unwrap_or(0.0);
+    fee_bps + impact_pct.abs() * 100.0
 }
 
 impl RouteAnalysis {
@@ -16,19 +86,250 @@ impl RouteAnalysis {
     /// # Examples
     ///
     /// ```
-    /// use crate::types::QuoteResponse;
-    /// use crate::router::RouteAnalysis;
+    /// use jup_sdk::types::QuoteResponse;
+    /// use jup_sdk::router::RouteAnalysis;
     ///
     /// let quote_response = QuoteResponse::default();
     /// let analysis = RouteAnalysis::new(quote_response);
     /// ```
     pub fn new(best_route: QuoteResponse) -> Self {
         Self {
+            venue_fees: venue_fees(&best_route),
+            effective_spread_bps: effective_spread_bps(&best_route),
             best_route,
             alternative_routes: Vec::new(),
             estimated_time: 0.0,
             confidence_score: 1.0,
+            confidence: ConfidenceBreakdown::default(),
+        }
+        .with_confidence(&ConfidenceInputs::default())
+    }
+
+    /// Recomputes `confidence` and `confidence_score` of the best route from `inputs`
+    pub fn with_confidence(mut self, inputs: &ConfidenceInputs) -> Self {
+        self.confidence = ConfidenceBreakdown::new(&self.best_route, inputs);
+        self.confidence_score = self.confidence.score;
+        self
+    }
+}
+
+/// Weights of the confidence factors: price impact, hops, venue reliability,
+/// staleness, liquidity
+const CONFIDENCE_WEIGHTS: [f64; 5] = [0.35, 0.15, 0.2, 0.15, 0.15];
+/// Slot lag at which the staleness factor halves
+const CONFIDENCE_HALF_LAG_SLOTS: f64 = 10.0;
+/// Factor applied per hop beyond the first
+const CONFIDENCE_HOP_DECAY: f64 = 0.9;
+
+/// Observations about a route beyond the quote itself, feeding the confidence score
+///
+/// Missing observations leave their factor out of the score.
+#[derive(Debug, Clone, Default)]
+pub struct ConfidenceInputs {
+    /// Success rate (0–1) of venues, see `VenueReliability::success_rates`
+    pub venue_reliability: HashMap<DexId, f64>,
+    /// Slots the quote's `context_slot` trails the chain tip
+    pub slot_lag: Option<u64>,
+    /// Traded amount relative to the largest amount within the impact budget, see
+    /// `LiquidityEstimate::max_amount`
+    pub depth_ratio: Option<f64>,
+}
+
+/// Confidence score of a route with the factors it combines
+///
+/// Each factor lies in `0.0..=1.0`, higher being better:
+/// - `price_impact`: `1 / (1 + impact_pct)`, halving at 1% impact
+/// - `hops`: `0.9` per hop beyond the first
+/// - `venue_reliability`: success rate of the least reliable venue on the route
+/// - `staleness`: `1 / (1 + slot_lag / 10)`, halving at 10 slots behind the tip
+/// - `liquidity`: `1 / (1 + depth_ratio)`, halving when the order uses the whole depth
+///   within the impact budget
+///
+/// `score` is their weighted mean (weights 0.35, 0.15, 0.2, 0.15, 0.15), renormalized
+/// over the factors that have observations.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfidenceBreakdown {
+    pub price_impact: f64,
+    pub hops: f64,
+    pub venue_reliability: Option<f64>,
+    pub staleness: Option<f64>,
+    pub liquidity: Option<f64>,
+    pub score: f64,
+}
+
+impl Default for ConfidenceBreakdown {
+    fn default() -> Self {
+        Self {
+            price_impact: 1.0,
+            hops: 1.0,
+            venue_reliability: None,
+            staleness: None,
+            liquidity: None,
+            score: 1.0,
+        }
+    }
+}
+
+impl ConfidenceBreakdown {
+    /// Scores `quote` given `inputs`
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::{
+    ///     router::{ConfidenceBreakdown, ConfidenceInputs},
+    ///     types::QuoteResponse,
+    /// };
+    ///
+    /// let quote = QuoteResponse::default().with_price_impact_pct(1.0);
+    /// let confidence = ConfidenceBreakdown::new(
+    ///     &quote,
+    ///     &ConfidenceInputs {
+    ///         slot_lag: Some(10),
+    ///         ..Default::default()
+    ///     },
+    /// );
+    /// assert_eq!(confidence.price_impact, 0.5);
+    /// assert_eq!(confidence.staleness, Some(0.5));
+    /// assert!(confidence.score > 0.5 && confidence.score < 1.0);
+    /// ```
+    pub fn new(quote: &QuoteResponse, inputs: &ConfidenceInputs) -> Self {
+        let impact_pct = quote
+            .price_impact_pct
+            .parse::<f64>()
+            .map(f64::abs)
+            .unwrap_or(100.0);
+        let price_impact = 1.0 / (1.0 + impact_pct);
+        let extra_hops = quote.route_plan.len().saturating_sub(1) as i32;
+        let hops = CONFIDENCE_HOP_DECAY.powi(extra_hops);
+        let venue_reliability = quote
+            .venues()
+            .into_iter()
+            .filter_map(|venue| inputs.venue_reliability.get(venue))
+            .map(|reliability| reliability.clamp(0.0, 1.0))
+            .reduce(f64::min);
+        let staleness = inputs
+            .slot_lag
+            .map(|lag| 1.0 / (1.0 + lag as f64 / CONFIDENCE_HALF_LAG_SLOTS));
+        let liquidity = inputs.depth_ratio.map(|ratio| 1.0 / (1.0 + ratio.max(0.0)));
+        let factors = [
+            Some(price_impact),
+            Some(hops),
+            venue_reliability,
+            staleness,
+            liquidity,
+        ];
+        let (weighted, weights) = factors
+            .iter()
+            .zip(CONFIDENCE_WEIGHTS)
+            .filter_map(|(factor, weight)| factor.map(|factor| (factor * weight, weight)))
+            .fold((0.0, 0.0), |(sum, total), (value, weight)| {
+                (sum + value, total + weight)
+            });
+        Self {
+            price_impact,
+            hops,
+            venue_reliability,
+            staleness,
+            liquidity,
+            score: (weighted / weights).clamp(0.0, 1.0),
+        }
+    }
+}
+
+/// Strategy for scoring a route among a set of candidate routes
+///
+/// Higher scores are better. Built-in scorers return values in `0.0..=1.0` so they
+/// can be combined with [`CompositeScorer`].
+pub trait RouteScorer {
+    /// Scores `route` relative to the full candidate set
+    ///
+    /// # Arguments
+    ///
+    /// route - The QuoteResponse to score
+    /// candidates - All routes being compared, including `route`
+    fn score(&self, route: &QuoteResponse, candidates: &[QuoteResponse]) -> f64;
+}
+
+/// Prefers the route returning the largest output amount
+#[derive(Debug, Clone, Default)]
+pub struct MaxOutputScorer;
+
+impl RouteScorer for MaxOutputScorer {
+    fn score(&self, route: &QuoteResponse, candidates: &[QuoteResponse]) -> f64 {
+        let best = candidates.iter().map(out_amount).max().unwrap_or(0);
+        if best == 0 {
+            return 0.0;
         }
+        out_amount(route) as f64 / best as f64
+    }
+}
+
+/// Prefers the route with the lowest price impact
+#[derive(Debug, Clone, Default)]
+pub struct MinImpactScorer;
+
+impl RouteScorer for MinImpactScorer {
+    fn score(&self, route: &QuoteResponse, _candidates: &[QuoteResponse]) -> f64 {
+        let price_impact = route.price_impact_pct.parse::<f64>().unwrap_or(100.0);
+        ((100.0 - price_impact.max(0.0)) / 100.0).clamp(0.0, 1.0)
+    }
+}
+
+/// Prefers the route with the fewest hops
+#[derive(Debug, Clone, Default)]
+pub struct MinHopsScorer;
+
+impl RouteScorer for MinHopsScorer {
+    fn score(&self, route: &QuoteResponse, _candidates: &[QuoteResponse]) -> f64 {
+        1.0 / (route.route_plan.len() as f64).max(1.0)
+    }
+}
+
+/// Prefers the route with the lowest total DEX fees across its route plan
+#[derive(Debug, Clone, Default)]
+pub struct FeeWeightedScorer;
+
+impl RouteScorer for FeeWeightedScorer {
+    fn score(&self, route: &QuoteResponse, _candidates: &[QuoteResponse]) -> f64 {
+        (1.0 - cal_route_fee_ratio(route)).clamp(0.0, 1.0)
+    }
+}
+
+/// Weighted combination of several scorers
+///
+/// # Examples
+///
+/// ```
+/// use jup_sdk::router::{CompositeScorer, MaxOutputScorer, MinHopsScorer};
+///
+/// let scorer = CompositeScorer::new()
+///     .with(MaxOutputScorer, 0.8)
+///     .with(MinHopsScorer, 0.2);
+/// ```
+#[derive(Default)]
+pub struct CompositeScorer {
+    scorers: Vec<(Box<dyn RouteScorer + Send + Sync>, f64)>,
+}
+
+impl CompositeScorer {
+    /// Creates an empty composite scorer
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a scorer with the given weight
+    pub fn with<S: RouteScorer + Send + Sync + 'static>(mut self, scorer: S, weight: f64) -> Self {
+        self.scorers.push((Box::new(scorer), weight));
+        self
+    }
+}
+
+impl RouteScorer for CompositeScorer {
+    fn score(&self, route: &QuoteResponse, candidates: &[QuoteResponse]) -> f64 {
+        self.scorers
+            .iter()
+            .map(|(scorer, weight)| scorer.score(route, candidates) * weight)
+            .sum()
     }
 }
 
@@ -36,83 +337,327 @@ impl RouteAnalysis {
 pub struct RouteOptimizer;
 
 impl RouteOptimizer {
-    /// Selects the best route from a list of routes based on weighted criteria
+    /// Selects the best route from a list of routes using the given scorer
     ///
     /// # Arguments
     ///
     /// routes - Slice of QuoteResponse to evaluate
-    /// weights - RouteWeights configuration for scoring
+    /// scorer - Any RouteScorer, e.g. RouteWeights or a CompositeScorer
     ///
     /// # Examples
     ///
+    /// The highest-output route wins under default weights, even against a simpler route:
+    ///
     /// ```
-    /// use crate::router::{RouteOptimizer, RouteWeights};
-    /// use crate::types::QuoteResponse;
+    /// use jup_sdk::dex::DexId;
+    /// use jup_sdk::router::{RouteOptimizer, RouteWeights};
+    /// use jup_sdk::types::{QuoteResponse, RoutePlan, SwapInfo};
     ///
-    /// let routes = vec![QuoteResponse::default(), QuoteResponse::default()];
-    /// let weights = RouteWeights::default();
-    /// let best_route = RouteOptimizer::select_best_route(&routes, &weights);
+    /// fn route(out_amount: &str, hops: usize) -> QuoteResponse {
+    ///     let hop = RoutePlan {
+    ///         swap_info: SwapInfo {
+    ///             amm_key: String::new(),
+    ///             label: DexId::Whirlpool,
+    ///             input_mint: "A".to_string(),
+    ///             output_mint: "B".to_string(),
+    ///             in_amount: "1000000".to_string(),
+    ///             out_amount: out_amount.to_string(),
+    ///             fee_amount: "0".to_string(),
+    ///             fee_mint: "B".to_string(),
+    ///         },
+    ///         percent: 100,
+    ///     };
+    ///     QuoteResponse {
+    ///         input_mint: "A".to_string(),
+    ///         output_mint: "B".to_string(),
+    ///         in_amount: "1000000".to_string(),
+    ///         out_amount: out_amount.to_string(),
+    ///         other_amount_threshold: out_amount.to_string(),
+    ///         swap_mode: "ExactIn".to_string(),
+    ///         slippage_bps: 50,
+    ///         platform_fee: None,
+    ///         price_impact_pct: "0.1".to_string(),
+    ///         route_plan: vec![hop; hops],
+    ///         context_slot: 0,
+    ///         time_taken: 0.01,
+    ///     }
+    /// }
+    ///
+    /// let routes = vec![route("990000", 1), route("1000000", 3)];
+    /// let best = RouteOptimizer::select_best_route(&routes, &RouteWeights::default()).unwrap();
+    /// assert_eq!(best.out_amount, "1000000");
     /// ```
-    pub fn select_best_route<'a>(
+    pub fn select_best_route<'a, S: RouteScorer + ?Sized>(
         routes: &'a [QuoteResponse],
-        weights: &'a RouteWeights,
+        scorer: &S,
     ) -> Option<&'a QuoteResponse> {
-        routes.iter().max_by(|a, b| {
-            let score_a = Self::cal_route_score(a, weights);
-            let score_b = Self::cal_route_score(b, weights);
-            score_a
-                .partial_cmp(&score_b)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        })
+        Self::rank_routes(routes, scorer)
+            .into_iter()
+            .next()
+            .map(|(route, _)| route)
     }
 
-    /// Calculates a comprehensive score for a route based on multiple factors
+    /// Scores all routes and returns them ordered from best to worst
     ///
     /// # Arguments
     ///
-    /// route - The QuoteResponse to score
-    /// weights - Weight configuration for different scoring factors
+    /// routes - Slice of QuoteResponse to evaluate
+    /// scorer - Any RouteScorer
+    pub fn rank_routes<'a, S: RouteScorer + ?Sized>(
+        routes: &'a [QuoteResponse],
+        scorer: &S,
+    ) -> Vec<(&'a QuoteResponse, f64)> {
+        let mut ranked: Vec<(&QuoteResponse, f64)> = routes
+            .iter()
+            .map(|route| (route, scorer.score(route, routes)))
+            .collect();
+        ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        ranked
+    }
+
+    /// Calculates a comprehensive score for a route based on multiple factors
     ///
-    /// # Examples
+    /// Every factor is normalized to `0.0..=1.0` against the candidate set; output amount
+    /// is measured relative to the best output, so routes are compared by what they return.
     ///
-    /// ```
-    /// use crate::router::{RouteOptimizer, RouteWeights};
-    /// use crate::types::QuoteResponse;
+    /// # Arguments
     ///
-    /// let route = QuoteResponse::default();
-    /// let weights = RouteWeights::default();
-    /// let score = RouteOptimizer::cal_route_score(&route, &weights);
-    /// ```
-    fn cal_route_score(route: &QuoteResponse, weights: &RouteWeights) -> f64 {
-        let mut score = 0.0;
-        if let Ok(price_impact) = route.price_impact_pct.parse::<f64>() {
-            score += (100.0 - price_impact.max(0.0)) * weights.price_impact;
-        }
-        score += (1000.0 - route.time_taken.max(0.0)) * weights.execution_speed;
-        let complexity = 1.0 / (route.route_plan.len() as f64).max(1.0);
-        score += complexity * weights.simplicity;
-        score
+    /// route - The QuoteResponse to score
+    /// candidates - All routes being compared
+    /// weights - Weight configuration for different scoring factors
+    fn cal_route_score(
+        route: &QuoteResponse,
+        candidates: &[QuoteResponse],
+        weights: &RouteWeights,
+    ) -> f64 {
+        let output = MaxOutputScorer.score(route, candidates);
+        let fees = FeeWeightedScorer.score(route, candidates);
+        let price_impact = MinImpactScorer.score(route, candidates);
+        let simplicity = MinHopsScorer.score(route, candidates);
+        let fastest = candidates
+            .iter()
+            .map(|candidate| candidate.time_taken)
+            .filter(|time_taken| *time_taken > 0.0)
+            .fold(f64::INFINITY, f64::min);
+        let execution_speed = if route.time_taken > 0.0 && fastest.is_finite() {
+            fastest / route.time_taken
+        } else {
+            1.0
+        };
+        output * weights.output
+            + fees * weights.fees
+            + price_impact * weights.price_impact
+            + execution_speed * weights.execution_speed
+            + simplicity * weights.simplicity
     }
 }
 
+/// Builds a key identifying a route by its hops (AMM and split percent)
+///
+/// Two quotes with the same key take the same path, even if their amounts differ.
+pub fn route_key(route: &QuoteResponse) -> String {
+    route
+        .route_plan
+        .iter()
+        .map(|plan| format!("{}:{}", plan.swap_info.amm_key, plan.percent))
+        .collect::<Vec<String>>()
+        .join("|")
+}
+
+/// Parses the output amount of a route, treating malformed amounts as zero
+fn out_amount(route: &QuoteResponse) -> u64 {
+    route.out_amount.parse().unwrap_or(0)
+}
+
+/// Calculates the total fee ratio paid across a route plan
+///
+/// Each hop's fee is expressed relative to the amount it was charged on (input or
+/// output side, depending on the fee mint) and weighted by the hop's split percent.
+pub fn cal_route_fee_ratio(route: &QuoteResponse) -> f64 {
+    route
+        .route_plan
+        .iter()
+        .map(|plan| {
+            let info = &plan.swap_info;
+            let fee: f64 = info.fee_amount.parse().unwrap_or(0.0);
+            let base: f64 = if info.fee_mint == info.input_mint {
+                info.in_amount.parse().unwrap_or(0.0)
+            } else {
+                info.out_amount.parse().unwrap_or(0.0)
+            };
+            if base <= 0.0 {
+                return 0.0;
+            }
+            fee / base * plan.percent as f64 / 100.0
+        })
+        .sum()
+}
+
 /// Weight configuration for route scoring criteria
+///
+/// Factors other than `output` act as tie-breakers: together they can only overturn
+/// output differences smaller than their combined weight.
 #[derive(Debug, Clone)]
 pub struct RouteWeights {
+    pub output: f64,
+    pub fees: f64,
     pub price_impact: f64,
     pub execution_speed: f64,
     pub simplicity: f64,
 }
 
+impl RouteScorer for RouteWeights {
+    fn score(&self, route: &QuoteResponse, candidates: &[QuoteResponse]) -> f64 {
+        RouteOptimizer::cal_route_score(route, candidates, self)
+    }
+}
+
 impl Default for RouteWeights {
-    /// Creates default RouteWeights with balanced priorities
+    /// Creates default RouteWeights prioritizing output amount
     ///
-    /// Default weights prioritize price impact (0.6), followed by execution speed (0.3)
-    /// and simplicity (0.1)
+    /// Output carries weight 1.0; fees (0.002), price impact (0.002), execution speed
+    /// (0.0005) and simplicity (0.0005) only break ties within 0.5% of the best output
     fn default() -> Self {
         Self {
-            price_impact: 0.6,
-            execution_speed: 0.3,
-            simplicity: 0.1,
+            output: 1.0,
+            fees: 0.002,
+            price_impact: 0.002,
+            execution_speed: 0.0005,
+            simplicity: 0.0005,
+        }
+    }
+}
+
+/// Connectivity graph built from Jupiter's indexed route map
+///
+/// Decodes the index arrays once so applications can query tradability offline.
+#[derive(Debug, Clone, Default)]
+pub struct RouteGraph {
+    mint_keys: Vec<String>,
+    index: HashMap<String, usize>,
+    edges: Vec<Vec<usize>>,
+}
+
+impl RouteGraph {
+    /// Builds a route graph from an indexed route map response
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jup_sdk::router::RouteGraph;
+    ///
+    /// # async fn example(client: jup_sdk::JupiterClient) -> Result<(), jup_sdk::types::JupiterError> {
+    /// let route_map = client.get_indexed_route_map().await?;
+    /// let graph = RouteGraph::from_route_map(&route_map);
+    /// println!("{} tradable mints", graph.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_route_map(response: &IndexedRouteMapResponse) -> Self {
+        let route_map = &response.indexed_route_map;
+        let mint_keys = route_map.mint_keys.clone();
+        let index = mint_keys
+            .iter()
+            .enumerate()
+            .map(|(i, mint)| (mint.clone(), i))
+            .collect();
+        let mut edges = vec![Vec::new(); mint_keys.len()];
+        for (from, targets) in &route_map.indexed_route_map {
+            let Ok(from) = from.parse::<usize>() else {
+                continue;
+            };
+            if from >= mint_keys.len() {
+                continue;
+            }
+            edges[from] = targets
+                .iter()
+                .copied()
+                .filter(|to| *to < mint_keys.len())
+                .collect();
+        }
+        Self {
+            mint_keys,
+            index,
+            edges,
+        }
+    }
+
+    /// Number of mints in the graph
+    pub fn len(&self) -> usize {
+        self.mint_keys.len()
+    }
+
+    /// Whether the graph contains no mints
+    pub fn is_empty(&self) -> bool {
+        self.mint_keys.is_empty()
+    }
+
+    /// Whether the mint appears in the route map
+    pub fn contains(&self, mint: &str) -> bool {
+        self.index.contains_key(mint)
+    }
+
+    /// Whether a direct route exists from `input_mint` to `output_mint`
+    pub fn is_tradable(&self, input_mint: &str, output_mint: &str) -> bool {
+        match (self.index.get(input_mint), self.index.get(output_mint)) {
+            (Some(from), Some(to)) => self.edges[*from].contains(to),
+            _ => false,
+        }
+    }
+
+    /// Mints directly reachable from `mint`
+    pub fn neighbors(&self, mint: &str) -> Vec<&str> {
+        self.index
+            .get(mint)
+            .map(|from| {
+                self.edges[*from]
+                    .iter()
+                    .map(|to| self.mint_keys[*to].as_str())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Enumerates simple paths from `input_mint` to `output_mint` using at most `max_hops` hops
+    ///
+    /// Each path starts with `input_mint` and ends with `output_mint`; shorter paths come first.
+    pub fn paths(&self, input_mint: &str, output_mint: &str, max_hops: usize) -> Vec<Vec<String>> {
+        let (Some(from), Some(to)) = (self.index.get(input_mint), self.index.get(output_mint))
+        else {
+            return Vec::new();
+        };
+        let mut paths = Vec::new();
+        let mut current = vec![*from];
+        self.collect_paths(*to, max_hops, &mut current, &mut paths);
+        paths.sort_by_key(|path| path.len());
+        paths
+            .into_iter()
+            .map(|path| path.iter().map(|i| self.mint_keys[*i].clone()).collect())
+            .collect()
+    }
+
+    fn collect_paths(
+        &self,
+        target: usize,
+        hops_left: usize,
+        current: &mut Vec<usize>,
+        paths: &mut Vec<Vec<usize>>,
+    ) {
+        if hops_left == 0 {
+            return;
+        }
+        let last = *current.last().unwrap();
+        for next in &self.edges[last] {
+            if current.contains(next) {
+                continue;
+            }
+            current.push(*next);
+            if *next == target {
+                paths.push(current.clone());
+            } else {
+                self.collect_paths(target, hops_left - 1, current, paths);
+            }
+            current.pop();
         }
     }
 }
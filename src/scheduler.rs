@@ -0,0 +1,269 @@
+/// Scheduler module.
+/// Runs periodic jobs (registry refreshes, cache warm-up, probes) on jittered
+/// intervals with exponential backoff on errors and per-job metrics.
+use rand::Rng;
+use std::{
+    collections::HashMap,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    global::{SCHEDULER_DEFAULT_JITTER, SCHEDULER_MAX_BACKOFF_SECONDS},
+    metrics::Metrics,
+    task::TaskGroup,
+    types::JupiterError,
+};
+
+/// Future returned by a scheduled job run
+pub type JobFuture = Pin<Box<dyn Future<Output = Result<(), JupiterError>> + Send>>;
+
+/// Schedule of a periodic job
+#[derive(Debug, Clone)]
+pub struct JobConfig {
+    /// Job name, used for stats and as the metrics endpoint `job:{name}`
+    pub name: String,
+    /// Delay between successful runs
+    pub interval: Duration,
+    /// Fraction of `interval` added or removed at random per run, so jobs started
+    /// together don't fire together
+    pub jitter: f64,
+    /// Upper bound of the delay after consecutive failures
+    pub max_backoff: Duration,
+    /// Whether the first run happens immediately instead of after one interval
+    pub run_immediately: bool,
+}
+
+impl JobConfig {
+    pub fn new(name: impl Into<String>, interval: Duration) -> Self {
+        Self {
+            name: name.into(),
+            interval,
+            jitter: SCHEDULER_DEFAULT_JITTER,
+            max_backoff: Duration::from_secs(SCHEDULER_MAX_BACKOFF_SECONDS),
+            run_immediately: true,
+        }
+    }
+
+    pub fn with_jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn with_max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Waits one interval before the first run
+    pub fn delayed(mut self) -> Self {
+        self.run_immediately = false;
+        self
+    }
+
+    /// Delay before the next run, doubling `interval` per consecutive failure up to
+    /// `max_backoff`, with jitter applied
+    fn next_delay(&self, consecutive_failures: u32) -> Duration {
+        let base = if consecutive_failures == 0 {
+            self.interval
+        } else {
+            let factor = 2u32.saturating_pow(consecutive_failures.min(16));
+            self.interval.saturating_mul(factor).min(self.max_backoff)
+        };
+        if self.jitter <= 0.0 {
+            return base;
+        }
+        let spread = rand::thread_rng().gen_range(-self.jitter..=self.jitter);
+        base.mul_f64((1.0 + spread).max(0.0))
+    }
+}
+
+/// Intervals of the built-in refresh jobs started by `JupiterClient::start_refreshers`
+///
+/// `None` disables a job. The route map and price jobs go through the client's
+/// response cache, so their interval should be at least the matching
+/// `CacheTtlConfig` TTL.
+#[derive(Debug, Clone, Default)]
+pub struct RefresherConfig {
+    /// Token registry refresh, rebuilding the shared `TokenMap`
+    pub token_map: Option<Duration>,
+    /// Indexed route map refresh
+    pub route_map: Option<Duration>,
+    /// Price cache warm-up for `price_mints`
+    pub prices: Option<Duration>,
+    pub price_mints: Vec<String>,
+    /// Health probe of the Jupiter API, feeding the latency tracker
+    pub latency_probe: Option<Duration>,
+}
+
+impl RefresherConfig {
+    pub fn with_token_map(mut self, interval: Duration) -> Self {
+        self.token_map = Some(interval);
+        self
+    }
+
+    pub fn with_route_map(mut self, interval: Duration) -> Self {
+        self.route_map = Some(interval);
+        self
+    }
+
+    pub fn with_prices(mut self, interval: Duration, mints: Vec<String>) -> Self {
+        self.prices = Some(interval);
+        self.price_mints = mints;
+        self
+    }
+
+    pub fn with_latency_probe(mut self, interval: Duration) -> Self {
+        self.latency_probe = Some(interval);
+        self
+    }
+}
+
+/// Run statistics of a scheduled job
+#[derive(Debug, Clone, Default)]
+pub struct JobStats {
+    pub name: String,
+    pub runs: u64,
+    pub failures: u64,
+    pub consecutive_failures: u32,
+    pub last_run: Option<SystemTime>,
+    pub last_duration: Option<Duration>,
+    pub last_error: Option<String>,
+}
+
+impl JobStats {
+    /// Whether the last run succeeded
+    pub fn is_healthy(&self) -> bool {
+        self.consecutive_failures == 0
+    }
+}
+
+/// Periodic job scheduler
+///
+/// Each job runs in its own task of a `TaskGroup`, so `shutdown` stops all of them.
+/// Runs are recorded in the scheduler's `Metrics` under `job:{name}`.
+///
+/// # Example
+/// ```rust
+/// use jup_sdk::{
+///     metrics::Metrics,
+///     scheduler::{JobConfig, Scheduler},
+/// };
+/// use std::time::Duration;
+///
+/// async fn run() {
+///     let mut scheduler = Scheduler::new(Metrics::default());
+///     scheduler.schedule(JobConfig::new("heartbeat", Duration::from_secs(10)), || {
+///         Box::pin(async { Ok(()) })
+///     });
+///     tokio::time::sleep(Duration::from_millis(10)).await;
+///     println!("{:?}", scheduler.stats());
+///     scheduler.shutdown().await;
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Scheduler {
+    metrics: Metrics,
+    stats: Arc<Mutex<HashMap<String, JobStats>>>,
+    tasks: TaskGroup,
+}
+
+impl Scheduler {
+    pub fn new(metrics: Metrics) -> Self {
+        Self {
+            metrics,
+            stats: Arc::new(Mutex::new(HashMap::new())),
+            tasks: TaskGroup::new(),
+        }
+    }
+
+    /// Schedules `job` to run periodically according to `config`
+    ///
+    /// # Params
+    /// config - Job name, interval, jitter and backoff
+    /// job - Called for every run, returns the run's future
+    pub fn schedule<F>(&mut self, config: JobConfig, job: F)
+    where
+        F: Fn() -> JobFuture + Send + 'static,
+    {
+        self.stats.lock().unwrap().insert(
+            config.name.clone(),
+            JobStats {
+                name: config.name.clone(),
+                ..Default::default()
+            },
+        );
+        let metrics = self.metrics.clone();
+        let stats = self.stats.clone();
+        self.tasks.spawn(move |token| async move {
+            let endpoint = format!("job:{}", config.name);
+            let mut consecutive_failures = 0;
+            if !config.run_immediately {
+                tokio::select! {
+                    _ = token.cancelled() => return,
+                    _ = tokio::time::sleep(config.next_delay(0)) => {}
+                }
+            }
+            loop {
+                let started = SystemTime::now();
+                let result = tokio::select! {
+                    _ = token.cancelled() => return,
+                    result = metrics.observe(&endpoint, job()) => result,
+                };
+                match &result {
+                    Ok(()) => consecutive_failures = 0,
+                    Err(e) => {
+                        consecutive_failures += 1;
+                        tracing::warn!(job = %config.name, error = %e, "scheduled job failed");
+                    }
+                }
+                if let Some(entry) = stats.lock().unwrap().get_mut(&config.name) {
+                    entry.runs += 1;
+                    entry.last_run = Some(started);
+                    entry.last_duration = started.elapsed().ok();
+                    entry.consecutive_failures = consecutive_failures;
+                    if let Err(e) = result {
+                        entry.failures += 1;
+                        entry.last_error = Some(e.to_string());
+                    }
+                }
+                tokio::select! {
+                    _ = token.cancelled() => return,
+                    _ = tokio::time::sleep(config.next_delay(consecutive_failures)) => {}
+                }
+            }
+        });
+    }
+
+    /// Run statistics of all jobs, sorted by name
+    pub fn stats(&self) -> Vec<JobStats> {
+        let mut stats: Vec<JobStats> = self.stats.lock().unwrap().values().cloned().collect();
+        stats.sort_by(|a, b| a.name.cmp(&b.name));
+        stats
+    }
+
+    /// Run statistics of one job
+    pub fn job_stats(&self, name: &str) -> Option<JobStats> {
+        self.stats.lock().unwrap().get(name).cloned()
+    }
+
+    pub fn len(&self) -> usize {
+        self.tasks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+    }
+
+    /// Stops all jobs, waiting for in-flight runs to be cancelled
+    pub async fn shutdown(self) {
+        self.tasks.shutdown().await;
+    }
+
+    /// Like `shutdown`, but aborts jobs still running after `timeout`
+    pub async fn shutdown_timeout(self, timeout: Duration) -> bool {
+        self.tasks.shutdown_timeout(timeout).await
+    }
+}
@@ -0,0 +1,61 @@
+/// JSON Schema module.
+/// Generates JSON Schemas of the API models for validating payloads outside Rust.
+use schemars::{schema::RootSchema, schema_for};
+use std::{collections::BTreeMap, path::Path};
+
+use crate::types::{
+    ApiAccountMeta, ApiInstruction, BatchQuoteError, BatchQuoteRequest, BatchQuoteResponse,
+    IndexedRouteMapResponse, PlatformFee, PriceResponse, QuoteRequest, QuoteResponse, RoutePlan,
+    SwapInfo, SwapInstructionsResponse, SwapRequest, SwapResponse, TokenInfo, TransactionStatus,
+};
+
+/// Schemas of all request/response models, keyed by type name
+///
+/// # Example
+/// ```rust
+/// use jup_sdk::schema::api_schemas;
+///
+/// let schemas = api_schemas();
+/// let quote = serde_json::to_string_pretty(&schemas["QuoteResponse"]).unwrap();
+/// println!("{}", quote);
+/// ```
+pub fn api_schemas() -> BTreeMap<&'static str, RootSchema> {
+    BTreeMap::from([
+        ("TokenInfo", schema_for!(TokenInfo)),
+        ("QuoteRequest", schema_for!(QuoteRequest)),
+        ("QuoteResponse", schema_for!(QuoteResponse)),
+        ("PlatformFee", schema_for!(PlatformFee)),
+        ("RoutePlan", schema_for!(RoutePlan)),
+        ("SwapInfo", schema_for!(SwapInfo)),
+        ("SwapRequest", schema_for!(SwapRequest)),
+        ("SwapResponse", schema_for!(SwapResponse)),
+        ("ApiAccountMeta", schema_for!(ApiAccountMeta)),
+        ("ApiInstruction", schema_for!(ApiInstruction)),
+        (
+            "SwapInstructionsResponse",
+            schema_for!(SwapInstructionsResponse),
+        ),
+        ("PriceResponse", schema_for!(PriceResponse)),
+        (
+            "IndexedRouteMapResponse",
+            schema_for!(IndexedRouteMapResponse),
+        ),
+        ("TransactionStatus", schema_for!(TransactionStatus)),
+        ("BatchQuoteRequest", schema_for!(BatchQuoteRequest)),
+        ("BatchQuoteResponse", schema_for!(BatchQuoteResponse)),
+        ("BatchQuoteError", schema_for!(BatchQuoteError)),
+    ])
+}
+
+/// Writes each schema of `api_schemas` to `<dir>/<TypeName>.json`
+///
+/// # Params
+/// dir - Output directory, created if missing
+pub fn write_schemas(dir: &Path) -> std::io::Result<()> {
+    std::fs::create_dir_all(dir)?;
+    for (name, schema) in api_schemas() {
+        let json = serde_json::to_vec_pretty(&schema).map_err(std::io::Error::other)?;
+        std::fs::write(dir.join(format!("{}.json", name)), json)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,111 @@
+/// Secret module.
+/// Zeroize-on-drop containers for keys and API credentials, redacted from `Debug` output.
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signature},
+    signer::{Signer, SignerError},
+};
+use zeroize::Zeroizing;
+
+use crate::types::JupiterError;
+
+/// String holding secret material, e.g. an API key
+///
+/// The contents are zeroed when dropped and never printed by `Debug`.
+///
+/// # Example
+/// ```rust
+/// use jup_sdk::secret::SecretString;
+///
+/// let key = SecretString::from("my-api-key");
+/// assert_eq!(format!("{:?}", key), "SecretString(..)");
+/// assert_eq!(key.expose_secret(), "my-api-key");
+/// ```
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct SecretString(Zeroizing<String>);
+
+impl SecretString {
+    pub fn new(secret: impl Into<String>) -> Self {
+        Self(Zeroizing::new(secret.into()))
+    }
+
+    /// Borrows the secret; avoid copying it into long-lived values
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<String> for SecretString {
+    fn from(secret: String) -> Self {
+        Self::new(secret)
+    }
+}
+
+impl From<&str> for SecretString {
+    fn from(secret: &str) -> Self {
+        Self::new(secret)
+    }
+}
+
+impl std::fmt::Debug for SecretString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SecretString(..)")
+    }
+}
+
+/// Keypair whose `Debug` output shows only the public key
+///
+/// The signing key is zeroed when dropped, and the bytes it is built from are
+/// zeroed by `from_bytes`. Implements `Signer`, so it can be passed wherever the
+/// SDK takes one.
+pub struct SecretKeypair(Keypair);
+
+impl SecretKeypair {
+    pub fn new(keypair: Keypair) -> Self {
+        Self(keypair)
+    }
+
+    /// Builds a keypair from 64 secret key bytes, zeroing them afterwards
+    pub fn from_bytes(bytes: Zeroizing<Vec<u8>>) -> Result<Self, JupiterError> {
+        Keypair::try_from(bytes.as_slice())
+            .map(Self)
+            .map_err(|e| JupiterError::InvalidInput(format!("invalid keypair bytes: {}", e)))
+    }
+
+    /// Borrows the underlying keypair
+    pub fn expose_secret(&self) -> &Keypair {
+        &self.0
+    }
+}
+
+impl From<Keypair> for SecretKeypair {
+    fn from(keypair: Keypair) -> Self {
+        Self::new(keypair)
+    }
+}
+
+impl std::fmt::Debug for SecretKeypair {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SecretKeypair")
+            .field("pubkey", &self.0.pubkey())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Signer for SecretKeypair {
+    fn try_pubkey(&self) -> Result<Pubkey, SignerError> {
+        self.0.try_pubkey()
+    }
+
+    fn try_sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        self.0.try_sign_message(message)
+    }
+
+    fn is_interactive(&self) -> bool {
+        self.0.is_interactive()
+    }
+}
@@ -0,0 +1,133 @@
+/// Signer loading module.
+/// Loads keypairs from JSON keypair files, base58 secrets, environment variables and
+/// BIP39 seed phrases, for use wherever the SDK takes a `Signer`.
+use bip39::Mnemonic;
+use solana_derivation_path::DerivationPath;
+use solana_keypair::seed_derivable::keypair_from_seed_and_derivation_path;
+use std::path::{Path, PathBuf};
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::{
+    global::DEFAULT_DERIVATION_PATH,
+    secret::{SecretKeypair, SecretString},
+    types::JupiterError,
+};
+
+/// Where to load a keypair from
+#[derive(Debug, Clone)]
+pub enum KeypairSource {
+    /// JSON keypair file as written by `solana-keygen`, a 64-byte array
+    File(PathBuf),
+    /// Base58 encoded 64-byte secret key, as exported by wallets
+    Base58(SecretString),
+    /// Environment variable holding a JSON byte array or a base58 secret
+    Env(String),
+    /// BIP39 mnemonic with optional passphrase and derivation path
+    /// (default `m/44'/501'/0'/0'`)
+    SeedPhrase {
+        phrase: SecretString,
+        passphrase: SecretString,
+        derivation_path: Option<String>,
+    },
+}
+
+impl KeypairSource {
+    /// Loads the keypair
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use jup_sdk::signer::KeypairSource;
+    /// use solana_sdk::signer::Signer;
+    ///
+    /// let keypair = KeypairSource::Env("SOLANA_KEYPAIR".to_string()).load().unwrap();
+    /// println!("wallet: {}", keypair.pubkey());
+    /// ```
+    pub fn load(&self) -> Result<SecretKeypair, JupiterError> {
+        match self {
+            KeypairSource::File(path) => keypair_from_file(path),
+            KeypairSource::Base58(secret) => keypair_from_base58(secret.expose_secret()),
+            KeypairSource::Env(name) => keypair_from_env(name),
+            KeypairSource::SeedPhrase {
+                phrase,
+                passphrase,
+                derivation_path,
+            } => keypair_from_seed_phrase(
+                phrase.expose_secret(),
+                passphrase.expose_secret(),
+                derivation_path.as_deref(),
+            ),
+        }
+    }
+}
+
+/// Loads a keypair from a JSON keypair file
+pub fn keypair_from_file(path: impl AsRef<Path>) -> Result<SecretKeypair, JupiterError> {
+    let path = path.as_ref();
+    let contents = Zeroizing::new(std::fs::read_to_string(path).map_err(|e| {
+        JupiterError::Error(format!("read keypair file {}: {}", path.display(), e))
+    })?);
+    keypair_from_json(&contents)
+}
+
+/// Parses a keypair from a JSON byte array
+pub fn keypair_from_json(json: &str) -> Result<SecretKeypair, JupiterError> {
+    let bytes: Vec<u8> = serde_json::from_str(json.trim())
+        .map_err(|e| JupiterError::InvalidInput(format!("invalid keypair JSON: {}", e)))?;
+    SecretKeypair::from_bytes(Zeroizing::new(bytes))
+}
+
+/// Parses a keypair from a base58 encoded secret key
+pub fn keypair_from_base58(secret: &str) -> Result<SecretKeypair, JupiterError> {
+    let bytes = bs58::decode(secret.trim())
+        .into_vec()
+        .map_err(|e| JupiterError::InvalidInput(format!("invalid base58 secret: {}", e)))?;
+    SecretKeypair::from_bytes(Zeroizing::new(bytes))
+}
+
+/// Loads a keypair from an environment variable holding a JSON byte array or a
+/// base58 secret key
+pub fn keypair_from_env(name: &str) -> Result<SecretKeypair, JupiterError> {
+    let value = Zeroizing::new(std::env::var(name).map_err(|e| {
+        JupiterError::InvalidInput(format!("environment variable {}: {}", name, e))
+    })?);
+    if value.trim_start().starts_with('[') {
+        keypair_from_json(&value)
+    } else {
+        keypair_from_base58(&value)
+    }
+}
+
+/// Derives a keypair from a BIP39 mnemonic
+///
+/// # Params
+/// phrase - BIP39 mnemonic, validated against the English word list
+/// passphrase - Optional BIP39 passphrase, empty for none
+/// derivation_path - Derivation path, `m/44'/501'/0'/0'` when `None`
+///
+/// # Example
+/// ```rust
+/// use jup_sdk::signer::keypair_from_seed_phrase;
+/// use solana_sdk::signer::Signer;
+///
+/// let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+/// let first = keypair_from_seed_phrase(phrase, "", None).unwrap();
+/// let second = keypair_from_seed_phrase(phrase, "", Some("m/44'/501'/1'/0'")).unwrap();
+/// assert_ne!(first.pubkey(), second.pubkey());
+/// ```
+pub fn keypair_from_seed_phrase(
+    phrase: &str,
+    passphrase: &str,
+    derivation_path: Option<&str>,
+) -> Result<SecretKeypair, JupiterError> {
+    let mut mnemonic = Mnemonic::parse_normalized(phrase.trim())
+        .map_err(|e| JupiterError::InvalidInput(format!("invalid seed phrase: {}", e)))?;
+    let path = derivation_path.unwrap_or(DEFAULT_DERIVATION_PATH);
+    let path = DerivationPath::from_absolute_path_str(path).map_err(|e| {
+        JupiterError::InvalidInput(format!("invalid derivation path {}: {}", path, e))
+    })?;
+    let seed = Zeroizing::new(mnemonic.to_seed(passphrase));
+    mnemonic.zeroize();
+    keypair_from_seed_and_derivation_path(seed.as_slice(), Some(path))
+        .map(SecretKeypair::new)
+        .map_err(|e| JupiterError::SigningError(format!("derive keypair: {}", e)))
+}
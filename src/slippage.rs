@@ -0,0 +1,155 @@
+/// Slippage module.
+/// Recommends slippage tolerances from the recent price volatility of each pair.
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use crate::{global::DEFAULT_SLIPPAGE_BPS, types::QuoteResponse};
+
+/// Minimum number of price samples needed to estimate volatility
+const MIN_SAMPLES: usize = 3;
+/// Extra tolerance per additional route hop, in basis points
+const HOP_SLIPPAGE_BPS: f64 = 5.0;
+
+/// Configuration of the slippage advisor
+#[derive(Debug, Clone)]
+pub struct SlippageAdvisorConfig {
+    /// Maximum number of price samples kept per pair
+    pub window: usize,
+    /// Samples older than this are ignored
+    pub max_age: Duration,
+    /// Lower bound of recommendations
+    pub min_bps: u16,
+    /// Upper bound of recommendations
+    pub max_bps: u16,
+    /// Recommendation while a pair has too few samples
+    pub default_bps: u16,
+    /// Multiple of the price change standard deviation to tolerate
+    pub volatility_multiplier: f64,
+}
+
+impl Default for SlippageAdvisorConfig {
+    fn default() -> Self {
+        Self {
+            window: 64,
+            max_age: Duration::from_secs(300),
+            min_bps: 10,
+            max_bps: 300,
+            default_bps: DEFAULT_SLIPPAGE_BPS,
+            volatility_multiplier: 3.0,
+        }
+    }
+}
+
+/// Tracks recent prices per pair and recommends slippage bps
+///
+/// Cheap to clone; clones share their samples.
+#[derive(Debug, Clone, Default)]
+pub struct SlippageAdvisor {
+    config: SlippageAdvisorConfig,
+    samples: Arc<Mutex<HashMap<(String, String), VecDeque<(Instant, f64)>>>>,
+}
+
+impl SlippageAdvisor {
+    pub fn new(config: SlippageAdvisorConfig) -> Self {
+        Self {
+            config,
+            samples: Arc::default(),
+        }
+    }
+
+    pub fn config(&self) -> &SlippageAdvisorConfig {
+        &self.config
+    }
+
+    /// Records a price of `output_mint` per `input_mint`
+    pub fn observe(&self, input_mint: &str, output_mint: &str, price: f64) {
+        if !price.is_finite() || price <= 0.0 {
+            return;
+        }
+        let mut samples = self.samples.lock().unwrap();
+        let pair = samples
+            .entry((input_mint.to_string(), output_mint.to_string()))
+            .or_default();
+        pair.push_back((Instant::now(), price));
+        while pair.len() > self.config.window.max(MIN_SAMPLES) {
+            pair.pop_front();
+        }
+    }
+
+    /// Records the price implied by a quote
+    pub fn observe_quote(&self, quote: &QuoteResponse) {
+        let in_amount: f64 = quote.in_amount.parse().unwrap_or(0.0);
+        let out_amount: f64 = quote.out_amount.parse().unwrap_or(0.0);
+        if in_amount > 0.0 {
+            self.observe(
+                &quote.input_mint,
+                &quote.output_mint,
+                out_amount / in_amount,
+            );
+        }
+    }
+
+    /// Standard deviation of successive relative price changes, in basis points
+    ///
+    /// Returns `None` while the pair has fewer than three recent samples.
+    pub fn volatility_bps(&self, input_mint: &str, output_mint: &str) -> Option<f64> {
+        let samples = self.samples.lock().unwrap();
+        let pair = samples.get(&(input_mint.to_string(), output_mint.to_string()))?;
+        let prices: Vec<f64> = pair
+            .iter()
+            .filter(|(at, _)| at.elapsed() <= self.config.max_age)
+            .map(|(_, price)| *price)
+            .collect();
+        if prices.len() < MIN_SAMPLES {
+            return None;
+        }
+        let changes: Vec<f64> = prices
+            .windows(2)
+            .map(|w| (w[1] - w[0]) / w[0] * 10_000.0)
+            .collect();
+        let mean = changes.iter().sum::<f64>() / changes.len() as f64;
+        let variance =
+            changes.iter().map(|c| (c - mean).powi(2)).sum::<f64>() / changes.len() as f64;
+        Some(variance.sqrt())
+    }
+
+    /// Recommended slippage bps for a pair, within `min_bps..=max_bps`
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::slippage::SlippageAdvisor;
+    ///
+    /// let advisor = SlippageAdvisor::default();
+    /// for price in [100.0, 100.5, 99.8, 100.2] {
+    ///     advisor.observe("SOL", "USDC", price);
+    /// }
+    /// let bps = advisor.recommend("SOL", "USDC");
+    /// assert!((10..=300).contains(&bps));
+    /// ```
+    pub fn recommend(&self, input_mint: &str, output_mint: &str) -> u16 {
+        match self.volatility_bps(input_mint, output_mint) {
+            Some(volatility) => self.clamp(volatility * self.config.volatility_multiplier),
+            None => self.config.default_bps,
+        }
+    }
+
+    /// Recommended slippage bps for a quote, adding tolerance for each extra hop
+    pub fn recommend_for_quote(&self, quote: &QuoteResponse) -> u16 {
+        let base = self.recommend(&quote.input_mint, &quote.output_mint) as f64;
+        let extra_hops = quote.route_plan.len().saturating_sub(1) as f64;
+        self.clamp(base + extra_hops * HOP_SLIPPAGE_BPS)
+    }
+
+    fn clamp(&self, bps: f64) -> u16 {
+        bps.ceil()
+            .clamp(self.config.min_bps as f64, self.config.max_bps as f64) as u16
+    }
+
+    /// Drops all samples
+    pub fn clear(&self) {
+        self.samples.lock().unwrap().clear();
+    }
+}
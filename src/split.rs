@@ -0,0 +1,170 @@
+/// Split routing module.
+/// Plans orders split across alternative routes over disjoint venue sets, for sizes
+/// where the combined output beats the single best route.
+use crate::{
+    global::DEFAULT_SPLIT_SHARES,
+    types::{QuoteRequest, QuoteResponse, SwapRequest},
+};
+
+/// How the legs of a split order are submitted
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SplitExecution {
+    /// One leg after another, stopping at the first leg that doesn't confirm
+    #[default]
+    Sequential,
+    /// All legs in one transaction; fails if they don't fit
+    Atomic,
+}
+
+/// Configuration of the split planner
+#[derive(Debug, Clone)]
+pub struct SplitConfig {
+    /// Maximum number of alternative routes, including the best one
+    pub max_routes: usize,
+    /// Candidate splits as shares in basis points, best route first; splits not
+    /// summing to 10 000 or needing more routes than found are skipped
+    pub splits: Vec<Vec<u16>>,
+    /// Minimum improvement over the single best route to split at all
+    pub min_improvement_bps: f64,
+    pub execution: SplitExecution,
+}
+
+impl Default for SplitConfig {
+    fn default() -> Self {
+        Self {
+            max_routes: 2,
+            splits: DEFAULT_SPLIT_SHARES
+                .iter()
+                .map(|shares| shares.to_vec())
+                .collect(),
+            min_improvement_bps: 5.0,
+            execution: SplitExecution::default(),
+        }
+    }
+}
+
+impl SplitConfig {
+    pub fn with_max_routes(mut self, max_routes: usize) -> Self {
+        self.max_routes = max_routes;
+        self
+    }
+
+    pub fn with_splits(mut self, splits: Vec<Vec<u16>>) -> Self {
+        self.splits = splits;
+        self
+    }
+
+    pub fn with_execution(mut self, execution: SplitExecution) -> Self {
+        self.execution = execution;
+        self
+    }
+
+    /// Candidate splits usable with `routes` alternative routes
+    pub(crate) fn candidate_splits(&self, routes: usize) -> impl Iterator<Item = &Vec<u16>> {
+        self.splits.iter().filter(move |shares| {
+            shares.len() >= 2
+                && shares.len() <= routes
+                && shares.iter().map(|share| *share as u32).sum::<u32>() == 10_000
+        })
+    }
+}
+
+/// One leg of a split order
+#[derive(Debug, Clone)]
+pub struct SplitLeg {
+    /// Share of the order in basis points
+    pub share_bps: u16,
+    /// Input amount of this leg (raw units)
+    pub amount: u64,
+    /// DEXes excluded to keep this leg off the venues of the better routes
+    pub excluded_dexes: Vec<String>,
+    pub quote: QuoteResponse,
+}
+
+impl SplitLeg {
+    pub fn out_amount(&self) -> u64 {
+        self.quote.out_amount.parse().unwrap_or(0)
+    }
+}
+
+/// Order split across alternative routes
+#[derive(Debug, Clone)]
+pub struct SplitPlan {
+    pub legs: Vec<SplitLeg>,
+    /// Combined output of all legs (raw units)
+    pub total_out: u64,
+    /// Output of the single best route for the whole order
+    pub single_route_out: u64,
+    /// Improvement of `total_out` over `single_route_out`, in basis points
+    pub improvement_bps: f64,
+    pub execution: SplitExecution,
+}
+
+impl SplitPlan {
+    /// Builds a plan from quoted legs
+    pub fn new(legs: Vec<SplitLeg>, single_route_out: u64, execution: SplitExecution) -> Self {
+        let total_out = legs.iter().map(SplitLeg::out_amount).sum();
+        let improvement_bps = if single_route_out > 0 {
+            (total_out as f64 - single_route_out as f64) / single_route_out as f64 * 10_000.0
+        } else {
+            0.0
+        };
+        Self {
+            legs,
+            total_out,
+            single_route_out,
+            improvement_bps,
+            execution,
+        }
+    }
+
+    /// Swap requests of the legs, in order
+    pub fn swap_requests(&self, user_public_key: &str) -> Vec<SwapRequest> {
+        self.legs
+            .iter()
+            .map(|leg| SwapRequest {
+                quote_response: leg.quote.clone(),
+                user_public_key: user_public_key.to_string(),
+                wrap_and_unwrap_sol: Some(true),
+                compute_unit_price: None,
+                prioritization_fee_lamports: None,
+                as_legacy_transaction: None,
+                destination_token_account: None,
+            })
+            .collect()
+    }
+}
+
+/// Splits `amount` by `shares`, the last leg taking the rounding remainder
+pub(crate) fn split_amounts(amount: u64, shares: &[u16]) -> Vec<u64> {
+    let mut amounts: Vec<u64> = shares
+        .iter()
+        .map(|share| (amount as u128 * *share as u128 / 10_000) as u64)
+        .collect();
+    if let Some(last) = amounts.last_mut() {
+        let others: u64 = shares
+            .iter()
+            .take(shares.len() - 1)
+            .map(|share| (amount as u128 * *share as u128 / 10_000) as u64)
+            .sum();
+        *last = amount - others;
+    }
+    amounts
+}
+
+/// Quote parameters of a leg: `request` at `amount`, excluding `excluded_dexes`
+pub(crate) fn leg_params(
+    request: &QuoteRequest,
+    amount: u64,
+    excluded_dexes: &[String],
+) -> Vec<(&'static str, String)> {
+    let mut params = QuoteRequest {
+        amount,
+        ..request.clone()
+    }
+    .to_query_params();
+    if !excluded_dexes.is_empty() {
+        params.push(("excludeDexes", excluded_dexes.join(",")));
+    }
+    params
+}
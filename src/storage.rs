@@ -0,0 +1,227 @@
+/// Storage module.
+/// Durable key-value persistence for SDK state such as orders and idempotency records.
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use crate::types::JupiterError;
+
+/// Storage key prefix of idempotency records
+pub const IDEMPOTENCY_KEY_PREFIX: &str = "idempotency:";
+
+/// Transaction submitted under an idempotency key
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IdempotencyRecord {
+    pub signature: String,
+    /// Unix time in milliseconds
+    pub submitted_at: u64,
+}
+
+/// Key-value persistence backend
+///
+/// Unlike `Cache`, entries never expire and failures are reported, since the data is
+/// bookkeeping that must survive restarts.
+#[async_trait]
+pub trait Storage: Send + Sync + std::fmt::Debug {
+    /// Gets a value if present
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, JupiterError>;
+
+    /// Stores a value, replacing any previous one
+    async fn put(&self, key: &str, value: Vec<u8>) -> Result<(), JupiterError>;
+
+    /// Removes a value; removing a missing key is not an error
+    async fn delete(&self, key: &str) -> Result<(), JupiterError>;
+
+    /// Lists all entries whose key starts with `prefix`, ordered by key
+    async fn list(&self, prefix: &str) -> Result<Vec<(String, Vec<u8>)>, JupiterError>;
+
+    /// Stores a value only if the key is absent, returning whether it was stored
+    ///
+    /// The default implementation is not atomic across processes; backends shared
+    /// between processes should override it.
+    async fn put_if_absent(&self, key: &str, value: Vec<u8>) -> Result<bool, JupiterError> {
+        if self.get(key).await?.is_some() {
+            return Ok(false);
+        }
+        self.put(key, value).await?;
+        Ok(true)
+    }
+}
+
+/// In-process storage, lost when the process exits
+#[derive(Debug, Default)]
+pub struct MemoryStorage {
+    entries: Mutex<BTreeMap<String, Vec<u8>>>,
+}
+
+impl MemoryStorage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl Storage for MemoryStorage {
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, JupiterError> {
+        Ok(self.entries.lock().unwrap().get(key).cloned())
+    }
+
+    async fn put(&self, key: &str, value: Vec<u8>) -> Result<(), JupiterError> {
+        self.entries.lock().unwrap().insert(key.to_string(), value);
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), JupiterError> {
+        self.entries.lock().unwrap().remove(key);
+        Ok(())
+    }
+
+    async fn list(&self, prefix: &str) -> Result<Vec<(String, Vec<u8>)>, JupiterError> {
+        Ok(self
+            .entries
+            .lock()
+            .unwrap()
+            .range(prefix.to_string()..)
+            .take_while(|(key, _)| key.starts_with(prefix))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect())
+    }
+
+    async fn put_if_absent(&self, key: &str, value: Vec<u8>) -> Result<bool, JupiterError> {
+        let mut entries = self.entries.lock().unwrap();
+        if entries.contains_key(key) {
+            return Ok(false);
+        }
+        entries.insert(key.to_string(), value);
+        Ok(true)
+    }
+}
+
+/// Storage keeping one file per key in a directory
+///
+/// File names are the hex encoded keys, so keys are limited to 127 bytes. Values are
+/// written to a temporary file and renamed, so a crash never leaves a partially
+/// written entry.
+///
+/// # Example
+/// ```rust,no_run
+/// use jup_sdk::storage::FileStorage;
+///
+/// let storage = FileStorage::new("./jup-state").unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct FileStorage {
+    dir: PathBuf,
+}
+
+impl FileStorage {
+    /// Opens a storage directory, creating it if needed
+    pub fn new(dir: impl AsRef<Path>) -> Result<Self, JupiterError> {
+        let dir = dir.as_ref().to_path_buf();
+        std::fs::create_dir_all(&dir)
+            .map_err(|e| JupiterError::Error(format!("create {}: {}", dir.display(), e)))?;
+        Ok(Self { dir })
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        let name: String = key.bytes().map(|b| format!("{:02x}", b)).collect();
+        self.dir.join(name)
+    }
+
+    fn decode_key(name: &str) -> Option<String> {
+        if name.len() % 2 != 0 {
+            return None;
+        }
+        let bytes = (0..name.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(name.get(i..i + 2)?, 16).ok())
+            .collect::<Option<Vec<u8>>>()?;
+        String::from_utf8(bytes).ok()
+    }
+}
+
+fn io_error(action: &str, path: &Path, e: std::io::Error) -> JupiterError {
+    JupiterError::Error(format!("{} {}: {}", action, path.display(), e))
+}
+
+#[async_trait]
+impl Storage for FileStorage {
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, JupiterError> {
+        let path = self.path(key);
+        match tokio::fs::read(&path).await {
+            Ok(value) => Ok(Some(value)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(io_error("read", &path, e)),
+        }
+    }
+
+    async fn put(&self, key: &str, value: Vec<u8>) -> Result<(), JupiterError> {
+        let path = self.path(key);
+        let temp = path.with_extension("tmp");
+        tokio::fs::write(&temp, value)
+            .await
+            .map_err(|e| io_error("write", &temp, e))?;
+        tokio::fs::rename(&temp, &path)
+            .await
+            .map_err(|e| io_error("rename", &path, e))
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), JupiterError> {
+        let path = self.path(key);
+        match tokio::fs::remove_file(&path).await {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(io_error("remove", &path, e)),
+            _ => Ok(()),
+        }
+    }
+
+    async fn list(&self, prefix: &str) -> Result<Vec<(String, Vec<u8>)>, JupiterError> {
+        let mut entries = Vec::new();
+        let mut dir = tokio::fs::read_dir(&self.dir)
+            .await
+            .map_err(|e| io_error("read", &self.dir, e))?;
+        while let Some(entry) = dir
+            .next_entry()
+            .await
+            .map_err(|e| io_error("read", &self.dir, e))?
+        {
+            let Some(key) = entry.file_name().to_str().and_then(Self::decode_key) else {
+                continue;
+            };
+            if key.starts_with(prefix)
+                && let Some(value) = self.get(&key).await?
+            {
+                entries.push((key, value));
+            }
+        }
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        Ok(entries)
+    }
+
+    async fn put_if_absent(&self, key: &str, value: Vec<u8>) -> Result<bool, JupiterError> {
+        let path = self.path(key);
+        // create_new fails if the entry exists, making the check atomic on one host
+        match tokio::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+            .await
+        {
+            Ok(mut file) => {
+                use tokio::io::AsyncWriteExt;
+                file.write_all(&value)
+                    .await
+                    .map_err(|e| io_error("write", &path, e))?;
+                file.sync_all()
+                    .await
+                    .map_err(|e| io_error("write", &path, e))?;
+                Ok(true)
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => Ok(false),
+            Err(e) => Err(io_error("create", &path, e)),
+        }
+    }
+}
@@ -0,0 +1,115 @@
+/// Swap summary module.
+/// Human-readable summaries of quotes for logs, CLIs and chat notifications.
+use crate::{amount::TokenAmount, token_map::TokenMap, types::QuoteResponse};
+
+/// Fractional digits shown for amounts in summaries
+const SUMMARY_DECIMALS: u32 = 4;
+
+/// One side of a summarized swap
+#[derive(Debug, Clone, PartialEq)]
+pub struct SummaryAmount {
+    pub mint: String,
+    /// Registry symbol, or the shortened mint for tokens missing from the registry
+    pub symbol: String,
+    /// Amount with decimals, `None` for tokens missing from the registry
+    pub amount: Option<TokenAmount>,
+    /// Raw amount as quoted
+    pub raw: String,
+}
+
+impl SummaryAmount {
+    fn new(tokens: &TokenMap, mint: &str, raw: &str) -> Self {
+        let token = tokens.get(mint);
+        Self {
+            mint: mint.to_string(),
+            symbol: token
+                .as_ref()
+                .map(|token| token.symbol.clone())
+                .unwrap_or_else(|| short_mint(mint)),
+            amount: token.and_then(|token| raw.parse().ok().map(|raw| token.amount(raw))),
+            raw: raw.to_string(),
+        }
+    }
+
+    /// Amount rounded for display, or the raw amount without decimals
+    pub fn display_amount(&self) -> String {
+        match &self.amount {
+            Some(amount) => amount
+                .to_decimal()
+                .round_dp(SUMMARY_DECIMALS)
+                .normalize()
+                .to_string(),
+            None => self.raw.clone(),
+        }
+    }
+}
+
+impl std::fmt::Display for SummaryAmount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.display_amount(), self.symbol)
+    }
+}
+
+/// Structured summary of a quote
+///
+/// Displays as e.g. `Swap 1.5 SOL → 228.4 USDC via Whirlpool→Meteora, impact 0.12%,
+/// min received 227.2`; ExactOut quotes show the maximum sent instead.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SwapSummary {
+    pub input: SummaryAmount,
+    pub output: SummaryAmount,
+    /// Slippage-adjusted bound: minimum output for ExactIn, maximum input for ExactOut
+    pub threshold: SummaryAmount,
+    pub exact_out: bool,
+    /// Venue labels in route order
+    pub venues: Vec<String>,
+    pub price_impact_pct: f64,
+    pub slippage_bps: u16,
+}
+
+impl SwapSummary {
+    pub fn new(quote: &QuoteResponse, tokens: &TokenMap) -> Self {
+        let exact_out = quote.swap_mode.eq_ignore_ascii_case("ExactOut");
+        let threshold_mint = if exact_out {
+            &quote.input_mint
+        } else {
+            &quote.output_mint
+        };
+        Self {
+            input: SummaryAmount::new(tokens, &quote.input_mint, &quote.in_amount),
+            output: SummaryAmount::new(tokens, &quote.output_mint, &quote.out_amount),
+            threshold: SummaryAmount::new(tokens, threshold_mint, &quote.other_amount_threshold),
+            exact_out,
+            venues: quote
+                .venues()
+                .into_iter()
+                .map(|venue| venue.label().to_string())
+                .collect(),
+            price_impact_pct: quote.price_impact_pct.parse().unwrap_or(0.0),
+            slippage_bps: quote.slippage_bps,
+        }
+    }
+}
+
+impl std::fmt::Display for SwapSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Swap {} → {}", self.input, self.output)?;
+        if !self.venues.is_empty() {
+            write!(f, " via {}", self.venues.join("→"))?;
+        }
+        write!(f, ", impact {:.2}%", self.price_impact_pct)?;
+        if self.exact_out {
+            write!(f, ", max sent {}", self.threshold.display_amount())
+        } else {
+            write!(f, ", min received {}", self.threshold.display_amount())
+        }
+    }
+}
+
+/// Shortens a mint for display, e.g. `So11…1112`
+fn short_mint(mint: &str) -> String {
+    match (mint.get(..4), mint.get(mint.len().saturating_sub(4)..)) {
+        (Some(head), Some(tail)) if mint.len() > 8 => format!("{}…{}", head, tail),
+        _ => mint.to_string(),
+    }
+}
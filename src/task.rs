@@ -0,0 +1,169 @@
+/// Background task module.
+/// Handles for long-lived tasks spawned by the SDK, with graceful shutdown.
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+
+/// Handle to a background task spawned by the SDK
+///
+/// The task receives a cancellation token; on cancellation it should stop polling,
+/// flush anything it persists and return. `shutdown` cancels and waits for that.
+/// Dropping the handle cancels the task without waiting; call `detach` to keep it
+/// running instead.
+#[derive(Debug)]
+pub struct BackgroundTask {
+    token: CancellationToken,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl BackgroundTask {
+    /// Spawns a task that receives a cancellation token to observe
+    pub fn spawn<F, Fut>(task: F) -> Self
+    where
+        F: FnOnce(CancellationToken) -> Fut,
+        Fut: std::future::Future<Output = ()> + Send + 'static,
+    {
+        Self::spawn_with_token(CancellationToken::new(), task)
+    }
+
+    /// Spawns a task observing `token`, e.g. a child token of a `TaskGroup`
+    pub fn spawn_with_token<F, Fut>(token: CancellationToken, task: F) -> Self
+    where
+        F: FnOnce(CancellationToken) -> Fut,
+        Fut: std::future::Future<Output = ()> + Send + 'static,
+    {
+        let handle = tokio::spawn(task(token.clone()));
+        Self {
+            token,
+            handle: Some(handle),
+        }
+    }
+
+    /// Signals the task to stop and waits for it to finish
+    pub async fn shutdown(mut self) {
+        self.token.cancel();
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.await;
+        }
+    }
+
+    /// Signals the task to stop and waits up to `timeout` for it to finish, aborting
+    /// it afterwards
+    ///
+    /// Returns whether the task finished on its own.
+    pub async fn shutdown_timeout(mut self, timeout: Duration) -> bool {
+        self.token.cancel();
+        let Some(mut handle) = self.handle.take() else {
+            return true;
+        };
+        match tokio::time::timeout(timeout, &mut handle).await {
+            Ok(_) => true,
+            Err(_) => {
+                tracing::warn!("background task did not stop in time, aborting");
+                handle.abort();
+                false
+            }
+        }
+    }
+
+    /// Lets the task keep running after the handle is dropped
+    pub fn detach(mut self) {
+        self.handle.take();
+    }
+
+    /// Whether the task has finished
+    pub fn is_finished(&self) -> bool {
+        self.handle
+            .as_ref()
+            .is_none_or(|handle| handle.is_finished())
+    }
+}
+
+impl Drop for BackgroundTask {
+    fn drop(&mut self) {
+        if self.handle.is_some() {
+            self.token.cancel();
+        }
+    }
+}
+
+/// Set of background tasks shut down together
+///
+/// Tasks spawned through the group observe child tokens of the group's token, so
+/// cancelling the group stops all of them.
+///
+/// # Example
+/// ```rust
+/// use jup_sdk::{JupiterClient, task::TaskGroup};
+/// use std::time::Duration;
+///
+/// async fn run(client: JupiterClient) {
+///     let mut tasks = TaskGroup::new();
+///     tasks.push(client.start_keep_alive(Duration::from_secs(30)));
+///     tasks.spawn(|token| async move {
+///         token.cancelled().await;
+///         // flush state here before returning
+///     });
+///     tasks.shutdown().await;
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct TaskGroup {
+    token: CancellationToken,
+    tasks: Vec<BackgroundTask>,
+}
+
+impl TaskGroup {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Spawns a task into the group
+    pub fn spawn<F, Fut>(&mut self, task: F)
+    where
+        F: FnOnce(CancellationToken) -> Fut,
+        Fut: std::future::Future<Output = ()> + Send + 'static,
+    {
+        let task = BackgroundTask::spawn_with_token(self.token.child_token(), task);
+        self.tasks.push(task);
+    }
+
+    /// Adds a task spawned elsewhere, e.g. by a client
+    pub fn push(&mut self, task: BackgroundTask) {
+        self.tasks.push(task);
+    }
+
+    /// Token cancelled when the group shuts down
+    pub fn token(&self) -> &CancellationToken {
+        &self.token
+    }
+
+    pub fn len(&self) -> usize {
+        self.tasks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+    }
+
+    /// Signals every task to stop, then waits for all of them
+    pub async fn shutdown(self) {
+        self.token.cancel();
+        futures::future::join_all(self.tasks.into_iter().map(BackgroundTask::shutdown)).await;
+    }
+
+    /// Like `shutdown`, but aborts tasks still running after `timeout`
+    ///
+    /// Returns whether every task finished on its own.
+    pub async fn shutdown_timeout(self, timeout: Duration) -> bool {
+        self.token.cancel();
+        futures::future::join_all(
+            self.tasks
+                .into_iter()
+                .map(|task| task.shutdown_timeout(timeout)),
+        )
+        .await
+        .into_iter()
+        .all(|finished| finished)
+    }
+}
@@ -0,0 +1,31 @@
+/// Telemetry module.
+/// Propagates the current tracing span to the Jupiter API as a W3C trace context.
+use opentelemetry::trace::TraceContextExt;
+use reqwest::RequestBuilder;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+/// Adds `traceparent` (and `tracestate`, if any) headers for the current span
+///
+/// Requests are left untouched when no OpenTelemetry layer is installed or the
+/// current span is not sampled into a valid trace.
+pub(crate) fn inject_trace_context(request_builder: RequestBuilder) -> RequestBuilder {
+    let context = tracing::Span::current().context();
+    let span = context.span();
+    let span_context = span.span_context();
+    if !span_context.is_valid() {
+        return request_builder;
+    }
+    let traceparent = format!(
+        "00-{}-{}-{:02x}",
+        span_context.trace_id(),
+        span_context.span_id(),
+        span_context.trace_flags().to_u8()
+    );
+    let request_builder = request_builder.header("traceparent", traceparent);
+    let tracestate = span_context.trace_state().header();
+    if tracestate.is_empty() {
+        request_builder
+    } else {
+        request_builder.header("tracestate", tracestate)
+    }
+}
@@ -0,0 +1,127 @@
+/// Adaptive throttling module.
+/// Tightens per-endpoint request rates when the API answers 429 and slowly recovers.
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// Configuration of the adaptive throttle
+#[derive(Debug, Clone)]
+pub struct AdaptiveThrottleConfig {
+    /// Rate (requests per second) at which throttling is lifted again
+    /// The static `rate_limit_requests_per_second` takes precedence when lower.
+    pub max_rate: f64,
+    /// Lowest rate the throttle tightens to
+    pub min_rate: f64,
+    /// Factor applied to the rate on every 429 response
+    pub decrease_factor: f64,
+    /// Requests per second regained for every second without a 429
+    pub recovery_per_second: f64,
+}
+
+impl Default for AdaptiveThrottleConfig {
+    fn default() -> Self {
+        Self {
+            max_rate: 50.0,
+            min_rate: 0.5,
+            decrease_factor: 0.5,
+            recovery_per_second: 0.2,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct EndpointThrottle {
+    /// Current rate, `None` while the endpoint is not throttled
+    rate: Option<f64>,
+    next_slot: Instant,
+    updated: Instant,
+}
+
+/// Per-endpoint throttle driven by observed rate-limit responses
+#[derive(Debug, Clone)]
+pub struct AdaptiveThrottle {
+    config: AdaptiveThrottleConfig,
+    ceiling: f64,
+    endpoints: Arc<Mutex<HashMap<String, EndpointThrottle>>>,
+}
+
+impl AdaptiveThrottle {
+    /// Creates a throttle, capped by the static rate limit if one is configured
+    pub fn new(config: AdaptiveThrottleConfig, static_rate: Option<u32>) -> Self {
+        let ceiling = static_rate
+            .map(|rate| (rate as f64).min(config.max_rate))
+            .unwrap_or(config.max_rate);
+        Self {
+            config,
+            ceiling,
+            endpoints: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Current rate of `endpoint`, or `None` if it is not throttled
+    pub fn current_rate(&self, endpoint: &str) -> Option<f64> {
+        let mut endpoints = self.endpoints.lock().unwrap();
+        let state = endpoints.get_mut(endpoint)?;
+        self.recover(state, Instant::now());
+        state.rate
+    }
+
+    /// Waits for the next request slot of `endpoint`
+    pub async fn acquire(&self, endpoint: &str) {
+        let now = Instant::now();
+        let slot = {
+            let mut endpoints = self.endpoints.lock().unwrap();
+            let Some(state) = endpoints.get_mut(endpoint) else {
+                return;
+            };
+            self.recover(state, now);
+            let slot = state.next_slot.max(now);
+            if let Some(rate) = state.rate {
+                state.next_slot = slot + Duration::from_secs_f64(1.0 / rate);
+            }
+            slot
+        };
+        if slot > now {
+            tokio::time::sleep(slot - now).await;
+        }
+    }
+
+    /// Records a rate-limit response, tightening the endpoint's rate
+    ///
+    /// # Params
+    /// endpoint - Endpoint that answered 429
+    /// retry_after - Delay requested by the `Retry-After` header, if any
+    pub fn record_rate_limited(&self, endpoint: &str, retry_after: Option<Duration>) {
+        let now = Instant::now();
+        let mut endpoints = self.endpoints.lock().unwrap();
+        let state = endpoints
+            .entry(endpoint.to_string())
+            .or_insert(EndpointThrottle {
+                rate: None,
+                next_slot: now,
+                updated: now,
+            });
+        self.recover(state, now);
+        let rate = (state.rate.unwrap_or(self.ceiling) * self.config.decrease_factor)
+            .max(self.config.min_rate);
+        state.rate = Some(rate);
+        state.updated = now;
+        if let Some(retry_after) = retry_after {
+            state.next_slot = state.next_slot.max(now + retry_after);
+        }
+        tracing::warn!(endpoint, rate, "rate limited, tightening throttle");
+    }
+
+    /// Regains rate linearly since the last update, lifting the throttle at the ceiling
+    fn recover(&self, state: &mut EndpointThrottle, now: Instant) {
+        let Some(rate) = state.rate else {
+            return;
+        };
+        let elapsed = now.saturating_duration_since(state.updated).as_secs_f64();
+        let rate = rate + elapsed * self.config.recovery_per_second;
+        state.updated = now;
+        state.rate = (rate < self.ceiling).then_some(rate);
+    }
+}
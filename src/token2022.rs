@@ -0,0 +1,119 @@
+/// Token-2022 module.
+/// Transfer-fee extension parsing and fee calculation for Token-2022 mints.
+use serde::{Deserialize, Serialize};
+
+/// Size of the base mint layout, padded to the size of a token account
+const MINT_PADDED_SIZE: usize = 165;
+/// Account type byte identifying a mint
+const ACCOUNT_TYPE_MINT: u8 = 1;
+/// TLV extension type of `TransferFeeConfig`
+const EXTENSION_TRANSFER_FEE_CONFIG: u16 = 1;
+/// Size of the `TransferFeeConfig` extension
+const TRANSFER_FEE_CONFIG_SIZE: usize = 108;
+/// Maximum transfer fee in basis points
+const MAX_FEE_BASIS_POINTS: u64 = 10_000;
+
+/// Transfer fee charged on every transfer of a Token-2022 mint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TransferFee {
+    /// First epoch the fee applies to
+    pub epoch: u64,
+    /// Maximum fee per transfer (raw units)
+    pub maximum_fee: u64,
+    /// Fee in basis points of the transferred amount
+    pub basis_points: u16,
+}
+
+impl TransferFee {
+    /// Fee withheld when transferring `amount`, rounded up and capped at `maximum_fee`
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::token2022::TransferFee;
+    ///
+    /// let fee = TransferFee { epoch: 0, maximum_fee: 5_000, basis_points: 100 };
+    /// assert_eq!(fee.calculate_fee(10_000), 100);
+    /// assert_eq!(fee.calculate_fee(10_000_000), 5_000);
+    /// ```
+    pub fn calculate_fee(&self, amount: u64) -> u64 {
+        if self.basis_points == 0 || amount == 0 {
+            return 0;
+        }
+        let fee =
+            (amount as u128 * self.basis_points as u128).div_ceil(MAX_FEE_BASIS_POINTS as u128);
+        (fee as u64).min(self.maximum_fee)
+    }
+
+    /// Amount received by the recipient of a transfer of `amount`
+    pub fn amount_after_fee(&self, amount: u64) -> u64 {
+        amount.saturating_sub(self.calculate_fee(amount))
+    }
+}
+
+/// `TransferFeeConfig` extension of a Token-2022 mint
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransferFeeConfig {
+    /// Fees withheld in the mint, not yet harvested
+    pub withheld_amount: u64,
+    pub older_transfer_fee: TransferFee,
+    pub newer_transfer_fee: TransferFee,
+}
+
+impl TransferFeeConfig {
+    /// Parses the extension from Token-2022 mint account data
+    ///
+    /// Returns `None` for mints without the transfer-fee extension, including
+    /// all SPL Token mints.
+    pub fn from_mint_data(data: &[u8]) -> Option<Self> {
+        if data.len() <= MINT_PADDED_SIZE || data[MINT_PADDED_SIZE] != ACCOUNT_TYPE_MINT {
+            return None;
+        }
+        let mut offset = MINT_PADDED_SIZE + 1;
+        while offset + 4 <= data.len() {
+            let extension_type = u16::from_le_bytes([data[offset], data[offset + 1]]);
+            let length = u16::from_le_bytes([data[offset + 2], data[offset + 3]]) as usize;
+            let value = data.get(offset + 4..offset + 4 + length)?;
+            if extension_type == EXTENSION_TRANSFER_FEE_CONFIG {
+                return Self::from_extension(value);
+            }
+            offset += 4 + length;
+        }
+        None
+    }
+
+    fn from_extension(value: &[u8]) -> Option<Self> {
+        if value.len() < TRANSFER_FEE_CONFIG_SIZE {
+            return None;
+        }
+        let u64_at = |offset: usize| -> Option<u64> {
+            value
+                .get(offset..offset + 8)?
+                .try_into()
+                .ok()
+                .map(u64::from_le_bytes)
+        };
+        let fee_at = |offset: usize| -> Option<TransferFee> {
+            Some(TransferFee {
+                epoch: u64_at(offset)?,
+                maximum_fee: u64_at(offset + 8)?,
+                basis_points: u16::from_le_bytes([value[offset + 16], value[offset + 17]]),
+            })
+        };
+        // Skips the fee config and withdraw authorities (32 bytes each)
+        Some(Self {
+            withheld_amount: u64_at(64)?,
+            older_transfer_fee: fee_at(72)?,
+            newer_transfer_fee: fee_at(90)?,
+        })
+    }
+
+    /// Fee in effect at `epoch`
+    pub fn fee_for_epoch(&self, epoch: u64) -> TransferFee {
+        if epoch >= self.newer_transfer_fee.epoch {
+            self.newer_transfer_fee
+        } else {
+            self.older_transfer_fee
+        }
+    }
+}
@@ -0,0 +1,169 @@
+/// Token map module.
+/// Owned token list indexed by address, symbol and tag.
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    amount::TokenAmount,
+    types::{JupiterError, TokenInfo},
+};
+
+/// Token list indexed by address, lowercase symbol and tag
+///
+/// Entries are held behind `Arc`, so lookups are cheap to clone and share.
+#[derive(Debug, Clone, Default)]
+pub struct TokenMap {
+    by_address: HashMap<String, Arc<TokenInfo>>,
+    /// Lowercase symbol to addresses, in insertion order
+    by_symbol: HashMap<String, Vec<String>>,
+    /// Tag to addresses, in insertion order
+    by_tag: HashMap<String, Vec<String>>,
+}
+
+impl TokenMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a map from a token list; later duplicates replace earlier ones
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::token_map::TokenMap;
+    ///
+    /// let map = TokenMap::from_tokens(Vec::new());
+    /// assert!(map.is_empty());
+    /// assert!(map.get_by_symbol("SOL").is_empty());
+    /// ```
+    pub fn from_tokens(tokens: Vec<TokenInfo>) -> Self {
+        let mut map = Self::new();
+        for token in tokens {
+            map.insert(token);
+        }
+        map
+    }
+
+    /// Inserts or replaces a token, returning the previous entry
+    pub fn insert(&mut self, token: TokenInfo) -> Option<Arc<TokenInfo>> {
+        let previous = self.remove(&token.address);
+        let address = token.address.clone();
+        self.by_symbol
+            .entry(token.symbol.to_lowercase())
+            .or_default()
+            .push(address.clone());
+        for tag in &token.tags {
+            self.by_tag
+                .entry(tag.clone())
+                .or_default()
+                .push(address.clone());
+        }
+        self.by_address.insert(address, Arc::new(token));
+        previous
+    }
+
+    /// Removes a token and its index entries
+    pub fn remove(&mut self, address: &str) -> Option<Arc<TokenInfo>> {
+        let token = self.by_address.remove(address)?;
+        let unindex = |index: &mut HashMap<String, Vec<String>>, key: &str| {
+            if let Some(addresses) = index.get_mut(key) {
+                addresses.retain(|a| a != address);
+                if addresses.is_empty() {
+                    index.remove(key);
+                }
+            }
+        };
+        unindex(&mut self.by_symbol, &token.symbol.to_lowercase());
+        for tag in &token.tags {
+            unindex(&mut self.by_tag, tag);
+        }
+        Some(token)
+    }
+
+    /// Replaces the contents with a fresh token list
+    pub fn refresh(&mut self, tokens: Vec<TokenInfo>) {
+        *self = Self::from_tokens(tokens);
+    }
+
+    /// Looks up a token by mint address
+    pub fn get(&self, address: &str) -> Option<Arc<TokenInfo>> {
+        self.by_address.get(address).cloned()
+    }
+
+    /// Tokens with a symbol (case-insensitive); symbols are not unique
+    pub fn get_by_symbol(&self, symbol: &str) -> Vec<Arc<TokenInfo>> {
+        self.lookup(&self.by_symbol, &symbol.to_lowercase())
+    }
+
+    /// First token listed with a symbol (case-insensitive)
+    pub fn first_by_symbol(&self, symbol: &str) -> Option<Arc<TokenInfo>> {
+        self.by_symbol
+            .get(&symbol.to_lowercase())
+            .and_then(|addresses| addresses.first())
+            .and_then(|address| self.get(address))
+    }
+
+    /// Tokens carrying a tag
+    pub fn get_by_tag(&self, tag: &str) -> Vec<Arc<TokenInfo>> {
+        self.lookup(&self.by_tag, tag)
+    }
+
+    fn lookup(&self, index: &HashMap<String, Vec<String>>, key: &str) -> Vec<Arc<TokenInfo>> {
+        index
+            .get(key)
+            .map(|addresses| addresses.iter().filter_map(|a| self.get(a)).collect())
+            .unwrap_or_default()
+    }
+
+    /// Wraps a raw amount of a token with its decimals
+    pub fn amount(&self, address: &str, raw: u64) -> Option<TokenAmount> {
+        self.by_address.get(address).map(|token| token.amount(raw))
+    }
+
+    /// Parses a display amount of a token, e.g. `"1.5"`
+    pub fn parse_amount(&self, address: &str, value: &str) -> Result<TokenAmount, JupiterError> {
+        self.by_address
+            .get(address)
+            .ok_or_else(|| JupiterError::InvalidInput(format!("unknown token {}", address)))?
+            .parse_amount(value)
+    }
+
+    pub fn len(&self) -> usize {
+        self.by_address.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_address.is_empty()
+    }
+
+    /// Iterates over all tokens in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = &Arc<TokenInfo>> {
+        self.by_address.values()
+    }
+}
+
+/// Shared, time-stamped `TokenMap` slot held by the client
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SharedTokenMap {
+    slot: Arc<Mutex<Option<(Instant, Arc<TokenMap>)>>>,
+}
+
+impl SharedTokenMap {
+    /// Current map, if built within `ttl`
+    pub(crate) fn get(&self, ttl: Duration) -> Option<Arc<TokenMap>> {
+        let slot = self.slot.lock().unwrap();
+        slot.as_ref()
+            .filter(|(built, _)| built.elapsed() < ttl)
+            .map(|(_, map)| map.clone())
+    }
+
+    pub(crate) fn set(&self, map: Arc<TokenMap>) {
+        *self.slot.lock().unwrap() = Some((Instant::now(), map));
+    }
+
+    pub(crate) fn clear(&self) {
+        *self.slot.lock().unwrap() = None;
+    }
+}
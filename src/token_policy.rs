@@ -0,0 +1,137 @@
+/// Token policy module.
+/// Client-level allow/deny lists and tag requirements for traded mints, enforced on
+/// every quote and swap so compliance rules can't be skipped by a call site.
+use std::collections::HashSet;
+
+use crate::{
+    token_map::TokenMap,
+    types::{JupiterError, QuoteResponse},
+};
+
+/// Tag of tokens verified by Jupiter
+pub const VERIFIED_TAG: &str = "verified";
+
+/// Mints a client may trade
+///
+/// A mint is rejected if it is denied, if an allow-list is set and doesn't contain it,
+/// or if it lacks any required tag in the token list. Every mint of a route is
+/// checked, intermediate ones included.
+///
+/// # Example
+/// ```rust
+/// use jup_sdk::{token_policy::TokenPolicy, types::JupiterError};
+///
+/// let policy = TokenPolicy::new()
+///     .deny("DezXAZ8z7PnrnRJjz3wXBoRgixCa6xjnB7YaB1pPB263")
+///     .verified_only();
+/// assert!(matches!(
+///     policy.check_mint("DezXAZ8z7PnrnRJjz3wXBoRgixCa6xjnB7YaB1pPB263", None),
+///     Err(JupiterError::PolicyViolation { .. })
+/// ));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TokenPolicy {
+    /// Mints allowed to trade; empty allows all mints not denied
+    pub allowed_mints: HashSet<String>,
+    pub denied_mints: HashSet<String>,
+    /// Token list tags every traded mint must carry
+    pub required_tags: Vec<String>,
+}
+
+impl TokenPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `mint` to the allow-list, restricting trading to allow-listed mints
+    pub fn allow(mut self, mint: &str) -> Self {
+        self.allowed_mints.insert(mint.to_string());
+        self
+    }
+
+    pub fn deny(mut self, mint: &str) -> Self {
+        self.denied_mints.insert(mint.to_string());
+        self
+    }
+
+    pub fn require_tag(mut self, tag: &str) -> Self {
+        if !self.required_tags.iter().any(|required| required == tag) {
+            self.required_tags.push(tag.to_string());
+        }
+        self
+    }
+
+    /// Only trades tokens tagged `verified` in the token list
+    pub fn verified_only(self) -> Self {
+        self.require_tag(VERIFIED_TAG)
+    }
+
+    /// Whether checks need the token list
+    pub fn needs_token_map(&self) -> bool {
+        !self.required_tags.is_empty()
+    }
+
+    /// Checks one mint
+    ///
+    /// # Params
+    /// mint - Mint address
+    /// tokens - Token list, required when tags are required
+    pub fn check_mint(&self, mint: &str, tokens: Option<&TokenMap>) -> Result<(), JupiterError> {
+        let violation = |reason: String| {
+            Err(JupiterError::PolicyViolation {
+                mint: mint.to_string(),
+                reason,
+            })
+        };
+        if self.denied_mints.contains(mint) {
+            return violation("mint is denied".to_string());
+        }
+        if !self.allowed_mints.is_empty() && !self.allowed_mints.contains(mint) {
+            return violation("mint is not allowed".to_string());
+        }
+        if self.required_tags.is_empty() {
+            return Ok(());
+        }
+        let Some(token) = tokens.and_then(|tokens| tokens.get(mint)) else {
+            return violation("mint is not in the token list".to_string());
+        };
+        for tag in &self.required_tags {
+            if !token.tags.contains(tag) {
+                return violation(format!("token isn't tagged {}", tag));
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks the input, output and intermediate mints of a quote
+    pub fn check_quote(
+        &self,
+        quote: &QuoteResponse,
+        tokens: Option<&TokenMap>,
+    ) -> Result<(), JupiterError> {
+        self.check_mints(&quote_mints(quote), tokens)
+    }
+
+    pub fn check_mints(
+        &self,
+        mints: &[&str],
+        tokens: Option<&TokenMap>,
+    ) -> Result<(), JupiterError> {
+        mints
+            .iter()
+            .try_for_each(|mint| self.check_mint(mint, tokens))
+    }
+}
+
+/// Every mint a quote trades, in route order without duplicates
+pub(crate) fn quote_mints(quote: &QuoteResponse) -> Vec<&str> {
+    let mut mints = vec![quote.input_mint.as_str()];
+    for plan in &quote.route_plan {
+        mints.push(&plan.swap_info.input_mint);
+        mints.push(&plan.swap_info.output_mint);
+    }
+    mints.push(&quote.output_mint);
+    let mut seen = HashSet::new();
+    mints.retain(|mint| seen.insert(*mint));
+    mints
+}
@@ -1,8 +1,11 @@
-use crate::types::{QuoteResponse, TokenInfo};
-use solana_sdk::pubkey::Pubkey;
+use crate::types::{JupiterError, QuoteResponse, TokenInfo};
+use rust_decimal::Decimal;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use solana_sdk::{hash::Hash, pubkey::Pubkey, signature::Signature};
 use std::collections::HashMap;
+use std::future::Future;
 use std::str::FromStr;
-use std::time::{SystemTime, UNIX_EPOCH};
+use tokio_util::sync::CancellationToken;
 
 /// Validates a Solana public key string and converts it to a Pubkey
 ///
@@ -24,8 +27,14 @@ pub fn validate_pubkey(address: &str) -> Result<Pubkey, String> {
     Pubkey::from_str(address).map_err(|e| e.to_string())
 }
 
+/// Basis points in 100%
+const BPS_DENOMINATOR: u128 = 10_000;
+
 /// Calculates the minimum amount after applying slippage
 ///
+/// Uses exact integer arithmetic and rounds down, so the result never exceeds the
+/// true minimum. Slippage above 10000 bps is treated as 100%.
+///
 /// # Arguments
 /// amount - The original amount
 /// slippage_bps - Slippage in basis points (1 basis point = 0.01%)
@@ -35,14 +44,39 @@ pub fn validate_pubkey(address: &str) -> Result<Pubkey, String> {
 ///
 /// # Example
 /// ```rust
-/// let amount = 1000000;
-/// let slippage_bps = 50; // 0.5%
-/// let min_amount = cal_slippage_amount(amount, slippage_bps);
-/// println!("Minimum amount after slippage: {}", min_amount);
+/// use jup_sdk::tool::cal_slippage_amount;
+///
+/// assert_eq!(cal_slippage_amount(1_000_000, 50), 995_000);
+/// assert_eq!(cal_slippage_amount(u64::MAX, 0), u64::MAX);
+/// assert_eq!(cal_slippage_amount(999, 1), 998);
 /// ```
 pub fn cal_slippage_amount(amount: u64, slippage_bps: u16) -> u64 {
-    let slippage_percent = slippage_bps as f64 / 10000.0;
-    (amount as f64 * (1.0 - slippage_percent)) as u64
+    let retained = BPS_DENOMINATOR - (slippage_bps as u128).min(BPS_DENOMINATOR);
+    (amount as u128 * retained / BPS_DENOMINATOR) as u64
+}
+
+/// Calculates a basis-point fee on an amount
+///
+/// Uses exact integer arithmetic and rounds up, so amounts net of the fee are
+/// never overstated.
+///
+/// # Arguments
+/// amount - The amount the fee applies to
+/// fee_bps - Fee in basis points
+///
+/// # Returns
+/// u64 - Fee amount, at most `amount`
+///
+/// # Example
+/// ```rust
+/// use jup_sdk::tool::cal_bps_fee;
+///
+/// assert_eq!(cal_bps_fee(1_000_000, 10), 1_000);
+/// assert_eq!(cal_bps_fee(999, 1), 1);
+/// ```
+pub fn cal_bps_fee(amount: u64, fee_bps: u16) -> u64 {
+    let fee = (amount as u128 * fee_bps as u128).div_ceil(BPS_DENOMINATOR);
+    fee.min(amount as u128) as u64
 }
 
 /// Formats a raw token amount to human-readable format with decimals
@@ -62,9 +96,11 @@ pub fn cal_slippage_amount(amount: u64, slippage_bps: u16) -> u64 {
 /// println!("Formatted amount: {}", formatted); // "1.234567890"
 /// ```
 pub fn format_amount(amount: u64, decimals: u8) -> String {
-    let factor = 10u64.pow(decimals as u32);
-    let whole = amount / factor;
-    let fractional = amount % factor;
+    let Some(factor) = 10u128.checked_pow(decimals as u32) else {
+        return format!("0.{:0>width$}", amount, width = decimals as usize);
+    };
+    let whole = amount as u128 / factor;
+    let fractional = amount as u128 % factor;
 
     if fractional == 0 {
         format!("{}", whole)
@@ -80,6 +116,13 @@ pub fn format_amount(amount: u64, decimals: u8) -> String {
 
 /// Parses a human-readable amount string into raw token amount
 ///
+/// Accepts `"1.5"`, `".5"`, `"1."`, underscores (`"1_000"`) and comma thousands
+/// separators (`"1,000.25"`). Underscores are ignored anywhere before the comma
+/// groups are checked, so every comma group must still hold three digits. Trailing
+/// zeros of the fraction don't count towards `decimals`. Rejects negative values,
+/// malformed separators, more significant fractional digits than `decimals` and
+/// amounts that overflow `u64`.
+///
 /// # Arguments
 /// amount_str - String representation of the amount
 /// decimals - Number of decimal places for the token
@@ -89,43 +132,79 @@ pub fn format_amount(amount: u64, decimals: u8) -> String {
 ///
 /// # Example
 /// ```rust
-/// let amount_str = "1.5";
-/// let decimals = 9;
-/// match parse_amount(amount_str, decimals) {
-///     Ok(raw_amount) => println!("Raw amount: {}", raw_amount), // 1500000000
-///     Err(e) => println!("Error: {}", e),
-/// }
+/// use jup_sdk::tool::{format_amount, parse_amount};
+///
+/// assert_eq!(parse_amount("1.5", 9), Ok(1_500_000_000));
+/// assert_eq!(parse_amount(".5", 6), Ok(500_000));
+/// assert_eq!(parse_amount("1,234,567.0", 0), Ok(1_234_567));
+/// assert_eq!(parse_amount("1_234_567", 0), Ok(1_234_567));
+/// assert_eq!(parse_amount("1.500000", 1), Ok(15));
+/// assert!(parse_amount("1,234_567", 0).is_err());
+/// assert!(parse_amount("1.55", 1).is_err());
+/// assert!(parse_amount("-1", 6).is_err());
+/// assert!(parse_amount("1,23", 6).is_err());
+/// assert!(parse_amount("18446744073709551616", 0).is_err());
+/// assert_eq!(parse_amount(&format_amount(u64::MAX, 6), 6), Ok(u64::MAX));
 /// ```
 pub fn parse_amount(amount_str: &str, decimals: u8) -> Result<u64, String> {
-    let parts: Vec<&str> = amount_str.split('.').collect();
-
-    match parts.len() {
-        1 => {
-            let whole = parts[0]
-                .parse::<u64>()
-                .map_err(|e| format!("Invalid amount: {}", e))?;
-            Ok(whole * 10u64.pow(decimals as u32))
-        }
-        2 => {
-            let whole = parts[0]
-                .parse::<u64>()
-                .map_err(|e| format!("Invalid amount: {}", e))?;
-            let fractional = parts[1];
-
-            // 确保小数部分不超过精度
-            if fractional.len() > decimals as usize {
-                return Err(format!("Too many decimal places. Maximum is {}", decimals));
-            }
+    let amount_str = amount_str.trim();
+    if amount_str.starts_with('-') {
+        return Err("Invalid amount: negative amounts are not allowed".to_string());
+    }
+    let amount_str = amount_str.replace('_', "");
+    let (whole, fractional) = match amount_str.split_once('.') {
+        Some((whole, fractional)) => (whole, fractional),
+        None => (amount_str.as_str(), ""),
+    };
+    if whole.is_empty() && fractional.is_empty() {
+        return Err("Invalid amount: empty".to_string());
+    }
+    let whole = strip_thousands_separators(whole)?;
+    if !whole
+        .chars()
+        .chain(fractional.chars())
+        .all(|c| c.is_ascii_digit())
+    {
+        return Err(format!("Invalid amount: {}", amount_str));
+    }
 
-            let fractional_padded = format!("{:0<width$}", fractional, width = decimals as usize);
-            let fractional_value = fractional_padded
-                .parse::<u64>()
-                .map_err(|e| format!("Invalid fractional part: {}", e))?;
+    // 确保小数部分不超过精度
+    let fractional = fractional.trim_end_matches('0');
+    if fractional.len() > decimals as usize {
+        return Err(format!("Too many decimal places. Maximum is {}", decimals));
+    }
 
-            Ok(whole * 10u64.pow(decimals as u32) + fractional_value)
+    let overflow = || format!("Amount {} overflows u64", amount_str);
+    let factor = 10u128.checked_pow(decimals as u32).ok_or_else(overflow)?;
+    let whole_value = match whole.trim_start_matches('0') {
+        "" => 0,
+        digits => digits.parse::<u128>().map_err(|_| overflow())?,
+    };
+    let fractional_value = match fractional {
+        "" => 0,
+        digits => {
+            digits.parse::<u128>().map_err(|_| overflow())?
+                * 10u128.pow((decimals as usize - digits.len()) as u32)
         }
-        _ => Err("Invalid amount format".to_string()),
+    };
+    whole_value
+        .checked_mul(factor)
+        .and_then(|value| value.checked_add(fractional_value))
+        .and_then(|value| u64::try_from(value).ok())
+        .ok_or_else(overflow)
+}
+
+/// Removes comma thousands separators, checking that groups have three digits
+fn strip_thousands_separators(whole: &str) -> Result<String, String> {
+    if !whole.contains(',') {
+        return Ok(whole.to_string());
     }
+    let groups: Vec<&str> = whole.split(',').collect();
+    let first_ok = (1..=3).contains(&groups[0].len());
+    if !first_ok || groups[1..].iter().any(|group| group.len() != 3) {
+        return Err(format!("Invalid thousands separators: {}", whole));
+    }
+    Ok(groups.concat())
 }
 
 /// Validates that slippage is within acceptable limits
@@ -190,25 +269,29 @@ pub fn is_valid_mint_address(address: &str) -> bool {
     validate_pubkey(address).is_ok()
 }
 
-/// Generates a unique nonce based on current time
+/// Generates a unique nonce
+///
+/// Nonces are strictly increasing within the process and carry random bits, see
+/// `id::unique_u64`.
 ///
 /// # Returns
 /// u64 - Unique nonce value
 ///
 /// # Example
 /// ```rust
-/// let nonce = generate_nonce();
-/// println!("Generated nonce: {}", nonce);
+/// use jup_sdk::tool::generate_nonce;
+///
+/// assert!(generate_nonce() < generate_nonce());
 /// ```
 pub fn generate_nonce() -> u64 {
-    SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap()
-        .as_nanos() as u64
+    crate::id::unique_u64()
 }
 
 /// Calculates price impact percentage for a trade
 ///
+/// Computed with `rust_decimal`, see `cal_price_impact_decimal`. Returns 0.0 when
+/// `spot_price` is not finite.
+///
 /// # Arguments
 /// input_amount - Amount of input token
 /// output_amount - Amount of output token
@@ -219,22 +302,58 @@ pub fn generate_nonce() -> u64 {
 ///
 /// # Example
 /// ```rust
-/// let input_amount = 1000000;
-/// let output_amount = 500000;
-/// let spot_price = 0.6;
-/// let impact = cal_price_impact(input_amount, output_amount, spot_price);
+/// use jup_sdk::tool::cal_price_impact;
+///
+/// let impact = cal_price_impact(1_000_000, 500_000, 0.6);
 /// println!("Price impact: {:.2}%", impact);
 /// ```
 pub fn cal_price_impact(input_amount: u64, output_amount: u64, spot_price: f64) -> f64 {
-    let expected_output = (input_amount as f64) * spot_price;
-    if expected_output == 0.0 {
-        return 0.0;
+    Decimal::from_f64(spot_price)
+        .map(|spot_price| cal_price_impact_decimal(input_amount, output_amount, spot_price))
+        .and_then(|impact| impact.to_f64())
+        .unwrap_or(0.0)
+}
+
+/// Calculates price impact percentage for a trade with decimal arithmetic
+///
+/// The result is rounded half-to-even to 8 decimal places. Returns zero when the
+/// expected output is zero or overflows.
+///
+/// # Arguments
+/// input_amount - Amount of input token
+/// output_amount - Amount of output token
+/// spot_price - Current spot price of input token in output token terms
+///
+/// # Returns
+/// Decimal - Price impact percentage
+///
+/// # Example
+/// ```rust
+/// use jup_sdk::tool::cal_price_impact_decimal;
+/// use rust_decimal::Decimal;
+///
+/// let impact = cal_price_impact_decimal(1_000_000, 500_000, Decimal::new(6, 1));
+/// assert_eq!(impact.to_string(), "16.66666667");
+/// ```
+pub fn cal_price_impact_decimal(
+    input_amount: u64,
+    output_amount: u64,
+    spot_price: Decimal,
+) -> Decimal {
+    let Some(expected_output) = Decimal::from(input_amount).checked_mul(spot_price) else {
+        return Decimal::ZERO;
+    };
+    if expected_output.is_zero() {
+        return Decimal::ZERO;
     }
-    (expected_output - output_amount as f64) / expected_output * 100.0
+    ((expected_output - Decimal::from(output_amount)) / expected_output * Decimal::ONE_HUNDRED)
+        .round_dp(8)
 }
 
 /// Validates transaction signature format
 ///
+/// A valid signature is the base58 encoding of 64 bytes.
+///
 /// # Arguments
 /// signature - Transaction signature string
 ///
@@ -243,19 +362,61 @@ pub fn cal_price_impact(input_amount: u64, output_amount: u64, spot_price: f64)
 ///
 /// # Example
 /// ```rust
-/// let sig = "5VERv8NMvzbJMEkV8xnrLkEaWRtSz9CosKDYvC7j45R5...";
-/// if validate_transaction_signature(sig) {
-///     println!("Valid signature format");
-/// }
+/// use jup_sdk::tool::validate_transaction_signature;
+///
+/// let sig = "5VERv8NMvzbJMEkV8xnrLkEaWRtSz9CosKDYvC7j45R5G7CDbPcaY1kJdwRbDp3wbNcgGHv7JDmqwFxoG3HjUPAo";
+/// assert!(validate_transaction_signature(sig));
+/// assert!(!validate_transaction_signature("0".repeat(88).as_str()));
 /// ```
 pub fn validate_transaction_signature(signature: &str) -> bool {
-    signature.len() == 88 && signature.chars().all(|c| c.is_ascii_hexdigit())
+    Signature::from_str(signature.trim()).is_ok()
+}
+
+/// A parsed transaction signature or 32-byte hash
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureOrHash {
+    Signature(Signature),
+    /// Blockhash or message hash, often passed by mistake where a signature is expected
+    Hash(Hash),
+}
+
+/// Parses a base58 string as a transaction signature or a 32-byte hash
+///
+/// # Arguments
+/// value - Base58 string
+///
+/// # Returns
+/// Result<SignatureOrHash, String> - The parsed value, or why it is neither
+///
+/// # Example
+/// ```rust
+/// use jup_sdk::tool::{SignatureOrHash, validate_signature_or_hash};
+///
+/// let hash = validate_signature_or_hash("11111111111111111111111111111111").unwrap();
+/// assert!(matches!(hash, SignatureOrHash::Hash(_)));
+/// assert!(validate_signature_or_hash("not-base58!").is_err());
+/// ```
+pub fn validate_signature_or_hash(value: &str) -> Result<SignatureOrHash, String> {
+    let value = value.trim();
+    if let Ok(signature) = Signature::from_str(value) {
+        return Ok(SignatureOrHash::Signature(signature));
+    }
+    if let Ok(hash) = Hash::from_str(value) {
+        return Ok(SignatureOrHash::Hash(hash));
+    }
+    Err(format!(
+        "{} is not a base58 transaction signature (64 bytes) or hash (32 bytes)",
+        value
+    ))
 }
 
 // ============================
 
 /// Calculates net output amount after deducting fees
 ///
+/// Token-2022 transfer fees set on `input_token` and `output_token` are deducted too.
+/// Fees are rounded up, so the net output is never overstated.
+///
 /// # Arguments
 /// quote - Quote response from swap
 /// input_token - Input token information
@@ -281,7 +442,14 @@ pub fn cal_net_output(
     output_token: &TokenInfo,
     additional_fees_bps: u16,
 ) -> Result<u64, String> {
-    let out_amount: u64 = quote.out_amount.parse().map_err(|e| format!("{:?}", e))?;
+    quote
+        .out_amount
+        .parse::<u64>()
+        .map_err(|e| format!("{:?}", e))?;
+    let out_amount = quote.net_out_amount(
+        input_token.transfer_fee.as_ref(),
+        output_token.transfer_fee.as_ref(),
+    );
 
     // 考虑平台手续费
     let platform_fee = if let Some(fee) = &quote.platform_fee {
@@ -291,7 +459,7 @@ pub fn cal_net_output(
     };
 
     // 考虑额外手续费
-    let additional_fee = (out_amount as f64 * additional_fees_bps as f64 / 10000.0) as u64;
+    let additional_fee = cal_bps_fee(out_amount, additional_fees_bps);
 
     Ok(out_amount
         .saturating_sub(platform_fee)
@@ -328,8 +496,8 @@ pub fn estimate_apy(
     output_token: &TokenInfo,
     time_frame_hours: f64,
 ) -> f64 {
-    let input_value = input_amount as f64 / 10f64.powi(input_token.decimals as i32);
-    let output_value = output_amount as f64 / 10f64.powi(output_token.decimals as i32);
+    let input_value = input_token.amount(input_amount).to_f64();
+    let output_value = output_token.amount(output_amount).to_f64();
 
     if input_value == 0.0 || output_value <= input_value {
         return 0.0;
@@ -349,6 +517,8 @@ pub fn estimate_apy(
 /// # Returns
 /// HashMap<String, &TokenInfo> - Mapping of address to token info
 ///
+/// Prefer `token_map::TokenMap`, which owns its tokens and also indexes symbols and tags.
+///
 /// # Example
 /// ```
 /// let token_map = TokenUtils::build_token_map(&tokens);
@@ -356,6 +526,7 @@ pub fn estimate_apy(
 ///     println!("Found token: {}", token.symbol);
 /// }
 /// ```
+#[deprecated(note = "use token_map::TokenMap")]
 pub fn build_token_map(tokens: &[TokenInfo]) -> HashMap<String, &TokenInfo> {
     tokens
         .iter()
@@ -408,3 +579,189 @@ fn cal_similarity(s1: &str, s2: &str) -> f64 {
     }
     common_chars as f64 / s1.len().max(s2.len()) as f64
 }
+
+/// Runs a future to completion unless the cancellation token fires first
+///
+/// # Arguments
+/// token - Optional cancellation token; `None` simply awaits the future
+/// future - The future to run
+///
+/// # Returns
+/// Result<T, JupiterError> - The future's output, or JupiterError::Cancelled
+///
+/// # Example
+/// ```rust
+/// let token = CancellationToken::new();
+/// let result = run_cancellable(Some(&token), tokio::time::sleep(Duration::from_secs(1))).await;
+/// ```
+pub async fn run_cancellable<T, F>(
+    token: Option<&CancellationToken>,
+    future: F,
+) -> Result<T, JupiterError>
+where
+    F: Future<Output = T>,
+{
+    match token {
+        Some(token) => tokio::select! {
+            _ = token.cancelled() => Err(JupiterError::Cancelled("Operation cancelled".to_string())),
+            output = future => Ok(output),
+        },
+        None => Ok(future.await),
+    }
+}
+
+/// Derives the associated token account of an owner for a mint
+///
+/// # Arguments
+/// owner - Wallet that owns the token account
+/// mint - Token mint
+/// token_program - Token program of the mint (SPL Token or Token-2022)
+///
+/// # Returns
+/// Pubkey - Associated token account address
+///
+/// # Example
+/// ```rust
+/// let ata = associated_token_address(&owner, &mint, &token_program);
+/// ```
+pub fn associated_token_address(owner: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    let ata_program = Pubkey::from_str(crate::dex::ASSOCIATED_TOKEN_PROGRAM_ID)
+        .expect("associated token program ID is a valid pubkey");
+    Pubkey::find_program_address(
+        &[owner.as_ref(), token_program.as_ref(), mint.as_ref()],
+        &ata_program,
+    )
+    .0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Deterministic xorshift generator, so failures are reproducible
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        /// Random value of random magnitude, covering small and large amounts
+        fn amount(&mut self) -> u64 {
+            let bits = self.next() % 65;
+            match bits {
+                64 => self.next(),
+                bits => self.next() & ((1u64 << bits) - 1),
+            }
+        }
+    }
+
+    const EDGE_AMOUNTS: [u64; 6] = [0, 1, 9, 10, u64::MAX - 1, u64::MAX];
+
+    #[test]
+    fn format_then_parse_round_trips() {
+        let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+        for decimals in 0..=38u8 {
+            for amount in EDGE_AMOUNTS {
+                assert_eq!(
+                    parse_amount(&format_amount(amount, decimals), decimals),
+                    Ok(amount),
+                    "amount {} decimals {}",
+                    amount,
+                    decimals
+                );
+            }
+        }
+        for _ in 0..100_000 {
+            let amount = rng.amount();
+            let decimals = (rng.next() % 39) as u8;
+            let formatted = format_amount(amount, decimals);
+            assert_eq!(
+                parse_amount(&formatted, decimals),
+                Ok(amount),
+                "amount {} decimals {} formatted {}",
+                amount,
+                decimals,
+                formatted
+            );
+        }
+    }
+
+    #[test]
+    fn slippage_threshold_rounds_down_and_is_monotonic() {
+        let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+        for _ in 0..100_000 {
+            let amount = rng.amount();
+            let bps = (rng.next() % 12_000) as u16;
+            let threshold = cal_slippage_amount(amount, bps);
+            assert!(threshold <= amount, "amount {} bps {}", amount, bps);
+            // Rounded down: the exact minimum is at least the threshold and less than one unit above
+            let exact = amount as u128 * (10_000 - bps.min(10_000) as u128);
+            assert!(threshold as u128 * 10_000 <= exact);
+            assert!(exact < (threshold as u128 + 1) * 10_000);
+            let more = bps.saturating_add((rng.next() % 500) as u16);
+            assert!(cal_slippage_amount(amount, more) <= threshold);
+        }
+        for bps in [0, 1, 50, 9_999, 10_000, u16::MAX] {
+            let threshold = cal_slippage_amount(u64::MAX, bps);
+            assert!(threshold <= cal_slippage_amount(u64::MAX, bps.saturating_sub(1)));
+            assert_eq!(cal_slippage_amount(0, bps), 0);
+        }
+        assert_eq!(cal_slippage_amount(u64::MAX, 0), u64::MAX);
+        assert_eq!(cal_slippage_amount(u64::MAX, 10_000), 0);
+    }
+
+    #[test]
+    fn net_output_never_exceeds_output_and_decreases_with_fees() {
+        let mut rng = Rng(0xd1b5_4a32_d192_ed03);
+        let (input, output) = (TokenInfo::default(), TokenInfo::default());
+        for _ in 0..50_000 {
+            let out_amount = match rng.next() % 10 {
+                0 => u64::MAX,
+                _ => rng.amount(),
+            };
+            let quote = QuoteResponse::new("A", "B", 1, out_amount);
+            let bps = (rng.next() % 10_001) as u16;
+            let net = cal_net_output(&quote, &input, &output, bps).unwrap();
+            assert!(net <= out_amount, "out {} bps {}", out_amount, bps);
+            // The fee rounds up, so the net output rounds down
+            assert!(net <= cal_slippage_amount(out_amount, bps));
+            let more = bps.saturating_add((rng.next() % 500) as u16);
+            assert!(cal_net_output(&quote, &input, &output, more).unwrap() <= net);
+        }
+    }
+
+    #[test]
+    fn decimal_price_impact_matches_f64() {
+        let mut rng = Rng(0x94d0_49bb_1331_11eb);
+        for _ in 0..50_000 {
+            let input_amount = rng.next() % 1_000_000_000_000 + 1;
+            // Spot price between 0.000001 and 1000 with six decimal places
+            let spot_price = Decimal::new((rng.next() % 1_000_000_000 + 1) as i64, 6);
+            let expected = input_amount as f64 * spot_price.to_f64().unwrap();
+            let output_amount = (expected * (rng.next() % 15_000) as f64 / 10_000.0) as u64;
+            let impact = cal_price_impact_decimal(input_amount, output_amount, spot_price)
+                .to_f64()
+                .unwrap();
+            let float = (expected - output_amount as f64) / expected * 100.0;
+            let tolerance = 1e-8 + float.abs() * 1e-9;
+            assert!(
+                (impact - float).abs() <= tolerance,
+                "input {} output {} spot {}: decimal {} f64 {}",
+                input_amount,
+                output_amount,
+                spot_price,
+                impact,
+                float
+            );
+        }
+        assert_eq!(
+            cal_price_impact_decimal(u64::MAX, 0, Decimal::MAX),
+            Decimal::ZERO
+        );
+        assert_eq!(cal_price_impact_decimal(0, 1, Decimal::ONE), Decimal::ZERO);
+    }
+}
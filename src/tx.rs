@@ -0,0 +1,187 @@
+/// Transaction module.
+/// Decoding, signing and serialization of swap transactions returned by the Jupiter API.
+use crate::{global::MAX_TRANSACTION_SIZE, types::JupiterError};
+use base64::{Engine, engine::general_purpose::STANDARD};
+use serde::Serialize;
+use solana_sdk::{
+    signature::Signature,
+    signer::Signer,
+    transaction::{Transaction, VersionedTransaction},
+};
+
+/// Decodes a base64 encoded swap transaction, as returned in `SwapResponse::swap_transaction`
+///
+/// # Example
+/// ```rust
+/// use jup_sdk::{tx::decode_swap_transaction, types::SwapResponse};
+///
+/// fn example(swap: &SwapResponse) -> Result<(), Box<dyn std::error::Error>> {
+/// let transaction = decode_swap_transaction(&swap.swap_transaction)?;
+/// println!("{} signatures required", transaction.message.header().num_required_signatures);
+/// Ok(())
+/// }
+/// ```
+pub fn decode_swap_transaction(encoded: &str) -> Result<VersionedTransaction, JupiterError> {
+    let bytes = STANDARD
+        .decode(encoded)
+        .map_err(|e| JupiterError::ParseError(format!("invalid base64 transaction: {}", e)))?;
+    bincode::deserialize(&bytes)
+        .map_err(|e| JupiterError::ParseError(format!("invalid transaction bytes: {}", e)))
+}
+
+/// Signs a transaction's message with every required signer
+///
+/// Fails with `JupiterError::SigningError` when the signers don't match the
+/// message's required signers.
+///
+/// # Example
+/// ```rust
+/// use jup_sdk::tx::{decode_swap_transaction, serialize_base64, sign_transaction};
+/// use solana_sdk::signature::Keypair;
+///
+/// fn example(encoded: &str, keypair: &Keypair) -> Result<String, Box<dyn std::error::Error>> {
+/// let transaction = decode_swap_transaction(encoded)?;
+/// let transaction = sign_transaction(transaction, &[keypair])?;
+/// Ok(serialize_base64(&transaction)?)
+/// }
+/// ```
+pub fn sign_transaction(
+    transaction: VersionedTransaction,
+    signers: &[&dyn Signer],
+) -> Result<VersionedTransaction, JupiterError> {
+    VersionedTransaction::try_new(transaction.message, signers)
+        .map_err(|e| JupiterError::SigningError(e.to_string()))
+}
+
+/// Signs a transaction's message with some of its required signers
+///
+/// Signatures of other required signers are kept, e.g. an RFQ market maker's
+/// signature added before or after the taker signs.
+///
+/// Fails with `JupiterError::SigningError` when a signer is not a required signer.
+pub fn partial_sign_transaction(
+    mut transaction: VersionedTransaction,
+    signers: &[&dyn Signer],
+) -> Result<VersionedTransaction, JupiterError> {
+    let message = transaction.message.serialize();
+    let required = transaction.message.header().num_required_signatures as usize;
+    let signer_keys = transaction.message.static_account_keys()[..required].to_vec();
+    transaction
+        .signatures
+        .resize(required, Signature::default());
+    for signer in signers {
+        let pubkey = signer.pubkey();
+        let index = signer_keys
+            .iter()
+            .position(|key| *key == pubkey)
+            .ok_or_else(|| {
+                JupiterError::SigningError(format!("{} is not a required signer", pubkey))
+            })?;
+        transaction.signatures[index] = signer
+            .try_sign_message(&message)
+            .map_err(|e| JupiterError::SigningError(e.to_string()))?;
+    }
+    Ok(transaction)
+}
+
+/// Serializes a transaction into base64, e.g. for `sendTransaction` RPC calls
+pub fn serialize_base64(transaction: &VersionedTransaction) -> Result<String, JupiterError> {
+    let bytes = bincode::serialize(transaction)
+        .map_err(|e| JupiterError::ParseError(format!("serialize transaction error: {}", e)))?;
+    Ok(STANDARD.encode(bytes))
+}
+
+/// Decodes a base64 encoded legacy transaction, as returned when the swap was
+/// requested with `as_legacy_transaction`
+pub fn decode_legacy_transaction(encoded: &str) -> Result<Transaction, JupiterError> {
+    let bytes = STANDARD
+        .decode(encoded)
+        .map_err(|e| JupiterError::ParseError(format!("invalid base64 transaction: {}", e)))?;
+    bincode::deserialize(&bytes)
+        .map_err(|e| JupiterError::ParseError(format!("invalid legacy transaction bytes: {}", e)))
+}
+
+/// Signs a legacy transaction with every required signer, keeping its blockhash
+pub fn sign_legacy_transaction(
+    mut transaction: Transaction,
+    signers: &[&dyn Signer],
+) -> Result<Transaction, JupiterError> {
+    let recent_blockhash = transaction.message.recent_blockhash;
+    transaction
+        .try_sign(signers, recent_blockhash)
+        .map_err(|e| JupiterError::SigningError(e.to_string()))?;
+    Ok(transaction)
+}
+
+/// Checks that a transaction fits in a single packet, returning its serialized size
+pub fn validate_transaction_size<T: Serialize>(transaction: &T) -> Result<usize, JupiterError> {
+    let size = bincode::serialized_size(transaction)
+        .map_err(|e| JupiterError::ParseError(e.to_string()))? as usize;
+    if size > MAX_TRANSACTION_SIZE {
+        return Err(JupiterError::ValidationError(format!(
+            "transaction too large: {} bytes (max {})",
+            size, MAX_TRANSACTION_SIZE
+        )));
+    }
+    Ok(size)
+}
+
+/// Swap transaction in either the legacy or the v0 format
+#[derive(Debug, Clone)]
+pub enum SwapTransaction {
+    Legacy(Transaction),
+    Versioned(VersionedTransaction),
+}
+
+impl SwapTransaction {
+    /// Decodes a base64 encoded swap transaction in the requested format
+    ///
+    /// # Params
+    /// encoded - Base64 encoded transaction
+    /// legacy - Whether the swap was requested with `as_legacy_transaction`
+    pub fn decode(encoded: &str, legacy: bool) -> Result<Self, JupiterError> {
+        if legacy {
+            decode_legacy_transaction(encoded).map(SwapTransaction::Legacy)
+        } else {
+            decode_swap_transaction(encoded).map(SwapTransaction::Versioned)
+        }
+    }
+
+    /// Signs the transaction with every required signer
+    pub fn sign(self, signers: &[&dyn Signer]) -> Result<Self, JupiterError> {
+        match self {
+            SwapTransaction::Legacy(transaction) => {
+                sign_legacy_transaction(transaction, signers).map(SwapTransaction::Legacy)
+            }
+            SwapTransaction::Versioned(transaction) => {
+                sign_transaction(transaction, signers).map(SwapTransaction::Versioned)
+            }
+        }
+    }
+
+    /// Checks that the transaction fits in a single packet
+    pub fn validate_size(&self) -> Result<usize, JupiterError> {
+        match self {
+            SwapTransaction::Legacy(transaction) => validate_transaction_size(transaction),
+            SwapTransaction::Versioned(transaction) => validate_transaction_size(transaction),
+        }
+    }
+
+    /// Fee payer signature, identifying the transaction once signed
+    pub fn signature(&self) -> Option<&Signature> {
+        match self {
+            SwapTransaction::Legacy(transaction) => transaction.signatures.first(),
+            SwapTransaction::Versioned(transaction) => transaction.signatures.first(),
+        }
+    }
+
+    /// Serializes the transaction into base64
+    pub fn serialize_base64(&self) -> Result<String, JupiterError> {
+        let bytes = match self {
+            SwapTransaction::Legacy(transaction) => bincode::serialize(transaction),
+            SwapTransaction::Versioned(transaction) => bincode::serialize(transaction),
+        }
+        .map_err(|e| JupiterError::ParseError(format!("serialize transaction error: {}", e)))?;
+        Ok(STANDARD.encode(bytes))
+    }
+}
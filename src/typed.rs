@@ -0,0 +1,354 @@
+/// Typed model module.
+/// Views of the API models with addresses held as `Pubkey`, validated at deserialization.
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{
+    dex::DexId,
+    types::{
+        JupiterError, PlatformFee, QuoteRequest, QuoteResponse, RoutePlan, SwapInfo, SwapRequest,
+    },
+};
+
+/// Serde adapter encoding a `Pubkey` as a base58 string
+///
+/// # Example
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use solana_sdk::pubkey::Pubkey;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Account {
+///     #[serde(with = "jup_sdk::typed::pubkey_serde")]
+///     key: Pubkey,
+/// }
+///
+/// let json = r#"{"key":"So11111111111111111111111111111111111111112"}"#;
+/// let account: Account = serde_json::from_str(json).unwrap();
+/// assert_eq!(serde_json::to_string(&account).unwrap(), json);
+/// assert!(serde_json::from_str::<Account>(r#"{"key":"not-a-key"}"#).is_err());
+/// ```
+pub mod pubkey_serde {
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+    use solana_sdk::pubkey::Pubkey;
+    use std::str::FromStr;
+
+    pub fn serialize<S: Serializer>(pubkey: &Pubkey, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(pubkey)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Pubkey, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Pubkey::from_str(&value)
+            .map_err(|e| D::Error::custom(format!("invalid pubkey {}: {}", value, e)))
+    }
+}
+
+fn parse_pubkey(field: &str, value: &str) -> Result<Pubkey, JupiterError> {
+    value
+        .parse()
+        .map_err(|_| JupiterError::ParseError(format!("invalid {} {}", field, value)))
+}
+
+/// `QuoteRequest` with typed mints
+///
+/// # Example
+/// ```rust
+/// use jup_sdk::{typed::TypedQuoteRequest, types::QuoteRequest};
+/// use solana_sdk::pubkey::Pubkey;
+///
+/// let (input_mint, output_mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+/// let request = TypedQuoteRequest {
+///     input_mint,
+///     output_mint,
+///     amount: 1_000,
+///     slippage_bps: 50,
+///     fee_bps: Some(10),
+///     only_direct_routes: None,
+///     as_legacy_transaction: None,
+///     restrict_middle_tokens: None,
+/// };
+/// assert_eq!(
+///     QuoteRequest::from(request).to_query_params(),
+///     vec![
+///         ("inputMint", input_mint.to_string()),
+///         ("outputMint", output_mint.to_string()),
+///         ("amount", "1000".to_string()),
+///         ("slippageBps", "50".to_string()),
+///         ("platformFeeBps", "10".to_string()),
+///     ]
+/// );
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TypedQuoteRequest {
+    #[serde(with = "pubkey_serde")]
+    pub input_mint: Pubkey,
+    #[serde(with = "pubkey_serde")]
+    pub output_mint: Pubkey,
+    pub amount: u64,
+    pub slippage_bps: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fee_bps: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub only_direct_routes: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub as_legacy_transaction: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restrict_middle_tokens: Option<bool>,
+}
+
+impl From<TypedQuoteRequest> for QuoteRequest {
+    fn from(request: TypedQuoteRequest) -> Self {
+        QuoteRequest {
+            input_mint: request.input_mint.to_string(),
+            output_mint: request.output_mint.to_string(),
+            amount: request.amount,
+            slippage_bps: request.slippage_bps,
+            fee_bps: request.fee_bps,
+            only_direct_routes: request.only_direct_routes,
+            as_legacy_transaction: request.as_legacy_transaction,
+            restrict_middle_tokens: request.restrict_middle_tokens,
+        }
+    }
+}
+
+impl TryFrom<&QuoteRequest> for TypedQuoteRequest {
+    type Error = JupiterError;
+
+    fn try_from(request: &QuoteRequest) -> Result<Self, Self::Error> {
+        Ok(TypedQuoteRequest {
+            input_mint: parse_pubkey("input_mint", &request.input_mint)?,
+            output_mint: parse_pubkey("output_mint", &request.output_mint)?,
+            amount: request.amount,
+            slippage_bps: request.slippage_bps,
+            fee_bps: request.fee_bps,
+            only_direct_routes: request.only_direct_routes,
+            as_legacy_transaction: request.as_legacy_transaction,
+            restrict_middle_tokens: request.restrict_middle_tokens,
+        })
+    }
+}
+
+/// `SwapInfo` with typed AMM key and mints
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TypedSwapInfo {
+    #[serde(with = "pubkey_serde")]
+    pub amm_key: Pubkey,
+    pub label: DexId,
+    #[serde(with = "pubkey_serde")]
+    pub input_mint: Pubkey,
+    #[serde(with = "pubkey_serde")]
+    pub output_mint: Pubkey,
+    pub in_amount: String,
+    pub out_amount: String,
+    pub fee_amount: String,
+    #[serde(with = "pubkey_serde")]
+    pub fee_mint: Pubkey,
+}
+
+impl From<TypedSwapInfo> for SwapInfo {
+    fn from(info: TypedSwapInfo) -> Self {
+        SwapInfo {
+            amm_key: info.amm_key.to_string(),
+            label: info.label,
+            input_mint: info.input_mint.to_string(),
+            output_mint: info.output_mint.to_string(),
+            in_amount: info.in_amount,
+            out_amount: info.out_amount,
+            fee_amount: info.fee_amount,
+            fee_mint: info.fee_mint.to_string(),
+        }
+    }
+}
+
+impl TryFrom<&SwapInfo> for TypedSwapInfo {
+    type Error = JupiterError;
+
+    fn try_from(info: &SwapInfo) -> Result<Self, Self::Error> {
+        Ok(TypedSwapInfo {
+            amm_key: parse_pubkey("amm_key", &info.amm_key)?,
+            label: info.label.clone(),
+            input_mint: parse_pubkey("input_mint", &info.input_mint)?,
+            output_mint: parse_pubkey("output_mint", &info.output_mint)?,
+            in_amount: info.in_amount.clone(),
+            out_amount: info.out_amount.clone(),
+            fee_amount: info.fee_amount.clone(),
+            fee_mint: parse_pubkey("fee_mint", &info.fee_mint)?,
+        })
+    }
+}
+
+/// `RoutePlan` with a typed swap step
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TypedRoutePlan {
+    pub swap_info: TypedSwapInfo,
+    pub percent: u8,
+}
+
+impl From<TypedRoutePlan> for RoutePlan {
+    fn from(plan: TypedRoutePlan) -> Self {
+        RoutePlan {
+            swap_info: plan.swap_info.into(),
+            percent: plan.percent,
+        }
+    }
+}
+
+impl TryFrom<&RoutePlan> for TypedRoutePlan {
+    type Error = JupiterError;
+
+    fn try_from(plan: &RoutePlan) -> Result<Self, Self::Error> {
+        Ok(TypedRoutePlan {
+            swap_info: TypedSwapInfo::try_from(&plan.swap_info)?,
+            percent: plan.percent,
+        })
+    }
+}
+
+/// `QuoteResponse` with typed mints and route steps
+///
+/// Serializes with the same field names as `QuoteResponse`, so a stored quote can
+/// be read as either.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TypedQuoteResponse {
+    #[serde(with = "pubkey_serde")]
+    pub input_mint: Pubkey,
+    #[serde(with = "pubkey_serde")]
+    pub output_mint: Pubkey,
+    pub in_amount: String,
+    pub out_amount: String,
+    pub other_amount_threshold: String,
+    pub swap_mode: String,
+    pub slippage_bps: u16,
+    pub platform_fee: Option<PlatformFee>,
+    pub price_impact_pct: String,
+    pub route_plan: Vec<TypedRoutePlan>,
+    pub context_slot: u64,
+    pub time_taken: f64,
+}
+
+impl From<TypedQuoteResponse> for QuoteResponse {
+    fn from(quote: TypedQuoteResponse) -> Self {
+        QuoteResponse {
+            input_mint: quote.input_mint.to_string(),
+            output_mint: quote.output_mint.to_string(),
+            in_amount: quote.in_amount,
+            out_amount: quote.out_amount,
+            other_amount_threshold: quote.other_amount_threshold,
+            swap_mode: quote.swap_mode,
+            slippage_bps: quote.slippage_bps,
+            platform_fee: quote.platform_fee,
+            price_impact_pct: quote.price_impact_pct,
+            route_plan: quote.route_plan.into_iter().map(Into::into).collect(),
+            context_slot: quote.context_slot,
+            time_taken: quote.time_taken,
+        }
+    }
+}
+
+impl TryFrom<&QuoteResponse> for TypedQuoteResponse {
+    type Error = JupiterError;
+
+    fn try_from(quote: &QuoteResponse) -> Result<Self, Self::Error> {
+        Ok(TypedQuoteResponse {
+            input_mint: parse_pubkey("input_mint", &quote.input_mint)?,
+            output_mint: parse_pubkey("output_mint", &quote.output_mint)?,
+            in_amount: quote.in_amount.clone(),
+            out_amount: quote.out_amount.clone(),
+            other_amount_threshold: quote.other_amount_threshold.clone(),
+            swap_mode: quote.swap_mode.clone(),
+            slippage_bps: quote.slippage_bps,
+            platform_fee: quote.platform_fee.clone(),
+            price_impact_pct: quote.price_impact_pct.clone(),
+            route_plan: quote
+                .route_plan
+                .iter()
+                .map(TypedRoutePlan::try_from)
+                .collect::<Result<_, _>>()?,
+            context_slot: quote.context_slot,
+            time_taken: quote.time_taken,
+        })
+    }
+}
+
+/// `SwapRequest` with a typed user key
+///
+/// # Example
+/// ```rust
+/// use jup_sdk::{
+///     typed::TypedSwapRequest,
+///     types::{QuoteResponse, SwapRequest},
+/// };
+///
+/// let mint = "So11111111111111111111111111111111111111112";
+/// let request = SwapRequest {
+///     quote_response: QuoteResponse::new(mint, mint, 1, 2),
+///     user_public_key: mint.to_string(),
+///     wrap_and_unwrap_sol: None,
+///     compute_unit_price: Some(1_000),
+///     prioritization_fee_lamports: None,
+///     as_legacy_transaction: None,
+///     destination_token_account: None,
+/// };
+/// let typed = TypedSwapRequest::try_from(&request).unwrap();
+/// let json = serde_json::to_value(&typed).unwrap();
+/// assert_eq!(json["user_public_key"], mint);
+/// assert_eq!(json["compute_unit_price"], 1_000);
+/// for unset in [
+///     "wrap_and_unwrap_sol",
+///     "prioritization_fee_lamports",
+///     "as_legacy_transaction",
+///     "destinationTokenAccount",
+/// ] {
+///     assert!(json.get(unset).is_none());
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TypedSwapRequest {
+    pub quote_response: TypedQuoteResponse,
+    #[serde(with = "pubkey_serde")]
+    pub user_public_key: Pubkey,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wrap_and_unwrap_sol: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compute_unit_price: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prioritization_fee_lamports: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub as_legacy_transaction: Option<bool>,
+    #[serde(
+        rename = "destinationTokenAccount",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub destination_token_account: Option<String>,
+}
+
+impl From<TypedSwapRequest> for SwapRequest {
+    fn from(request: TypedSwapRequest) -> Self {
+        SwapRequest {
+            quote_response: request.quote_response.into(),
+            user_public_key: request.user_public_key.to_string(),
+            wrap_and_unwrap_sol: request.wrap_and_unwrap_sol,
+            compute_unit_price: request.compute_unit_price,
+            prioritization_fee_lamports: request.prioritization_fee_lamports,
+            as_legacy_transaction: request.as_legacy_transaction,
+            destination_token_account: request.destination_token_account,
+        }
+    }
+}
+
+impl TryFrom<&SwapRequest> for TypedSwapRequest {
+    type Error = JupiterError;
+
+    fn try_from(request: &SwapRequest) -> Result<Self, Self::Error> {
+        Ok(TypedSwapRequest {
+            quote_response: TypedQuoteResponse::try_from(&request.quote_response)?,
+            user_public_key: parse_pubkey("user_public_key", &request.user_public_key)?,
+            wrap_and_unwrap_sol: request.wrap_and_unwrap_sol,
+            compute_unit_price: request.compute_unit_price,
+            prioritization_fee_lamports: request.prioritization_fee_lamports,
+            as_legacy_transaction: request.as_legacy_transaction,
+            destination_token_account: request.destination_token_account.clone(),
+        })
+    }
+}
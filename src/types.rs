@@ -1,10 +1,35 @@
+use base64::{Engine, engine::general_purpose::STANDARD};
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, time::Duration};
+use solana_client::rpc_config::RpcSendTransactionConfig;
+use solana_commitment_config::CommitmentLevel;
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+use std::{collections::HashMap, str::FromStr, sync::Arc, time::Duration};
+use tokio::{sync::Mutex, time::Instant};
 
-use crate::tool::cal_slippage_amount;
+use crate::{
+    amount::TokenAmount,
+    dex::DexId,
+    global::DEFAULT_BATCH_CONCURRENCY,
+    mev::MevProtection,
+    monitor::{TransactionMonitorResult, TransactionStatus as MonitorStatus},
+    policy::{ExecutionAttempt, ExecutionPolicy},
+    pretrade::VetoReason,
+    priority::ExecutionPriority,
+    retry::ErrorCategory,
+    summary::SwapSummary,
+    token_map::TokenMap,
+    token2022::TransferFee,
+    tool::cal_slippage_amount,
+    typed::TypedQuoteResponse,
+};
 
 /// Represents token information including metadata and extensions
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict-serde", serde(deny_unknown_fields))]
 pub struct TokenInfo {
     pub address: String,
     pub chain_id: u64,
@@ -14,85 +39,589 @@ pub struct TokenInfo {
     pub logo_uri: String,
     pub tags: Vec<String>,
     pub extensions: Option<TokenExtensions>,
+    /// Token-2022 transfer fee in effect, see `JupiterClient::get_transfer_fee`
+    #[serde(default)]
+    pub transfer_fee: Option<TransferFee>,
+}
+
+impl TokenInfo {
+    /// Creates token info with the given identity, leaving metadata empty
+    pub fn new(address: &str, symbol: &str, decimals: u8) -> Self {
+        Self {
+            address: address.to_string(),
+            symbol: symbol.to_string(),
+            decimals,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// Wraps a raw amount of this token with its decimals
+    pub fn amount(&self, raw: u64) -> TokenAmount {
+        TokenAmount::new(raw, self.decimals)
+    }
+
+    /// Parses a display amount of this token, e.g. `"1.5"`
+    pub fn parse_amount(&self, value: &str) -> Result<TokenAmount, JupiterError> {
+        TokenAmount::from_ui_str(value, self.decimals)
+    }
 }
 
 /// Request structure for getting swap quotes
+///
+/// Unset optional fields are omitted when serialized, since some endpoints reject
+/// explicit nulls. Quotes are requested with the camelCase `to_query_params`.
+///
+/// # Example
+/// ```rust
+/// use jup_sdk::types::QuoteRequest;
+///
+/// let request = QuoteRequest {
+///     input_mint: "So11111111111111111111111111111111111111112".to_string(),
+///     output_mint: "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v".to_string(),
+///     amount: 1_000_000,
+///     slippage_bps: 50,
+///     fee_bps: None,
+///     only_direct_routes: Some(true),
+///     as_legacy_transaction: None,
+///     restrict_middle_tokens: None,
+/// };
+/// assert_eq!(
+///     request.to_query_params(),
+///     vec![
+///         ("inputMint", "So11111111111111111111111111111111111111112".to_string()),
+///         ("outputMint", "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v".to_string()),
+///         ("amount", "1000000".to_string()),
+///         ("slippageBps", "50".to_string()),
+///         ("onlyDirectRoutes", "true".to_string()),
+///     ]
+/// );
+/// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct QuoteRequest {
     pub input_mint: String,
     pub output_mint: String,
     pub amount: u64,
     pub slippage_bps: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub fee_bps: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub only_direct_routes: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub as_legacy_transaction: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub restrict_middle_tokens: Option<bool>,
 }
 
+impl QuoteRequest {
+    /// Builds the Jupiter API query parameters for this request
+    pub fn to_query_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = vec![
+            ("inputMint", self.input_mint.clone()),
+            ("outputMint", self.output_mint.clone()),
+            ("amount", self.amount.to_string()),
+            ("slippageBps", self.slippage_bps.to_string()),
+        ];
+        if let Some(fee_bps) = self.fee_bps {
+            params.push(("platformFeeBps", fee_bps.to_string()));
+        }
+        if let Some(only_direct_routes) = self.only_direct_routes {
+            params.push(("onlyDirectRoutes", only_direct_routes.to_string()));
+        }
+        if let Some(as_legacy_transaction) = self.as_legacy_transaction {
+            params.push(("asLegacyTransaction", as_legacy_transaction.to_string()));
+        }
+        if let Some(restrict_middle_tokens) = self.restrict_middle_tokens {
+            params.push((
+                "restrictIntermediateTokens",
+                restrict_middle_tokens.to_string(),
+            ));
+        }
+        params
+    }
+}
+
 /// Response structure containing swap quote details
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// `Default` and the builders are meant for fixtures; real quotes come from
+/// `JupiterClient::get_quote`.
+///
+/// # Example
+/// ```rust
+/// use jup_sdk::types::{QuoteResponse, RoutePlan};
+///
+/// let quote = QuoteResponse::new("So11111111111111111111111111111111111111112", "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v", 1_000_000, 150_000)
+///     .with_slippage_bps(50)
+///     .with_route_plan(vec![RoutePlan::default()]);
+/// assert_eq!(quote.other_amount_threshold, "149250");
+/// assert_ne!(quote, QuoteResponse::default());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict-serde", serde(deny_unknown_fields))]
 pub struct QuoteResponse {
     pub input_mint: String,
     pub output_mint: String,
+    #[serde(with = "crate::amount::string_or_number")]
+    #[cfg_attr(feature = "schema", schemars(with = "String"))]
     pub in_amount: String,
+    #[serde(with = "crate::amount::string_or_number")]
+    #[cfg_attr(feature = "schema", schemars(with = "String"))]
     pub out_amount: String,
+    #[serde(with = "crate::amount::string_or_number")]
+    #[cfg_attr(feature = "schema", schemars(with = "String"))]
     pub other_amount_threshold: String,
     pub swap_mode: String,
     pub slippage_bps: u16,
     pub platform_fee: Option<PlatformFee>,
+    #[serde(with = "crate::amount::string_or_number")]
+    #[cfg_attr(feature = "schema", schemars(with = "String"))]
     pub price_impact_pct: String,
     pub route_plan: Vec<RoutePlan>,
     pub context_slot: u64,
     pub time_taken: f64,
 }
 
+impl QuoteResponse {
+    /// Creates an ExactIn quote of `in_amount` for `out_amount`, without slippage
+    pub fn new(input_mint: &str, output_mint: &str, in_amount: u64, out_amount: u64) -> Self {
+        Self {
+            input_mint: input_mint.to_string(),
+            output_mint: output_mint.to_string(),
+            in_amount: in_amount.to_string(),
+            out_amount: out_amount.to_string(),
+            other_amount_threshold: out_amount.to_string(),
+            swap_mode: "ExactIn".to_string(),
+            price_impact_pct: "0".to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the slippage and recomputes `other_amount_threshold` from `out_amount`
+    pub fn with_slippage_bps(mut self, slippage_bps: u16) -> Self {
+        self.slippage_bps = slippage_bps;
+        let out_amount: u64 = self.out_amount.parse().unwrap_or(0);
+        self.other_amount_threshold =
+            (out_amount as u128 * (10_000 - slippage_bps.min(10_000)) as u128 / 10_000).to_string();
+        self
+    }
+
+    pub fn with_route_plan(mut self, route_plan: Vec<RoutePlan>) -> Self {
+        self.route_plan = route_plan;
+        self
+    }
+
+    pub fn with_price_impact_pct(mut self, price_impact_pct: f64) -> Self {
+        self.price_impact_pct = price_impact_pct.to_string();
+        self
+    }
+
+    pub fn with_context_slot(mut self, context_slot: u64) -> Self {
+        self.context_slot = context_slot;
+        self
+    }
+
+    /// Venues of the route steps, in route order without duplicates
+    pub fn venues(&self) -> Vec<&DexId> {
+        let mut venues: Vec<&DexId> = Vec::new();
+        for plan in &self.route_plan {
+            if !venues.contains(&&plan.swap_info.label) {
+                venues.push(&plan.swap_info.label);
+            }
+        }
+        venues
+    }
+
+    /// Whether any route step trades on `venue`
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::{dex::DexId, types::QuoteResponse};
+    ///
+    /// fn acceptable(quote: &QuoteResponse) -> bool {
+    ///     !quote.uses_venue(&DexId::PumpFun)
+    /// }
+    /// ```
+    pub fn uses_venue(&self, venue: &DexId) -> bool {
+        self.route_plan
+            .iter()
+            .any(|plan| &plan.swap_info.label == venue)
+    }
+
+    /// Human-readable summary, resolving symbols and decimals from `tokens`
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::{
+    ///     token_map::TokenMap,
+    ///     types::{QuoteResponse, TokenInfo},
+    /// };
+    ///
+    /// let sol = "So11111111111111111111111111111111111111112";
+    /// let usdc = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+    /// let tokens = TokenMap::from_tokens(vec![
+    ///     TokenInfo::new(sol, "SOL", 9),
+    ///     TokenInfo::new(usdc, "USDC", 6),
+    /// ]);
+    /// let quote = QuoteResponse::new(sol, usdc, 1_500_000_000, 228_400_000)
+    ///     .with_slippage_bps(50)
+    ///     .with_price_impact_pct(0.12);
+    /// assert_eq!(
+    ///     quote.summary(&tokens).to_string(),
+    ///     "Swap 1.5 SOL → 228.4 USDC, impact 0.12%, min received 227.258"
+    /// );
+    /// ```
+    pub fn summary(&self, tokens: &TokenMap) -> SwapSummary {
+        SwapSummary::new(self, tokens)
+    }
+
+    /// Typed view with mints and AMM keys parsed as `Pubkey`
+    pub fn typed(&self) -> Result<TypedQuoteResponse, JupiterError> {
+        TypedQuoteResponse::try_from(self)
+    }
+
+    /// Input amount with the input mint's decimals
+    pub fn in_token_amount(&self, decimals: u8) -> Result<TokenAmount, JupiterError> {
+        parse_raw_amount(&self.in_amount, decimals)
+    }
+
+    /// Quoted output amount with the output mint's decimals
+    pub fn out_token_amount(&self, decimals: u8) -> Result<TokenAmount, JupiterError> {
+        parse_raw_amount(&self.out_amount, decimals)
+    }
+
+    /// Slippage-adjusted threshold; output decimals for ExactIn, input decimals for ExactOut
+    pub fn threshold_token_amount(&self, decimals: u8) -> Result<TokenAmount, JupiterError> {
+        parse_raw_amount(&self.other_amount_threshold, decimals)
+    }
+
+    /// Output amount received after Token-2022 transfer fees
+    ///
+    /// The input fee reduces the amount reaching the pools, scaling the output down
+    /// proportionally; the output fee is withheld from the amount received.
+    ///
+    /// # Params
+    /// input_fee - Transfer fee of the input mint, if any
+    /// output_fee - Transfer fee of the output mint, if any
+    pub fn net_out_amount(
+        &self,
+        input_fee: Option<&TransferFee>,
+        output_fee: Option<&TransferFee>,
+    ) -> u64 {
+        let out_amount: u64 = self.out_amount.parse().unwrap_or(0);
+        let in_amount: u64 = self.in_amount.parse().unwrap_or(0);
+        let out_amount = match input_fee {
+            Some(fee) if in_amount > 0 => {
+                let received = fee.amount_after_fee(in_amount);
+                (out_amount as u128 * received as u128 / in_amount as u128) as u64
+            }
+            _ => out_amount,
+        };
+        match output_fee {
+            Some(fee) => fee.amount_after_fee(out_amount),
+            None => out_amount,
+        }
+    }
+}
+
+fn parse_raw_amount(raw: &str, decimals: u8) -> Result<TokenAmount, JupiterError> {
+    raw.parse()
+        .map(|raw| TokenAmount::new(raw, decimals))
+        .map_err(|_| JupiterError::ParseError(format!("invalid amount {}", raw)))
+}
+
 /// Platform fee information
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict-serde", serde(deny_unknown_fields))]
 pub struct PlatformFee {
+    #[serde(with = "crate::amount::string_or_number")]
+    #[cfg_attr(feature = "schema", schemars(with = "String"))]
     pub amount: String,
     pub fee_bps: u16,
 }
 
 /// Individual route information within a swap route plan
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict-serde", serde(deny_unknown_fields))]
 pub struct RoutePlan {
     pub swap_info: SwapInfo,
     pub percent: u8,
 }
 
+impl RoutePlan {
+    /// Creates a route step carrying `percent` of the order through `swap_info`
+    pub fn new(swap_info: SwapInfo, percent: u8) -> Self {
+        Self { swap_info, percent }
+    }
+}
+
 /// Swap information for a specific route step
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// `label` resolves the API's venue label against the DEX registry; labels missing
+/// from the registry are kept verbatim in `DexId::Unknown`, or rejected with the
+/// `strict-serde` feature.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict-serde", serde(deny_unknown_fields))]
 pub struct SwapInfo {
     pub amm_key: String,
-    pub label: String,
+    pub label: DexId,
     pub input_mint: String,
     pub output_mint: String,
+    #[serde(with = "crate::amount::string_or_number")]
+    #[cfg_attr(feature = "schema", schemars(with = "String"))]
     pub in_amount: String,
+    #[serde(with = "crate::amount::string_or_number")]
+    #[cfg_attr(feature = "schema", schemars(with = "String"))]
     pub out_amount: String,
+    #[serde(with = "crate::amount::string_or_number")]
+    #[cfg_attr(feature = "schema", schemars(with = "String"))]
     pub fee_amount: String,
     pub fee_mint: String,
 }
 
 /// Request structure for executing a swap
+///
+/// Unset optional fields are omitted when serialized. `destination_token_account` is
+/// sent as `destinationTokenAccount`, the name the swap API reads.
+///
+/// # Example
+/// ```rust
+/// use jup_sdk::types::{QuoteResponse, SwapRequest};
+///
+/// let request = SwapRequest {
+///     quote_response: QuoteResponse::new("A", "B", 1, 2),
+///     user_public_key: "C".to_string(),
+///     wrap_and_unwrap_sol: Some(true),
+///     compute_unit_price: None,
+///     prioritization_fee_lamports: None,
+///     as_legacy_transaction: None,
+///     destination_token_account: Some("D".to_string()),
+/// };
+/// let json = serde_json::to_value(&request).unwrap();
+/// assert_eq!(json["wrap_and_unwrap_sol"], true);
+/// assert_eq!(json["destinationTokenAccount"], "D");
+/// assert!(json.get("destination_token_account").is_none());
+/// for unset in ["compute_unit_price", "prioritization_fee_lamports", "as_legacy_transaction"] {
+///     assert!(json.get(unset).is_none());
+/// }
+/// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct SwapRequest {
     pub quote_response: QuoteResponse,
     pub user_public_key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub wrap_and_unwrap_sol: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub compute_unit_price: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub prioritization_fee_lamports: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub as_legacy_transaction: Option<bool>,
+    /// Token account receiving the output instead of the user's associated token
+    /// account, e.g. a recipient's
+    #[serde(
+        rename = "destinationTokenAccount",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub destination_token_account: Option<String>,
 }
 
 /// Response structure containing swap transaction details
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict-serde", serde(deny_unknown_fields))]
 pub struct SwapResponse {
     pub swap_transaction: String,
     pub last_valid_block_height: u64,
     pub prioritization_fee_lamports: Option<u64>,
 }
 
+impl SwapResponse {
+    /// Creates a response for a base64-encoded transaction valid until
+    /// `last_valid_block_height`
+    pub fn new(swap_transaction: &str, last_valid_block_height: u64) -> Self {
+        Self {
+            swap_transaction: swap_transaction.to_string(),
+            last_valid_block_height,
+            prioritization_fee_lamports: None,
+        }
+    }
+
+    pub fn with_prioritization_fee_lamports(mut self, lamports: u64) -> Self {
+        self.prioritization_fee_lamports = Some(lamports);
+        self
+    }
+}
+
+/// Account of an instruction returned by the swap-instructions endpoint
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict-serde", serde(deny_unknown_fields))]
+pub struct ApiAccountMeta {
+    pub pubkey: String,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+/// Instruction returned by the swap-instructions endpoint
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict-serde", serde(deny_unknown_fields))]
+pub struct ApiInstruction {
+    pub program_id: String,
+    pub accounts: Vec<ApiAccountMeta>,
+    /// Base64 encoded instruction data
+    pub data: String,
+}
+
+/// Response structure containing the individual instructions of a swap
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict-serde", serde(deny_unknown_fields))]
+pub struct SwapInstructionsResponse {
+    pub token_ledger_instruction: Option<ApiInstruction>,
+    #[serde(default)]
+    pub compute_budget_instructions: Vec<ApiInstruction>,
+    #[serde(default)]
+    pub setup_instructions: Vec<ApiInstruction>,
+    pub swap_instruction: ApiInstruction,
+    pub cleanup_instruction: Option<ApiInstruction>,
+    #[serde(default)]
+    pub other_instructions: Vec<ApiInstruction>,
+    #[serde(default)]
+    pub address_lookup_table_addresses: Vec<String>,
+}
+
+impl ApiAccountMeta {
+    /// Converts into a Solana account meta
+    pub fn to_account_meta(&self) -> Result<AccountMeta, JupiterError> {
+        Ok(AccountMeta {
+            pubkey: Pubkey::from_str(&self.pubkey)
+                .map_err(|e| JupiterError::ParseError(format!("{}: {}", self.pubkey, e)))?,
+            is_signer: self.is_signer,
+            is_writable: self.is_writable,
+        })
+    }
+}
+
+impl ApiInstruction {
+    /// Account metas of the instruction, in order
+    pub fn account_metas(&self) -> Result<Vec<AccountMeta>, JupiterError> {
+        self.accounts
+            .iter()
+            .map(ApiAccountMeta::to_account_meta)
+            .collect()
+    }
+
+    /// Decoded instruction data
+    pub fn data_bytes(&self) -> Result<Vec<u8>, JupiterError> {
+        STANDARD
+            .decode(&self.data)
+            .map_err(|e| JupiterError::ParseError(format!("invalid instruction data: {}", e)))
+    }
+
+    /// Converts into a Solana instruction
+    pub fn to_instruction(&self) -> Result<Instruction, JupiterError> {
+        Ok(Instruction {
+            program_id: Pubkey::from_str(&self.program_id)
+                .map_err(|e| JupiterError::ParseError(format!("{}: {}", self.program_id, e)))?,
+            accounts: self.account_metas()?,
+            data: self.data_bytes()?,
+        })
+    }
+}
+
+impl TryFrom<&ApiInstruction> for Instruction {
+    type Error = JupiterError;
+
+    fn try_from(instruction: &ApiInstruction) -> Result<Self, Self::Error> {
+        instruction.to_instruction()
+    }
+}
+
+impl SwapInstructionsResponse {
+    /// The Jupiter swap instruction
+    pub fn swap(&self) -> Result<Instruction, JupiterError> {
+        self.swap_instruction.to_instruction()
+    }
+
+    /// Every instruction in execution order: compute budget, token ledger, setup,
+    /// swap, cleanup and other instructions
+    pub fn instructions(&self) -> Result<Vec<Instruction>, JupiterError> {
+        self.compute_budget_instructions
+            .iter()
+            .chain(&self.token_ledger_instruction)
+            .chain(&self.setup_instructions)
+            .chain(std::iter::once(&self.swap_instruction))
+            .chain(&self.cleanup_instruction)
+            .chain(&self.other_instructions)
+            .map(ApiInstruction::to_instruction)
+            .collect()
+    }
+
+    /// Accounts of the swap instruction prepared for a CPI from an on-chain program,
+    /// e.g. as Anchor `remaining_accounts`
+    ///
+    /// Accounts equal to `program_authority` are marked as non-signers, since the
+    /// calling program signs for its PDA with `invoke_signed`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::types::SwapInstructionsResponse;
+    /// use solana_program::pubkey::Pubkey;
+    ///
+    /// fn example(swap: &SwapInstructionsResponse, vault_authority: Pubkey) -> Result<(), Box<dyn std::error::Error>> {
+    /// let remaining_accounts = swap.cpi_account_metas(&vault_authority)?;
+    /// let swap_data = swap.swap_instruction.data_bytes()?;
+    /// Ok(())
+    /// }
+    /// ```
+    pub fn cpi_account_metas(
+        &self,
+        program_authority: &Pubkey,
+    ) -> Result<Vec<AccountMeta>, JupiterError> {
+        let mut accounts = self.swap_instruction.account_metas()?;
+        for account in accounts.iter_mut() {
+            if &account.pubkey == program_authority {
+                account.is_signer = false;
+            }
+        }
+        Ok(accounts)
+    }
+
+    /// Lookup table addresses referenced by the instructions
+    pub fn lookup_table_addresses(&self) -> Result<Vec<Pubkey>, JupiterError> {
+        self.address_lookup_table_addresses
+            .iter()
+            .map(|address| {
+                Pubkey::from_str(address)
+                    .map_err(|e| JupiterError::ParseError(format!("{}: {}", address, e)))
+            })
+            .collect()
+    }
+}
+
 /// Price information response for a token
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict-serde", serde(deny_unknown_fields))]
 pub struct PriceResponse {
     pub id: String,
     pub mint_symbol: String,
@@ -102,7 +631,9 @@ pub struct PriceResponse {
 }
 
 /// Token extension metadata
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict-serde", serde(deny_unknown_fields))]
 pub struct TokenExtensions {
     pub coingecko_id: Option<String>,
     pub website: Option<String>,
@@ -110,12 +641,16 @@ pub struct TokenExtensions {
 
 /// Response containing indexed route map data
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict-serde", serde(deny_unknown_fields))]
 pub struct IndexedRouteMapResponse {
     pub indexed_route_map: IndexedRouteMap,
 }
 
 /// Indexed route map structure for efficient route lookup
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "strict-serde", serde(deny_unknown_fields))]
 pub struct IndexedRouteMap {
     pub mint_keys: Vec<String>,
     pub indexed_route_map: HashMap<String, Vec<usize>>,
@@ -125,30 +660,163 @@ pub struct IndexedRouteMap {
 #[derive(Debug, Clone)]
 pub enum JupiterError {
     RequestFailed(String),
+    HttpError {
+        status: u16,
+        message: String,
+        /// Request ID of the failed call, see `JupiterError::request_id`
+        request_id: Option<String>,
+    },
     InvalidInput(String),
     NetworkError(String),
     ValidationError(String),
-    RateLimitExceeded(String),
+    RateLimitExceeded {
+        message: String,
+        /// Request ID of the rate limited call, see `JupiterError::request_id`
+        request_id: Option<String>,
+    },
     TransactionFailed(String),
+    SigningError(String),
     ParseError(String),
+    Cancelled(String),
+    /// A transaction was already submitted under this idempotency key
+    AlreadySubmitted {
+        key: String,
+        signature: String,
+    },
+    /// The API answered with a JSON error body
+    Api {
+        status: u16,
+        error: JupiterApiError,
+        request_id: Option<String>,
+    },
+    /// A traded mint is rejected by the client's `TokenPolicy`
+    PolicyViolation {
+        mint: String,
+        reason: String,
+    },
+    /// A registered `PreTradeCheck` vetoed the trade
+    PreTradeVeto {
+        check: String,
+        reason: VetoReason,
+    },
     Error(String),
 }
 
 impl JupiterError {
+    /// Builds an error from a non-success HTTP status code and response body
+    ///
+    /// JSON error bodies (`{"error": "...", "errorCode": "..."}`) become
+    /// `JupiterError::Api`, so callers can branch on the error code.
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::types::{JupiterApiErrorCode, JupiterError};
+    ///
+    /// let error = JupiterError::from_status(
+    ///     400,
+    ///     r#"{"error":"Could not find any route","errorCode":"COULD_NOT_FIND_ANY_ROUTE"}"#.to_string(),
+    /// );
+    /// assert_eq!(
+    ///     error.api_error().map(|e| &e.code),
+    ///     Some(&JupiterApiErrorCode::CouldNotFindAnyRoute)
+    /// );
+    ///
+    /// let rate_limited = JupiterError::from_status(429, "Too many requests".to_string())
+    ///     .with_request_id("req-1");
+    /// assert_eq!(rate_limited.request_id(), Some("req-1"));
+    /// ```
+    pub fn from_status(status: u16, message: String) -> Self {
+        match status {
+            429 => JupiterError::RateLimitExceeded {
+                message,
+                request_id: None,
+            },
+            _ => match JupiterApiError::parse(&message) {
+                Some(error) => JupiterError::Api {
+                    status,
+                    error,
+                    request_id: None,
+                },
+                None => JupiterError::HttpError {
+                    status,
+                    message,
+                    request_id: None,
+                },
+            },
+        }
+    }
+
+    /// Attaches the request ID of the failed call to HTTP, API and rate limit errors
+    pub fn with_request_id(mut self, id: &str) -> Self {
+        if let JupiterError::HttpError { request_id, .. }
+        | JupiterError::Api { request_id, .. }
+        | JupiterError::RateLimitExceeded { request_id, .. } = &mut self
+        {
+            *request_id = Some(id.to_string());
+        }
+        self
+    }
+
+    /// Request ID to quote when escalating a failed call to Jupiter support
+    ///
+    /// This is the `x-request-id` returned by the server, or the ID the SDK sent when
+    /// the server returned none.
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            JupiterError::HttpError { request_id, .. }
+            | JupiterError::Api { request_id, .. }
+            | JupiterError::RateLimitExceeded { request_id, .. } => request_id.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Parsed API error body, if the API returned one
+    pub fn api_error(&self) -> Option<&JupiterApiError> {
+        match self {
+            JupiterError::Api { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+
+    /// Classifies the error for retry and recovery decisions
+    pub fn category(&self) -> ErrorCategory {
+        match self {
+            JupiterError::NetworkError(_) => ErrorCategory::Network,
+            JupiterError::HttpError { status, .. } => ErrorCategory::from_status(*status),
+            JupiterError::Api { status, error, .. } => {
+                if error.code.is_route_error() {
+                    ErrorCategory::Validation
+                } else {
+                    ErrorCategory::from_status(*status)
+                }
+            }
+            JupiterError::RateLimitExceeded { .. } => ErrorCategory::RateLimit,
+            JupiterError::InvalidInput(_) => ErrorCategory::Validation,
+            JupiterError::ValidationError(_) => ErrorCategory::Validation,
+            JupiterError::TransactionFailed(_) => ErrorCategory::Transaction,
+            JupiterError::SigningError(_) => ErrorCategory::Validation,
+            JupiterError::RequestFailed(_) => ErrorCategory::Unknown,
+            JupiterError::ParseError(_) => ErrorCategory::Unknown,
+            JupiterError::Cancelled(_) => ErrorCategory::Unknown,
+            JupiterError::AlreadySubmitted { .. } => ErrorCategory::Validation,
+            JupiterError::PolicyViolation { .. } => ErrorCategory::Validation,
+            JupiterError::PreTradeVeto { .. } => ErrorCategory::Validation,
+            JupiterError::Error(_) => ErrorCategory::Unknown,
+        }
+    }
+
     /// Determines if the error is retriable
     pub fn is_retriable(&self) -> bool {
+        self.category().is_retriable()
+    }
+
+    /// HTTP status code of the failed request, if the error came from an HTTP response
+    pub fn status(&self) -> Option<u16> {
         match self {
-            JupiterError::NetworkError(_) => true,
-            JupiterError::RequestFailed(msg) => {
-                // Retry on 5xx server errors
-                msg.contains("500") || msg.contains("502") || msg.contains("503")
-            }
-            JupiterError::RateLimitExceeded(_) => true,
-            JupiterError::InvalidInput(_) => false,
-            JupiterError::ParseError(_) => false,
-            JupiterError::TransactionFailed(_) => false,
-            JupiterError::Error(_) => false,
-            JupiterError::ValidationError(_) => false,
+            JupiterError::HttpError { status, .. } => Some(*status),
+            JupiterError::Api { status, .. } => Some(*status),
+            JupiterError::RateLimitExceeded { .. } => Some(429),
+            _ => None,
         }
     }
 }
@@ -157,49 +825,214 @@ impl std::fmt::Display for JupiterError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             JupiterError::RequestFailed(msg) => write!(f, "Request failed: {}", msg),
+            JupiterError::HttpError {
+                status,
+                message,
+                request_id,
+            } => {
+                write!(f, "Request failed: HTTP {}: {}", status, message)?;
+                write_request_id(f, request_id)
+            }
             JupiterError::InvalidInput(msg) => write!(f, "Invalid input: {}", msg),
             JupiterError::NetworkError(msg) => write!(f, "Network error: {}", msg),
             JupiterError::ParseError(msg) => write!(f, "Parse error: {}", msg),
+            JupiterError::Cancelled(msg) => write!(f, "Cancelled: {}", msg),
+            JupiterError::Api {
+                status,
+                error,
+                request_id,
+            } => {
+                write!(f, "Request failed: HTTP {}: {}", status, error)?;
+                write_request_id(f, request_id)
+            }
+            JupiterError::AlreadySubmitted { key, signature } => write!(
+                f,
+                "Already submitted: idempotency key {} was used by {}",
+                key, signature
+            ),
+            JupiterError::PolicyViolation { mint, reason } => {
+                write!(f, "Policy violation: {}: {}", mint, reason)
+            }
+            JupiterError::PreTradeVeto { check, reason } => {
+                write!(f, "Pre-trade veto by {}: {}", check, reason)
+            }
             JupiterError::Error(msg) => write!(f, "Parse error: {}", msg),
             JupiterError::ValidationError(msg) => write!(f, "Parse error: {}", msg),
-            JupiterError::RateLimitExceeded(msg) => write!(f, "Parse error: {}", msg),
+            JupiterError::RateLimitExceeded {
+                message,
+                request_id,
+            } => {
+                write!(f, "Parse error: {}", message)?;
+                write_request_id(f, request_id)
+            }
             JupiterError::TransactionFailed(msg) => write!(f, "Parse error: {}", msg),
+            JupiterError::SigningError(msg) => write!(f, "Signing error: {}", msg),
         }
     }
 }
 
 impl std::error::Error for JupiterError {}
 
+fn write_request_id(
+    f: &mut std::fmt::Formatter<'_>,
+    request_id: &Option<String>,
+) -> std::fmt::Result {
+    match request_id {
+        Some(request_id) => write!(f, " (request id {})", request_id),
+        None => Ok(()),
+    }
+}
+
+/// Error condition reported in the `errorCode` of a Jupiter API error body
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum JupiterApiErrorCode {
+    NoRoutesFound,
+    CouldNotFindAnyRoute,
+    TokenNotTradable,
+    CircularArbitrageIsDisabled,
+    RoutePlanDoesNotConsumeAllTheAmount,
+    MarketNotFound,
+    InvalidMint,
+    AmountTooSmall,
+    /// Code not known to the SDK, kept verbatim
+    Other(String),
+}
+
+impl JupiterApiErrorCode {
+    /// Parses an API error code, e.g. `NO_ROUTES_FOUND`
+    pub fn from_code(code: &str) -> Self {
+        match code {
+            "NO_ROUTES_FOUND" => JupiterApiErrorCode::NoRoutesFound,
+            "COULD_NOT_FIND_ANY_ROUTE" => JupiterApiErrorCode::CouldNotFindAnyRoute,
+            "TOKEN_NOT_TRADABLE" => JupiterApiErrorCode::TokenNotTradable,
+            "CIRCULAR_ARBITRAGE_IS_DISABLED" => JupiterApiErrorCode::CircularArbitrageIsDisabled,
+            "ROUTE_PLAN_DOES_NOT_CONSUME_ALL_THE_AMOUNT" => {
+                JupiterApiErrorCode::RoutePlanDoesNotConsumeAllTheAmount
+            }
+            "MARKET_NOT_FOUND" => JupiterApiErrorCode::MarketNotFound,
+            "INVALID_MINT" => JupiterApiErrorCode::InvalidMint,
+            "AMOUNT_TOO_SMALL" => JupiterApiErrorCode::AmountTooSmall,
+            _ => JupiterApiErrorCode::Other(code.to_string()),
+        }
+    }
+
+    /// Code as sent by the API
+    pub fn as_str(&self) -> &str {
+        match self {
+            JupiterApiErrorCode::NoRoutesFound => "NO_ROUTES_FOUND",
+            JupiterApiErrorCode::CouldNotFindAnyRoute => "COULD_NOT_FIND_ANY_ROUTE",
+            JupiterApiErrorCode::TokenNotTradable => "TOKEN_NOT_TRADABLE",
+            JupiterApiErrorCode::CircularArbitrageIsDisabled => "CIRCULAR_ARBITRAGE_IS_DISABLED",
+            JupiterApiErrorCode::RoutePlanDoesNotConsumeAllTheAmount => {
+                "ROUTE_PLAN_DOES_NOT_CONSUME_ALL_THE_AMOUNT"
+            }
+            JupiterApiErrorCode::MarketNotFound => "MARKET_NOT_FOUND",
+            JupiterApiErrorCode::InvalidMint => "INVALID_MINT",
+            JupiterApiErrorCode::AmountTooSmall => "AMOUNT_TOO_SMALL",
+            JupiterApiErrorCode::Other(code) => code,
+        }
+    }
+
+    /// Whether the pair or amount can't be routed, so retrying the same request is
+    /// pointless
+    pub fn is_route_error(&self) -> bool {
+        matches!(
+            self,
+            JupiterApiErrorCode::NoRoutesFound
+                | JupiterApiErrorCode::CouldNotFindAnyRoute
+                | JupiterApiErrorCode::TokenNotTradable
+                | JupiterApiErrorCode::CircularArbitrageIsDisabled
+                | JupiterApiErrorCode::RoutePlanDoesNotConsumeAllTheAmount
+                | JupiterApiErrorCode::MarketNotFound
+                | JupiterApiErrorCode::InvalidMint
+                | JupiterApiErrorCode::AmountTooSmall
+        )
+    }
+}
+
+impl std::fmt::Display for JupiterApiErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// JSON error body returned by the Jupiter API
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JupiterApiError {
+    pub code: JupiterApiErrorCode,
+    /// Human-readable `error` message
+    pub message: String,
+}
+
+impl JupiterApiError {
+    /// Parses an error body; `None` unless it is JSON with an `error` message or an
+    /// `errorCode`. Numeric codes are kept as `Other`.
+    pub fn parse(body: &str) -> Option<Self> {
+        let value: serde_json::Value = serde_json::from_str(body).ok()?;
+        let message = value
+            .get("error")
+            .or_else(|| value.get("message"))
+            .and_then(|message| message.as_str());
+        let code = match value.get("errorCode") {
+            Some(serde_json::Value::String(code)) => Some(code.clone()),
+            Some(serde_json::Value::Number(code)) => Some(code.to_string()),
+            _ => None,
+        };
+        if message.is_none() && code.is_none() {
+            return None;
+        }
+        Some(Self {
+            code: JupiterApiErrorCode::from_code(code.as_deref().unwrap_or_default()),
+            message: message.unwrap_or_default().to_string(),
+        })
+    }
+}
+
+impl std::fmt::Display for JupiterApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.code {
+            JupiterApiErrorCode::Other(code) if code.is_empty() => write!(f, "{}", self.message),
+            code => write!(f, "{} ({})", self.message, code),
+        }
+    }
+}
+
 /// Rate limiter for API requests
 #[derive(Debug, Clone)]
 pub struct RateLimiter {
     requests_per_second: u32,
-    // Can be implemented using governor or tower::limit::RateLimit
+    // Next instant at which a request may be issued, shared by all clones
+    next_slot: Arc<Mutex<Instant>>,
 }
 
 impl RateLimiter {
     /// Creates a new rate limiter with specified requests per second
     pub fn new(requests_per_second: u32) -> Self {
         Self {
-            requests_per_second,
+            requests_per_second: requests_per_second.max(1),
+            next_slot: Arc::new(Mutex::new(Instant::now())),
         }
     }
 
     /// Acquires permission to make a request, waiting if necessary
-    pub fn acquire(&self) -> impl std::future::Future<Output = ()> {
-        // Simplified rate limiting implementation
-        // In practice, use governor crate for more precise rate limiting
-        async {
-            tokio::time::sleep(Duration::from_millis(
-                1000 / self.requests_per_second as u64,
-            ))
-            .await;
-        }
+    ///
+    /// Requests are spaced evenly, so concurrent callers are released one
+    /// slot at a time instead of all at once.
+    pub async fn acquire(&self) {
+        let interval = Duration::from_secs(1) / self.requests_per_second;
+        let slot = {
+            let mut next_slot = self.next_slot.lock().await;
+            let slot = (*next_slot).max(Instant::now());
+            *next_slot = slot + interval;
+            slot
+        };
+        tokio::time::sleep_until(slot).await;
     }
 }
 
 /// Transaction status types
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum TransactionStatusType {
     Pending,
     Confirmed,
@@ -210,6 +1043,7 @@ pub enum TransactionStatusType {
 
 /// Transaction status monitoring - used for tracking transaction confirmation status
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct TransactionStatus {
     /// Transaction signature
     pub signature: String,
@@ -236,6 +1070,55 @@ pub struct AdvancedSwapConfig {
     pub max_price_impact_bps: u16,
     /// Whether to use versioned transactions
     pub use_versioned_transaction: bool,
+    /// Create the output token account before executing the swap if it is missing
+    pub ensure_destination_ata: bool,
+    /// Use the client's `SlippageAdvisor` recommendation, capped at `max_slippage_bps`,
+    /// instead of the request's slippage
+    pub auto_slippage: bool,
+    /// Priority fee and broadcast preset; `None` leaves fees to the API defaults
+    /// and sends once
+    pub priority: Option<ExecutionPriority>,
+    /// Options used when submitting the signed transaction
+    pub send_options: SendOptions,
+    /// Key under which the submitted signature is recorded; a second execution with
+    /// the same key fails with `JupiterError::AlreadySubmitted` instead of sending;
+    /// retries of an execution policy use `{key}#{attempt}`
+    pub idempotency_key: Option<String>,
+    /// Re-quotes and retries classified failures with adjusted parameters; `None`
+    /// submits once
+    pub execution_policy: Option<ExecutionPolicy>,
+    /// Owner receiving the output instead of the signer; their associated token
+    /// account is created if missing, paid by the signer
+    pub recipient: Option<Pubkey>,
+    /// Quotes `amount` as the exact output (`ExactOut`) instead of the exact input
+    pub exact_out: bool,
+    /// Sends only through private relays, never to the public RPC; `None` sends
+    /// through the client's Solana RPC
+    pub mev_protection: Option<MevProtection>,
+}
+
+impl AdvancedSwapConfig {
+    /// Restricts routing to `venue`, in addition to other preferred venues
+    pub fn prefer_venue(mut self, venue: DexId) -> Self {
+        self.preferred_amms.push(venue.label().to_string());
+        self
+    }
+
+    /// Never routes through `venue`
+    ///
+    /// # Example
+    /// ```rust
+    /// use jup_sdk::{dex::DexId, types::AdvancedSwapConfig};
+    ///
+    /// let config = AdvancedSwapConfig::default()
+    ///     .exclude_venue(DexId::PumpFun)
+    ///     .exclude_venue(DexId::PumpAmm);
+    /// assert_eq!(config.excluded_amms, vec!["Pump.fun", "Pump.fun Amm"]);
+    /// ```
+    pub fn exclude_venue(mut self, venue: DexId) -> Self {
+        self.excluded_amms.push(venue.label().to_string());
+        self
+    }
 }
 
 impl Default for AdvancedSwapConfig {
@@ -246,22 +1129,191 @@ impl Default for AdvancedSwapConfig {
             excluded_amms: Vec::new(),
             max_price_impact_bps: 500, // 5%
             use_versioned_transaction: true,
+            ensure_destination_ata: false,
+            auto_slippage: false,
+            priority: None,
+            send_options: SendOptions::default(),
+            idempotency_key: None,
+            execution_policy: None,
+            recipient: None,
+            exact_out: false,
+            mev_protection: None,
+        }
+    }
+}
+
+/// Transaction submission options
+///
+/// # Example
+/// ```rust
+/// use jup_sdk::types::SendOptions;
+///
+/// let options = SendOptions::fast();
+/// assert!(options.to_rpc_config().skip_preflight);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SendOptions {
+    /// Skip the RPC node's preflight simulation
+    pub skip_preflight: bool,
+    /// Commitment the preflight simulation runs at; `None` uses the node default
+    pub preflight_commitment: Option<CommitmentLevel>,
+    /// How many times the RPC node retries the send; `None` uses the node default
+    pub max_retries: Option<usize>,
+}
+
+impl SendOptions {
+    /// Skips preflight for the fastest landing; failures only surface on-chain
+    pub fn fast() -> Self {
+        Self {
+            skip_preflight: true,
+            preflight_commitment: None,
+            max_retries: None,
+        }
+    }
+
+    /// Runs preflight against confirmed state so stale or failing swaps are rejected
+    /// before they are broadcast
+    pub fn safe() -> Self {
+        Self {
+            skip_preflight: false,
+            preflight_commitment: Some(CommitmentLevel::Confirmed),
+            max_retries: None,
+        }
+    }
+
+    pub fn with_max_retries(mut self, max_retries: usize) -> Self {
+        self.max_retries = Some(max_retries);
+        self
+    }
+
+    /// Converts to the RPC client's send config
+    pub fn to_rpc_config(&self) -> RpcSendTransactionConfig {
+        RpcSendTransactionConfig {
+            skip_preflight: self.skip_preflight,
+            preflight_commitment: self.preflight_commitment,
+            max_retries: self.max_retries,
+            ..RpcSendTransactionConfig::default()
         }
     }
 }
 
 /// Batch quote request - for getting multiple swap quotes in one request
+///
+/// # Example
+/// ```rust
+/// use jup_sdk::types::{BatchQuoteRequest, QuoteRequest};
+///
+/// let request = BatchQuoteRequest {
+///     requests: vec![QuoteRequest {
+///         input_mint: "A".to_string(),
+///         output_mint: "B".to_string(),
+///         amount: 1,
+///         slippage_bps: 50,
+///         fee_bps: None,
+///         only_direct_routes: None,
+///         as_legacy_transaction: None,
+///         restrict_middle_tokens: None,
+///     }],
+/// };
+/// assert_eq!(
+///     serde_json::to_string(&request).unwrap(),
+///     r#"{"requests":[{"input_mint":"A","output_mint":"B","amount":1,"slippage_bps":50}]}"#
+/// );
+/// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct BatchQuoteRequest {
     /// Multiple quote requests
     pub requests: Vec<QuoteRequest>,
 }
 
+/// Options for batch quoting
+#[derive(Debug, Clone)]
+pub struct BatchQuoteOptions {
+    /// Maximum number of quotes in flight; the rate limiter still applies
+    pub concurrency: usize,
+    /// Abort the batch and return the first error instead of per-request results
+    pub fail_fast: bool,
+}
+
+impl Default for BatchQuoteOptions {
+    fn default() -> Self {
+        Self {
+            concurrency: DEFAULT_BATCH_CONCURRENCY,
+            fail_fast: false,
+        }
+    }
+}
+
 /// Batch quote response - contains multiple quote results
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct BatchQuoteResponse {
-    /// List of quote results
+    /// Successful quotes, in request order
     pub quotes: Vec<QuoteResponse>,
+    /// Failed requests, in request order
+    #[serde(default)]
+    pub errors: Vec<BatchQuoteError>,
+}
+
+/// Failure of a single request within a batch
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct BatchQuoteError {
+    /// Index of the request in `BatchQuoteRequest::requests`
+    pub index: usize,
+    pub input_mint: String,
+    pub output_mint: String,
+    pub amount: u64,
+    pub message: String,
+    pub retriable: bool,
+    /// Original error; not serialized
+    #[serde(skip)]
+    pub error: Option<JupiterError>,
+}
+
+impl BatchQuoteResponse {
+    /// Builds the response from per-request results in request order
+    pub fn from_results(
+        requests: &[QuoteRequest],
+        results: Vec<Result<QuoteResponse, JupiterError>>,
+    ) -> Self {
+        let mut quotes = Vec::new();
+        let mut errors = Vec::new();
+        for (index, (request, result)) in requests.iter().zip(results).enumerate() {
+            match result {
+                Ok(quote) => quotes.push(quote),
+                Err(e) => errors.push(BatchQuoteError {
+                    index,
+                    input_mint: request.input_mint.clone(),
+                    output_mint: request.output_mint.clone(),
+                    amount: request.amount,
+                    message: e.to_string(),
+                    retriable: e.is_retriable(),
+                    error: Some(e),
+                }),
+            }
+        }
+        Self { quotes, errors }
+    }
+
+    /// Whether every request succeeded
+    pub fn is_complete(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Per-request results in request order
+    pub fn into_results(self) -> Vec<Result<QuoteResponse, JupiterError>> {
+        let total = self.quotes.len() + self.errors.len();
+        let mut quotes = self.quotes.into_iter();
+        let mut errors = self.errors.into_iter().peekable();
+        (0..total)
+            .filter_map(|index| match errors.next_if(|e| e.index == index) {
+                Some(e) => Some(Err(e.error.unwrap_or(JupiterError::Error(e.message)))),
+                None => quotes.next().map(Ok),
+            })
+            .collect()
+    }
 }
 
 /// Swap execution result - encapsulates complete swap operation result
@@ -273,22 +1325,63 @@ pub struct SwapExecutionResult {
     pub swap_response: crate::types::SwapResponse,
     /// Configuration used
     pub config: AdvancedSwapConfig,
+    /// Signature of the submitted transaction, if it was sent
+    pub signature: Option<String>,
+    /// Monitoring outcome, if the transaction was monitored
+    pub monitor_result: Option<TransactionMonitorResult>,
+    /// Token-2022 transfer fee of the input mint, if any
+    pub input_transfer_fee: Option<TransferFee>,
+    /// Token-2022 transfer fee of the output mint, if any
+    pub output_transfer_fee: Option<TransferFee>,
+    /// Audit of every submitted transaction when an execution policy retried; this
+    /// result describes the last one
+    pub attempts: Vec<ExecutionAttempt>,
 }
 
 impl SwapExecutionResult {
-    /// Gets the expected output amount
+    /// Gets the expected output amount, net of Token-2022 transfer fees
     pub fn get_expected_output(&self) -> u64 {
-        self.quote.out_amount.parse().unwrap_or(0)
+        self.quote.net_out_amount(
+            self.input_transfer_fee.as_ref(),
+            self.output_transfer_fee.as_ref(),
+        )
     }
 
     /// Gets the minimum output amount considering slippage
+    ///
+    /// Uses the quote's `other_amount_threshold` when present, otherwise derives it
+    /// from the expected output and slippage. ExactOut swaps deliver exactly
+    /// `out_amount`, their threshold being the maximum input, so the minimum is the
+    /// quoted output net of the output transfer fee.
     pub fn get_minimum_output(&self) -> u64 {
-        let out_amount: u64 = self.quote.out_amount.parse().unwrap_or(0);
-        cal_slippage_amount(out_amount, self.quote.slippage_bps)
+        if self.quote.swap_mode.eq_ignore_ascii_case("ExactOut") {
+            let out_amount = self.quote.out_amount.parse().unwrap_or(0);
+            return match &self.output_transfer_fee {
+                Some(fee) => fee.amount_after_fee(out_amount),
+                None => out_amount,
+            };
+        }
+        match self.quote.other_amount_threshold.parse::<u64>() {
+            Ok(threshold) => match &self.output_transfer_fee {
+                Some(fee) => fee.amount_after_fee(threshold),
+                None => threshold,
+            },
+            Err(_) => cal_slippage_amount(self.get_expected_output(), self.quote.slippage_bps),
+        }
     }
 
     /// Calculates price impact percentage
     pub fn get_price_impact(&self) -> f64 {
         self.quote.price_impact_pct.parse().unwrap_or(0.0)
     }
+
+    /// Whether the swap transaction was confirmed or finalized on-chain
+    pub fn is_confirmed(&self) -> bool {
+        self.monitor_result.as_ref().is_some_and(|result| {
+            matches!(
+                result.status,
+                MonitorStatus::Confirmed | MonitorStatus::Finalized
+            )
+        })
+    }
 }
@@ -0,0 +1,195 @@
+/// Verification module.
+/// Checks swap transactions returned by the API before they are signed, as a defense
+/// against a compromised or spoofed API endpoint.
+use std::str::FromStr;
+
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{
+    dex::{ProgramIdList, SYSTEM_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID},
+    global::WSOL_MINT,
+    tool::associated_token_address,
+    tx::decode_swap_transaction,
+    types::{JupiterError, SwapResponse},
+};
+
+/// System program `Transfer` instruction discriminant
+const SYSTEM_TRANSFER: u32 = 2;
+/// System program `TransferWithSeed` instruction discriminant
+const SYSTEM_TRANSFER_WITH_SEED: u32 = 11;
+/// Token program `Transfer` instruction discriminant
+const TOKEN_TRANSFER: u8 = 3;
+/// Token program `TransferChecked` instruction discriminant
+const TOKEN_TRANSFER_CHECKED: u8 = 12;
+
+/// Problem found while verifying a swap transaction
+#[derive(Debug, Clone, PartialEq)]
+pub enum VerificationIssue {
+    /// The fee payer is not the expected user
+    FeePayerMismatch { expected: Pubkey, actual: Pubkey },
+    /// The transaction requires a signature from an account other than the user
+    UnexpectedSigner(Pubkey),
+    /// A top-level instruction invokes a program outside the known program list
+    UnknownProgram(Pubkey),
+    /// A top-level transfer sends funds to an account not owned by the user
+    /// `destination` is `None` when it is loaded from an address lookup table.
+    UnexpectedTransfer {
+        program: Pubkey,
+        destination: Option<Pubkey>,
+    },
+}
+
+impl std::fmt::Display for VerificationIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VerificationIssue::FeePayerMismatch { expected, actual } => {
+                write!(f, "fee payer {} does not match user {}", actual, expected)
+            }
+            VerificationIssue::UnexpectedSigner(signer) => {
+                write!(f, "unexpected required signer {}", signer)
+            }
+            VerificationIssue::UnknownProgram(program) => {
+                write!(f, "unknown program {}", program)
+            }
+            VerificationIssue::UnexpectedTransfer {
+                program,
+                destination: Some(destination),
+            } => write!(f, "unexpected {} transfer to {}", program, destination),
+            VerificationIssue::UnexpectedTransfer {
+                program,
+                destination: None,
+            } => write!(
+                f,
+                "unexpected {} transfer to a lookup table account",
+                program
+            ),
+        }
+    }
+}
+
+/// Lists the problems found in a swap transaction
+///
+/// Checks that the user is the fee payer and only signer, that every top-level
+/// instruction invokes a known program, and that top-level SOL and token transfers
+/// only move funds between the user and their own associated token accounts for
+/// wrapped SOL and `expected_mints`.
+///
+/// # Params
+/// swap - Swap response returned by the API
+/// expected_user - Wallet that requested the swap
+/// expected_mints - Mints of the swap, typically the input and output mint
+/// programs - Known program list, see `JupiterClient::get_program_ids`
+pub fn inspect_swap_transaction(
+    swap: &SwapResponse,
+    expected_user: &Pubkey,
+    expected_mints: &[Pubkey],
+    programs: &ProgramIdList,
+) -> Result<Vec<VerificationIssue>, JupiterError> {
+    let transaction = decode_swap_transaction(&swap.swap_transaction)?;
+    let message = &transaction.message;
+    let keys = message.static_account_keys();
+    let mut issues = Vec::new();
+
+    let fee_payer = keys
+        .first()
+        .ok_or_else(|| JupiterError::ParseError("transaction has no accounts".to_string()))?;
+    if fee_payer != expected_user {
+        issues.push(VerificationIssue::FeePayerMismatch {
+            expected: *expected_user,
+            actual: *fee_payer,
+        });
+    }
+    let num_signers = message.header().num_required_signatures as usize;
+    for signer in keys.iter().take(num_signers).skip(1) {
+        issues.push(VerificationIssue::UnexpectedSigner(*signer));
+    }
+
+    let allowed_destinations = user_accounts(expected_user, expected_mints);
+    let system_program = program_id(SYSTEM_PROGRAM_ID);
+    let token_programs = [
+        program_id(TOKEN_PROGRAM_ID),
+        program_id(TOKEN_2022_PROGRAM_ID),
+    ];
+    for instruction in message.instructions() {
+        let Some(program) = keys.get(instruction.program_id_index as usize) else {
+            return Err(JupiterError::ParseError(
+                "instruction program is not a static account".to_string(),
+            ));
+        };
+        if !programs.is_known_program(program) {
+            issues.push(VerificationIssue::UnknownProgram(*program));
+            continue;
+        }
+        let destination_index = if *program == system_program {
+            match system_discriminant(&instruction.data) {
+                Some(SYSTEM_TRANSFER) => instruction.accounts.get(1),
+                Some(SYSTEM_TRANSFER_WITH_SEED) => instruction.accounts.get(2),
+                _ => None,
+            }
+        } else if token_programs.contains(program) {
+            match instruction.data.first() {
+                Some(&TOKEN_TRANSFER) => instruction.accounts.get(1),
+                Some(&TOKEN_TRANSFER_CHECKED) => instruction.accounts.get(2),
+                _ => None,
+            }
+        } else {
+            None
+        };
+        if let Some(index) = destination_index {
+            let destination = keys.get(*index as usize).copied();
+            if !destination.is_some_and(|d| allowed_destinations.contains(&d)) {
+                issues.push(VerificationIssue::UnexpectedTransfer {
+                    program: *program,
+                    destination,
+                });
+            }
+        }
+    }
+    Ok(issues)
+}
+
+/// Verifies a swap transaction, failing with `JupiterError::ValidationError` listing
+/// every problem found
+///
+/// See `inspect_swap_transaction` for the checks performed.
+pub fn verify_swap_transaction(
+    swap: &SwapResponse,
+    expected_user: &Pubkey,
+    expected_mints: &[Pubkey],
+    programs: &ProgramIdList,
+) -> Result<(), JupiterError> {
+    let issues = inspect_swap_transaction(swap, expected_user, expected_mints, programs)?;
+    if issues.is_empty() {
+        return Ok(());
+    }
+    let issues: Vec<String> = issues.iter().map(|issue| issue.to_string()).collect();
+    Err(JupiterError::ValidationError(format!(
+        "swap transaction verification failed: {}",
+        issues.join("; ")
+    )))
+}
+
+/// The user and their associated token accounts for wrapped SOL and `mints`
+fn user_accounts(user: &Pubkey, mints: &[Pubkey]) -> Vec<Pubkey> {
+    let mut accounts = vec![*user];
+    let wsol = program_id(WSOL_MINT);
+    for mint in mints.iter().chain(std::iter::once(&wsol)) {
+        for token_program in [TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID] {
+            accounts.push(associated_token_address(
+                user,
+                mint,
+                &program_id(token_program),
+            ));
+        }
+    }
+    accounts
+}
+
+fn program_id(address: &str) -> Pubkey {
+    Pubkey::from_str(address).expect("built-in address is a valid pubkey")
+}
+
+fn system_discriminant(data: &[u8]) -> Option<u32> {
+    data.get(..4)
+        .map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
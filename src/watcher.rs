@@ -0,0 +1,409 @@
+/// Wallet watcher module.
+/// Follows a wallet through a websocket `logsSubscribe` subscription, or by polling
+/// its signatures, and emits the Jupiter swap fills that land for it.
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use solana_client::{
+    nonblocking::{pubsub_client::PubsubClient, rpc_client::RpcClient},
+    rpc_client::GetConfirmedSignaturesForAddress2Config,
+    rpc_config::{RpcTransactionConfig, RpcTransactionLogsConfig, RpcTransactionLogsFilter},
+};
+use solana_commitment_config::CommitmentConfig;
+use solana_network_sdk::Solana;
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
+use solana_transaction_status::{
+    EncodedConfirmedTransactionWithStatusMeta, UiTransactionEncoding, UiTransactionTokenBalance,
+    option_serializer::OptionSerializer,
+};
+use std::{collections::HashMap, str::FromStr, sync::Arc, time::Duration};
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+
+#[cfg(feature = "grpc")]
+use crate::grpc::{GrpcConfig, TransactionFilter, subscribe_transactions};
+use crate::{
+    dex::JUPITER_PROGRAM_ID,
+    global::WSOL_MINT,
+    notify::{NotificationEvent, Notifier},
+    task::BackgroundTask,
+    types::JupiterError,
+};
+
+/// Attempts to fetch a transaction announced by the log subscription, which can run
+/// ahead of `getTransaction`
+const FETCH_ATTEMPTS: u32 = 3;
+
+/// Configuration of a `WalletWatcher`
+#[derive(Debug, Clone)]
+pub struct WalletWatcherConfig {
+    /// Commitment fills must reach before they are emitted
+    pub commitment: CommitmentConfig,
+    /// Interval of the polling fallback
+    pub poll_interval: Duration,
+    /// Websocket RPC endpoint for `logsSubscribe`; polling is used when `None` or
+    /// when the subscription fails
+    pub websocket_url: Option<String>,
+    /// Yellowstone gRPC endpoint, preferred over the websocket when set
+    #[cfg(feature = "grpc")]
+    pub grpc: Option<GrpcConfig>,
+    /// Notified of every fill
+    pub notifier: Option<Arc<dyn Notifier>>,
+    /// Fills buffered before the watcher waits for the receiver
+    pub channel_capacity: usize,
+}
+
+impl Default for WalletWatcherConfig {
+    fn default() -> Self {
+        Self {
+            commitment: CommitmentConfig::confirmed(),
+            poll_interval: Duration::from_secs(2),
+            websocket_url: None,
+            #[cfg(feature = "grpc")]
+            grpc: None,
+            notifier: None,
+            channel_capacity: 256,
+        }
+    }
+}
+
+/// Jupiter swap fill decoded from a wallet's balance changes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WalletFill {
+    pub signature: String,
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    pub wallet: String,
+    /// Mint the wallet spent, wrapped SOL for native SOL
+    pub input_mint: String,
+    /// Amount spent (raw units), net of the transaction fee for SOL
+    pub input_amount: u64,
+    /// Mint the wallet received, wrapped SOL for native SOL
+    pub output_mint: String,
+    /// Amount received (raw units)
+    pub output_amount: u64,
+    /// Transaction fee in lamports
+    pub fee: u64,
+}
+
+impl WalletFill {
+    /// Decodes the fill of `wallet` from a fetched transaction
+    ///
+    /// Returns `None` for failed transactions, transactions not invoking the Jupiter
+    /// program, and transactions that don't both debit and credit the wallet.
+    pub fn from_transaction(
+        wallet: &Pubkey,
+        transaction: &EncodedConfirmedTransactionWithStatusMeta,
+    ) -> Option<Self> {
+        let meta = transaction.transaction.meta.as_ref()?;
+        if meta.err.is_some() {
+            return None;
+        }
+        let OptionSerializer::Some(logs) = &meta.log_messages else {
+            return None;
+        };
+        if !logs.iter().any(|log| log.contains(JUPITER_PROGRAM_ID)) {
+            return None;
+        }
+
+        let owner = wallet.to_string();
+        let mut deltas: HashMap<String, i128> = HashMap::new();
+        let mut apply = |balances: &OptionSerializer<Vec<UiTransactionTokenBalance>>,
+                         sign: i128| {
+            if let OptionSerializer::Some(balances) = balances {
+                for balance in balances {
+                    if !matches!(&balance.owner, OptionSerializer::Some(o) if *o == owner) {
+                        continue;
+                    }
+                    let amount: i128 = balance.ui_token_amount.amount.parse().unwrap_or(0);
+                    *deltas.entry(balance.mint.clone()).or_default() += sign * amount;
+                }
+            }
+        };
+        apply(&meta.pre_token_balances, -1);
+        apply(&meta.post_token_balances, 1);
+
+        // Native SOL counts as wrapped SOL, so wrapping and unwrapping net out
+        let decoded = transaction.transaction.transaction.decode()?;
+        let keys = decoded.message.static_account_keys();
+        if let Some(index) = keys.iter().position(|key| key == wallet) {
+            let pre = *meta.pre_balances.get(index)? as i128;
+            let post = *meta.post_balances.get(index)? as i128;
+            // The fee payer's balance also drops by the fee, which is reported apart
+            let fee = if index == 0 { meta.fee as i128 } else { 0 };
+            *deltas.entry(WSOL_MINT.to_string()).or_default() += post - pre + fee;
+        }
+
+        let (input_mint, spent) = deltas.iter().min_by_key(|(_, delta)| **delta)?;
+        let (output_mint, received) = deltas.iter().max_by_key(|(_, delta)| **delta)?;
+        if *spent >= 0 || *received <= 0 {
+            return None;
+        }
+        Some(Self {
+            signature: decoded.signatures.first()?.to_string(),
+            slot: transaction.slot,
+            block_time: transaction.block_time,
+            wallet: owner,
+            input_mint: input_mint.clone(),
+            input_amount: u64::try_from(-spent).ok()?,
+            output_mint: output_mint.clone(),
+            output_amount: u64::try_from(*received).ok()?,
+            fee: meta.fee,
+        })
+    }
+}
+
+/// Watches a wallet and emits its Jupiter swap fills as they land
+///
+/// Fills arrive in landing order on `next`, e.g. to feed a PnL tracker, and are sent
+/// to the configured notifier. The watcher stops when shut down or dropped.
+///
+/// # Example
+/// ```rust
+/// use jup_sdk::JupiterClient;
+/// use solana_sdk::pubkey::Pubkey;
+///
+/// async fn follow(client: JupiterClient, wallet: Pubkey) {
+///     let mut watcher = client.watch_wallet(&wallet, None);
+///     while let Some(fill) = watcher.next().await {
+///         println!("{} -> {}: {}", fill.input_mint, fill.output_mint, fill.output_amount);
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct WalletWatcher {
+    fills: mpsc::Receiver<WalletFill>,
+    task: BackgroundTask,
+}
+
+impl WalletWatcher {
+    /// Starts watching `wallet`
+    ///
+    /// Only fills landing after the start are emitted.
+    pub fn start(solana: Arc<Solana>, wallet: Pubkey, config: WalletWatcherConfig) -> Self {
+        let (sender, fills) = mpsc::channel(config.channel_capacity.max(1));
+        let task = BackgroundTask::spawn(move |token| async move {
+            let Some(rpc_client) = solana.client.clone() else {
+                tracing::error!("wallet watcher needs a solana client");
+                return;
+            };
+            let watch = Watch {
+                rpc_client,
+                wallet,
+                config,
+                sender,
+            };
+            watch.run(token).await;
+        });
+        Self { fills, task }
+    }
+
+    /// Waits for the next fill; `None` once the watcher has stopped
+    pub async fn next(&mut self) -> Option<WalletFill> {
+        self.fills.recv().await
+    }
+
+    /// Stops watching and waits for the watcher to finish
+    pub async fn shutdown(self) {
+        self.task.shutdown().await;
+    }
+}
+
+/// State of a running watcher
+struct Watch {
+    rpc_client: Arc<RpcClient>,
+    wallet: Pubkey,
+    config: WalletWatcherConfig,
+    sender: mpsc::Sender<WalletFill>,
+}
+
+impl Watch {
+    async fn run(&self, token: CancellationToken) {
+        #[cfg(feature = "grpc")]
+        if let Some(grpc) = &self.config.grpc {
+            match self.stream(grpc, &token).await {
+                Ok(()) => return,
+                Err(e) => tracing::warn!(error = %e, "wallet grpc stream failed, falling back"),
+            }
+        }
+        if let Some(url) = &self.config.websocket_url {
+            match self.subscribe(url, &token).await {
+                Ok(()) => return,
+                Err(e) => {
+                    tracing::warn!(error = %e, "wallet log subscription failed, polling instead")
+                }
+            }
+        }
+        self.poll(&token).await;
+    }
+
+    /// Follows the wallet via a Yellowstone gRPC transaction stream until cancelled
+    #[cfg(feature = "grpc")]
+    async fn stream(
+        &self,
+        grpc: &GrpcConfig,
+        token: &CancellationToken,
+    ) -> Result<(), JupiterError> {
+        let mut transactions = subscribe_transactions(
+            grpc,
+            TransactionFilter::Account(self.wallet),
+            self.config.commitment,
+        )
+        .await?;
+        loop {
+            let transaction = tokio::select! {
+                _ = token.cancelled() => return Ok(()),
+                transaction = transactions.next() => transaction,
+            };
+            let transaction = transaction.ok_or_else(|| {
+                JupiterError::NetworkError("grpc: transaction stream closed".to_string())
+            })??;
+            if !transaction.failed && !self.handle(&transaction.signature).await {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Follows the wallet via `logsSubscribe` until cancelled
+    async fn subscribe(&self, url: &str, token: &CancellationToken) -> Result<(), JupiterError> {
+        let pubsub = PubsubClient::new(url)
+            .await
+            .map_err(|e| JupiterError::NetworkError(e.to_string()))?;
+        let (mut logs, unsubscribe) = pubsub
+            .logs_subscribe(
+                RpcTransactionLogsFilter::Mentions(vec![self.wallet.to_string()]),
+                RpcTransactionLogsConfig {
+                    commitment: Some(self.config.commitment),
+                },
+            )
+            .await
+            .map_err(|e| JupiterError::NetworkError(e.to_string()))?;
+        loop {
+            let response = tokio::select! {
+                _ = token.cancelled() => break,
+                response = logs.next() => response,
+            };
+            let Some(response) = response else {
+                unsubscribe().await;
+                return Err(JupiterError::NetworkError(
+                    "log subscription closed".to_string(),
+                ));
+            };
+            let entry = response.value;
+            if entry.err.is_some()
+                || !entry
+                    .logs
+                    .iter()
+                    .any(|log| log.contains(JUPITER_PROGRAM_ID))
+            {
+                continue;
+            }
+            if let Ok(signature) = Signature::from_str(&entry.signature)
+                && !self.handle(&signature).await
+            {
+                break;
+            }
+        }
+        unsubscribe().await;
+        Ok(())
+    }
+
+    /// Follows the wallet by polling its signatures until cancelled
+    async fn poll(&self, token: &CancellationToken) {
+        let mut until = self.latest_signature().await;
+        loop {
+            tokio::select! {
+                _ = token.cancelled() => return,
+                _ = tokio::time::sleep(self.config.poll_interval) => {}
+            }
+            let config = GetConfirmedSignaturesForAddress2Config {
+                before: None,
+                until,
+                limit: None,
+                commitment: Some(self.config.commitment),
+            };
+            let signatures = match self
+                .rpc_client
+                .get_signatures_for_address_with_config(&self.wallet, config)
+                .await
+            {
+                Ok(signatures) => signatures,
+                Err(e) => {
+                    tracing::warn!(error = %e, "failed to poll wallet signatures");
+                    continue;
+                }
+            };
+            // Newest first; emit in landing order
+            for status in signatures.iter().rev() {
+                let Ok(signature) = Signature::from_str(&status.signature) else {
+                    continue;
+                };
+                until = Some(signature);
+                if status.err.is_none() && !self.handle(&signature).await {
+                    return;
+                }
+            }
+        }
+    }
+
+    async fn latest_signature(&self) -> Option<Signature> {
+        let config = GetConfirmedSignaturesForAddress2Config {
+            limit: Some(1),
+            commitment: Some(self.config.commitment),
+            ..Default::default()
+        };
+        self.rpc_client
+            .get_signatures_for_address_with_config(&self.wallet, config)
+            .await
+            .ok()?
+            .first()
+            .and_then(|status| Signature::from_str(&status.signature).ok())
+    }
+
+    /// Fetches, decodes and emits a fill; returns false once the receiver is gone
+    async fn handle(&self, signature: &Signature) -> bool {
+        let Some(fill) = self.fetch_fill(signature).await else {
+            return true;
+        };
+        if let Some(notifier) = &self.config.notifier {
+            let event = NotificationEvent::Filled {
+                signature: fill.signature.clone(),
+                slot: fill.slot,
+                input_mint: Some(fill.input_mint.clone()),
+                output_mint: Some(fill.output_mint.clone()),
+                out_amount: Some(fill.output_amount),
+            };
+            if let Err(e) = notifier.notify(&event).await {
+                tracing::warn!(error = %e, "failed to deliver notification");
+            }
+        }
+        self.sender.send(fill).await.is_ok()
+    }
+
+    async fn fetch_fill(&self, signature: &Signature) -> Option<WalletFill> {
+        // getTransaction rejects processed
+        let commitment = if self.config.commitment == CommitmentConfig::processed() {
+            CommitmentConfig::confirmed()
+        } else {
+            self.config.commitment
+        };
+        for attempt in 1..=FETCH_ATTEMPTS {
+            let config = RpcTransactionConfig {
+                encoding: Some(UiTransactionEncoding::Base64),
+                commitment: Some(commitment),
+                max_supported_transaction_version: Some(0),
+            };
+            match self
+                .rpc_client
+                .get_transaction_with_config(signature, config)
+                .await
+            {
+                Ok(transaction) => return WalletFill::from_transaction(&self.wallet, &transaction),
+                Err(e) if attempt == FETCH_ATTEMPTS => {
+                    tracing::warn!(signature = %signature, error = %e, "failed to fetch wallet transaction");
+                }
+                Err(_) => tokio::time::sleep(self.config.poll_interval).await,
+            }
+        }
+        None
+    }
+}